@@ -0,0 +1,205 @@
+use crate::organisms::{
+    with_logs_directory, DeterministicOrder, EcosystemTuning, Extinction, OrganismPlugin,
+    EXTINCTION_EXIT_CODE,
+};
+use crate::utils::{advance_sim_time, measure_sim_rate, SimRate, SimTime};
+use crate::world::{WorldPlugin, WorldSeed};
+use bevy::app::PluginsState;
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+/// One self-contained headless simulation: its own world, organisms, tuning and log directory
+/// Every instance is a separate Bevy `App`, so any number of them can run in one process,
+/// e.g. an A/B comparison of two seeds or two tunings (set `tuning` before building).
+/// Instances always use the deterministic order and fixed timestep, which keeps the update
+/// order and the seeded random streams stable between runs of the same seed and tuning.
+pub struct SimInstance {
+    /// Name of the instance's log directory under `data/logs`
+    pub name: String,
+    /// World seed; also seeds the founder genomes
+    pub seed: u64,
+    pub tuning: EcosystemTuning,
+}
+
+impl SimInstance {
+    pub fn new(name: impl Into<String>, seed: u64) -> Self {
+        Self {
+            name: name.into(),
+            seed,
+            tuning: EcosystemTuning::default(),
+        }
+    }
+
+    /// Directory this instance's CSVs and exports are written to
+    pub fn logs_dir(&self) -> PathBuf {
+        PathBuf::from("data/logs").join(&self.name)
+    }
+
+    /// Build the instance's app, ready to be stepped with `App::update`
+    pub fn into_app(self) -> App {
+        let logs_dir = self.logs_dir();
+        let mut tuning = self.tuning;
+        tuning.organism_seed ^= self.seed;
+        let deterministic = DeterministicOrder(true);
+        // Without a window the rate is printed periodically, prefixed with the instance name
+        let mut rate = SimRate::from_args();
+        rate.label = Some(self.name.clone());
+
+        with_logs_directory(logs_dir, || {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins)
+                .init_resource::<SimTime>()
+                .insert_resource(WorldSeed(self.seed))
+                .insert_resource(tuning)
+                .insert_resource(deterministic)
+                .insert_resource(deterministic.time_update_strategy())
+                .add_plugins(WorldPlugin)
+                .add_plugins(OrganismPlugin)
+                .insert_resource(rate)
+                .add_systems(First, advance_sim_time)
+                .add_systems(Last, measure_sim_rate);
+
+            // `App::run` would hand the app to the schedule runner; finish it by hand instead
+            while app.plugins_state() == PluginsState::Adding {
+                bevy::tasks::tick_global_task_pools_on_main_thread();
+            }
+            app.finish();
+            app.cleanup();
+            app
+        })
+    }
+
+    /// Simulate `ticks` ticks (fewer after a Ctrl+C or an extinction), then drop the app (which
+    /// flushes its loggers). Returns whether the run ended in extinction.
+    pub fn run(self, ticks: u64) -> bool {
+        let name = self.name.clone();
+        let logs_dir = self.logs_dir();
+        let mut app = self.into_app();
+        for tick in 0..ticks {
+            if crate::utils::shutdown::interrupted() {
+                warn!("[INSTANCE] {name}: interrupted after {tick} of {ticks} ticks");
+                break;
+            }
+            // Without `restart_on_extinction` an extinct world is paused; nothing left to run
+            if let Some(extinct_at) = app.world.resource::<Extinction>().extinct_at {
+                warn!("[INSTANCE] {name}: extinct at tick {extinct_at}, stopping");
+                break;
+            }
+            app.update();
+        }
+        let ran = app.world.resource::<SimTime>().tick;
+        let extinction = app.world.resource::<Extinction>();
+        let ended_extinct = extinction.extinct_at.is_some();
+        info!(
+            "[INSTANCE] {name}: ran {ran} of {ticks} ticks ({} extinctions), logs in {}",
+            extinction.count,
+            logs_dir.display()
+        );
+        ended_extinct
+    }
+}
+
+/// `--instances <seed_a> <seed_b>`: run two headless instances side by side, one per thread,
+/// for `--instance-ticks` ticks (default 3600), logging to `data/logs/seed_<seed>`
+/// Exits the process with `EXTINCTION_EXIT_CODE` if either instance ended in extinction.
+pub fn run_instances_from_args() -> bool {
+    let Some((seed_a, seed_b)) = crate::utils::cli::option_pair("instances") else {
+        return false;
+    };
+    let (Ok(seed_a), Ok(seed_b)) = (seed_a.trim().parse::<u64>(), seed_b.trim().parse::<u64>())
+    else {
+        error!("Invalid --instances '{seed_a}' '{seed_b}', expected two integer seeds");
+        std::process::exit(1);
+    };
+    let ticks = match crate::utils::cli::option_value("instance-ticks") {
+        Some(value) => value.trim().parse().unwrap_or_else(|_| {
+            warn!("Invalid --instance-ticks '{}', using the default", value);
+            DEFAULT_INSTANCE_TICKS
+        }),
+        None => DEFAULT_INSTANCE_TICKS,
+    };
+
+    let handles: Vec<_> = [seed_a, seed_b]
+        .into_iter()
+        .map(|seed| {
+            let instance = SimInstance::new(format!("seed_{seed}"), seed);
+            std::thread::spawn(move || instance.run(ticks))
+        })
+        .collect();
+    let mut any_extinct = false;
+    for handle in handles {
+        match handle.join() {
+            Ok(extinct) => any_extinct |= extinct,
+            Err(_) => error!("[INSTANCE] An instance panicked"),
+        }
+    }
+    if any_extinct {
+        info!("[INSTANCE] An instance went extinct, exiting with code {EXTINCTION_EXIT_CODE}");
+        std::process::exit(EXTINCTION_EXIT_CODE);
+    }
+    true
+}
+
+/// One simulated minute at 60 ticks per second
+const DEFAULT_INSTANCE_TICKS: u64 = 3600;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::{Energy, OrganismUid, SpeciesId};
+
+    /// (uid, species, energy bits) of every organism, in uid order
+    fn snapshot(app: &mut App) -> Vec<(u64, u32, u32)> {
+        let mut query = app.world.query::<(&OrganismUid, &SpeciesId, &Energy)>();
+        let mut rows: Vec<_> = query
+            .iter(&app.world)
+            .map(|(uid, species, energy)| (uid.0, species.value(), energy.current.to_bits()))
+            .collect();
+        rows.sort_unstable();
+        rows
+    }
+
+    #[test]
+    fn instances_measure_their_sim_rate() {
+        let instance = SimInstance::new("sim_rate_test", 3);
+        let logs = instance.logs_dir();
+        let mut app = instance.into_app();
+        for _ in 0..3 {
+            app.update();
+        }
+        let rate = app.world.resource::<SimRate>();
+        assert_eq!(rate.label.as_deref(), Some("sim_rate_test"));
+        assert!(rate.frames_per_second() > 0.0);
+
+        drop(app);
+        std::fs::remove_dir_all(logs).ok();
+    }
+
+    #[test]
+    fn instances_with_the_same_seed_stay_identical() {
+        // A tight threshold makes the first reassignment (tick 500) found new species
+        let instance = |name: &str| {
+            let mut instance = SimInstance::new(name, 7);
+            instance.tuning.initial_spawn_count = 40;
+            instance.tuning.speciation_threshold = 0.05;
+            instance
+        };
+        let (first, second) = (instance("determinism_test_a"), instance("determinism_test_b"));
+        let logs = [first.logs_dir(), second.logs_dir()];
+        let (mut first, mut second) = (first.into_app(), second.into_app());
+
+        for tick in 0..520 {
+            first.update();
+            second.update();
+            assert_eq!(snapshot(&mut first), snapshot(&mut second), "diverged at tick {tick}");
+        }
+        let species: std::collections::HashSet<_> =
+            snapshot(&mut first).into_iter().map(|(_, species, _)| species).collect();
+        assert!(species.len() > 1, "no species were founded to compare");
+
+        drop((first, second));
+        for dir in logs {
+            std::fs::remove_dir_all(dir).ok();
+        }
+    }
+}
@@ -1,20 +1,105 @@
+mod instance;
 mod organisms;
 mod utils;
 mod visualization;
 mod world;
 
 use bevy::prelude::*;
-use organisms::OrganismPlugin;
-use tracing_subscriber::EnvFilter;
-use visualization::VisualizationPlugin;
-use world::WorldPlugin;
+use bevy::winit::WinitSettings;
+use organisms::{
+    diff_world_files, Bottleneck, DeterministicOrder, EcosystemStats, GenomeHotReload,
+    LoggingConfig, OrganismPlugin, TrackedOrganisms,
+};
+use std::path::Path;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{reload, EnvFilter};
+use utils::{advance_sim_time, measure_sim_rate, SimRate, SimTime, SpatialHashGrid};
+use visualization::{CameraConfig, FastForward, LogFilterHandle, Timelapse, VisualizationPlugin};
+use world::{ActiveTerrainGenerator, WorldEdges, WorldPlugin, WorldSeed};
 
 fn main() {
     // Initialize tracing subscriber for better error visibility
     // Default to INFO level if RUST_LOG is not set
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let directives = filter.to_string();
 
-    tracing_subscriber::fmt().with_env_filter(filter).init();
+    // Reloadable so `--fast-forward` can quiet the console until it reaches its target
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    // `--diff a.json b.json`: compare two exported stats snapshots and exit
+    if let Some((path_a, path_b)) = utils::cli::option_pair("diff") {
+        match diff_world_files(Path::new(&path_a), Path::new(&path_b)) {
+            Ok(diff) => print!("{diff}"),
+            Err(err) => {
+                eprintln!("Diff failed: {err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // `--bench-spatial-hash`: time neighbor queries across bucket sizes and exit
+    if utils::cli::has_flag("bench-spatial-hash") {
+        const CELL_SIZES: [f32; 8] = [4.0, 8.0, 12.0, 16.0, 24.0, 32.0, 48.0, 64.0];
+        let timings = utils::benchmark_cell_sizes(20_000, 1000.0, (6.0, 65.0), &CELL_SIZES, 42);
+        println!("cell_size  build_ms  query_ms  candidates/query  neighbors/query");
+        for timing in timings {
+            println!(
+                "{:>9.1}  {:>8.2}  {:>8.2}  {:>16.1}  {:>15.1}",
+                timing.cell_size,
+                timing.build.as_secs_f64() * 1000.0,
+                timing.queries.as_secs_f64() * 1000.0,
+                timing.mean_candidates,
+                timing.mean_neighbors
+            );
+        }
+        return;
+    }
+
+    // `--bench-chunk-generation [count]`: time a burst of new chunks, synchronous vs async, and exit
+    if utils::cli::has_flag("bench-chunk-generation")
+        || utils::cli::option_value("bench-chunk-generation").is_some()
+    {
+        let chunk_count = utils::cli::option_value("bench-chunk-generation")
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(32);
+        let timing = world::benchmark_chunk_generation(
+            chunk_count,
+            &ActiveTerrainGenerator::from_args(),
+            WorldSeed::from_args(),
+        );
+        println!(
+            "{} chunks, synchronous initialize_chunk: {:.2} ms",
+            timing.chunks,
+            timing.synchronous.as_secs_f64() * 1000.0
+        );
+        println!("policy          total_ms  longest_frame_ms");
+        for burst in timing.bursts {
+            let policy = format!("{:?}", burst.policy);
+            println!(
+                "{:<14}  {:>8.2}  {:>16.2}",
+                policy,
+                burst.total.as_secs_f64() * 1000.0,
+                burst.longest_frame.as_secs_f64() * 1000.0
+            );
+        }
+        return;
+    }
+
+    // Ctrl+C shuts down cleanly (windowed or headless) so no buffered CSV rows are lost
+    utils::shutdown::install_interrupt_handler();
+
+    // `--instances <seed_a> <seed_b>`: run two headless simulations side by side and exit
+    // (with `EXTINCTION_EXIT_CODE` if either went extinct)
+    if instance::run_instances_from_args() {
+        return;
+    }
+
+    let deterministic = DeterministicOrder::from_args();
 
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
@@ -25,12 +110,43 @@ fn main() {
             }),
             ..default()
         }))
+        .init_resource::<SimTime>()
+        .insert_resource(SimRate::from_args())
+        .insert_resource(WorldSeed::from_args())
+        .insert_resource(WorldEdges::from_args())
+        .insert_resource(ActiveTerrainGenerator::from_args())
+        .insert_resource(LoggingConfig::from_args())
+        .insert_resource(TrackedOrganisms::from_args())
+        .insert_resource(EcosystemStats::from_args())
+        .insert_resource(GenomeHotReload::from_args())
+        .insert_resource(Bottleneck::from_args())
+        .insert_resource(deterministic)
+        .insert_resource(deterministic.time_update_strategy())
+        .insert_resource(SpatialHashGrid::from_args())
+        .insert_resource(FastForward::from_args())
+        .insert_resource(CameraConfig::from_args())
+        .insert_resource(Timelapse::from_args())
+        .insert_resource(LogFilterHandle {
+            handle: filter_handle,
+            directives,
+        })
         .add_plugins(WorldPlugin)
         .add_plugins(OrganismPlugin)
         .add_plugins(VisualizationPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, update_simulation)
+        .add_systems(First, (advance_sim_time, utils::shutdown::exit_on_interrupt))
+        .add_systems(Last, measure_sim_rate)
+        // Hand control back after the app shuts down so the exit status below is reported
+        .insert_resource(WinitSettings {
+            return_from_run: true,
+            ..default()
+        })
         .run();
+
+    // The app (and its loggers) is dropped by now; report an interrupted run's status
+    if utils::shutdown::interrupted() {
+        std::process::exit(utils::shutdown::INTERRUPTED_EXIT_CODE);
+    }
 }
 
 fn setup(mut commands: Commands) {
@@ -39,8 +155,3 @@ fn setup(mut commands: Commands) {
     info!("Evolution Simulator initialized");
     info!("Core framework ready");
 }
-
-fn update_simulation(_time: Res<Time>) {
-    // Placeholder for simulation tick updates
-    // This will be replaced with proper simulation loop
-}
@@ -1,5 +1,6 @@
 use crate::organisms::components::*;
-use crate::world::{ResourceType, WorldGrid};
+use crate::organisms::decision_trace::DecisionTrace;
+use crate::world::{Cell, ResourceType, TerrainType, WorldGrid, RESOURCE_TYPE_COUNT};
 use bevy::prelude::*;
 use glam::Vec2;
 use std::collections::HashMap;
@@ -21,6 +22,8 @@ pub enum BehaviorState {
     Resting,
     /// Long-range movement toward richer territory
     Migrating,
+    /// Closing in on a predator together with same-species allies instead of fleeing
+    Mobbing,
 }
 
 /// Component tracking organism's current behavior state
@@ -41,6 +44,16 @@ pub struct Behavior {
     pub recent_threat: Option<Vec2>,
     /// Long-range migration target (if any)
     pub migration_target: Option<Vec2>,
+    /// Seconds the current state has gone without progress (see `track_progress`)
+    pub stalled_time: f32,
+    /// Best progress measure seen in the current state (energy while Eating,
+    /// distance to target while Chasing)
+    progress_mark: Option<f32>,
+    /// Heading (radians) used when moving without a target; random-walks each tick
+    /// (None until the organism's stream first picks one)
+    pub wander_heading: Option<f32>,
+    /// Target given up on after stalling, and seconds before it may be picked again
+    abandoned_target: Option<(Vec2, f32)>,
 }
 
 impl Default for Behavior {
@@ -54,6 +67,10 @@ impl Default for Behavior {
             threat_timer: 0.0,
             recent_threat: None,
             migration_target: None,
+            stalled_time: 0.0,
+            progress_mark: None,
+            wander_heading: None,
+            abandoned_target: None,
         }
     }
 }
@@ -63,10 +80,63 @@ impl Behavior {
         Self::default()
     }
 
+    /// Random-walk the wander heading, drawing from the organism's own stream
+    pub fn advance_wander_heading(&mut self, rng: &mut fastrand::Rng, jitter: f32, dt: f32) {
+        let heading = match self.wander_heading {
+            Some(heading) => heading + (rng.f32() * 2.0 - 1.0) * jitter * dt.sqrt(),
+            None => rng.f32() * std::f32::consts::TAU,
+        };
+        self.wander_heading = Some(heading.rem_euclid(std::f32::consts::TAU));
+    }
+
+    /// Let the remembered threat fade for `dt` without a fresh sighting
+    pub fn decay_threat(&mut self, decay_rate: f32, dt: f32) {
+        self.threat_timer = (self.threat_timer - dt * decay_rate).max(0.0);
+        if self.threat_timer <= 0.0 {
+            self.recent_threat = None;
+        }
+    }
+
+    /// Whether the current state has gone without progress for longer than it may
+    pub fn is_stalled(&self) -> bool {
+        max_stalled_time(self.state).is_some_and(|limit| self.stalled_time > limit)
+    }
+
+    /// Where the current state is headed: its target, or the organism itself while Eating in place
+    pub fn current_target(&self, position: Vec2) -> Vec2 {
+        self.target_position.unwrap_or(position)
+    }
+
+    /// Keep away from `target` for `ABANDONED_TARGET_COOLDOWN` seconds
+    pub fn abandon_target(&mut self, target: Vec2) {
+        self.abandoned_target = Some((target, ABANDONED_TARGET_COOLDOWN));
+    }
+
+    /// The target still off-limits after a stall, if any
+    pub fn abandoned_target(&self) -> Option<Vec2> {
+        self.abandoned_target.map(|(target, _)| target)
+    }
+
+    /// Count down the abandoned target's cooldown
+    pub fn age_abandoned_target(&mut self, dt: f32) {
+        if let Some((_, remaining)) = &mut self.abandoned_target {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                self.abandoned_target = None;
+            }
+        }
+    }
+
+    fn wander_direction(&self) -> Vec2 {
+        Vec2::from_angle(self.wander_heading.unwrap_or(0.0))
+    }
+
     pub fn set_state(&mut self, new_state: BehaviorState) {
         if self.state != new_state {
             self.state = new_state;
             self.state_time = 0.0;
+            self.stalled_time = 0.0;
+            self.progress_mark = None;
             // Clear targets when changing states
             self.target_entity = None;
             self.target_position = None;
@@ -75,6 +145,56 @@ impl Behavior {
             }
         }
     }
+
+    /// Update `stalled_time` for states that should be making progress
+    /// Eating progresses while energy rises; Chasing while the target gets closer.
+    /// Other states never stall.
+    pub fn track_progress(&mut self, energy: f32, position: Vec2, dt: f32) {
+        // (measure, whether progress means the measure rising, minimum change that counts)
+        let (measure, rising, min_change) = match (self.state, self.target_position) {
+            (BehaviorState::Eating, _) => (energy, true, 1e-4),
+            (BehaviorState::Chasing, Some(target)) => ((target - position).length(), false, 0.05),
+            (BehaviorState::Chasing, None) => {
+                self.stalled_time += dt;
+                return;
+            }
+            _ => {
+                self.stalled_time = 0.0;
+                self.progress_mark = None;
+                return;
+            }
+        };
+
+        let improved = self.progress_mark.map_or(true, |best| {
+            if rising {
+                measure > best + min_change
+            } else {
+                measure < best - min_change
+            }
+        });
+        if improved {
+            self.progress_mark = Some(measure);
+            self.stalled_time = 0.0;
+        } else {
+            self.stalled_time += dt;
+        }
+    }
+}
+
+/// Seconds a target given up on after stalling stays off-limits, so the organism wanders
+/// somewhere else instead of re-picking it on the very next decision
+const ABANDONED_TARGET_COOLDOWN: f32 = 10.0;
+
+/// Targets this close to an abandoned one count as the same target
+const ABANDONED_TARGET_RADIUS: f32 = 2.0;
+
+/// Longest a state may go without progress before the organism gives up on it
+fn max_stalled_time(state: BehaviorState) -> Option<f32> {
+    match state {
+        BehaviorState::Eating => Some(4.0),
+        BehaviorState::Chasing => Some(8.0),
+        _ => None,
+    }
 }
 
 /// Sensory information about nearby entities
@@ -85,11 +205,17 @@ pub struct SensoryData {
     /// Nearby resources (position, resource_type, distance, value)
     pub nearby_resources: Vec<(Vec2, ResourceType, f32, f32)>,
     /// Current cell resource values
-    pub current_cell_resources: [f32; 6],
+    pub current_cell_resources: [f32; crate::world::RESOURCE_TYPE_COUNT],
     /// Closest predator information
     pub nearest_predator: Option<(Entity, Vec2, f32)>,
     /// Highest value resource in range
     pub richest_resource: Option<(Vec2, ResourceType, f32, f32)>,
+    /// Best fresh carcass in range (position, freshness) - only sensed by decomposers
+    pub nearest_carcass: Option<(Vec2, f32)>,
+    /// Living same-species, same-type organisms in range that aren't a threat
+    pub nearby_allies: u32,
+    /// Resource thresholds the data was collected with
+    pub detection: ResourceDetection,
 }
 
 impl SensoryData {
@@ -97,11 +223,164 @@ impl SensoryData {
         Self {
             nearby_organisms: Vec::new(),
             nearby_resources: Vec::new(),
-            current_cell_resources: [0.0; 6],
+            current_cell_resources: [0.0; crate::world::RESOURCE_TYPE_COUNT],
             nearest_predator: None,
             richest_resource: None,
+            nearest_carcass: None,
+            nearby_allies: 0,
+            detection: ResourceDetection::default(),
+        }
+    }
+
+    /// Drop a resource cell sensed at `position` (and the richest one, if it was that cell)
+    pub fn forget_resource(&mut self, position: Vec2) {
+        self.nearby_resources.retain(|(pos, _, _, _)| *pos != position);
+        if self.richest_resource.is_some_and(|(pos, _, _, _)| pos == position) {
+            self.richest_resource = None;
+        }
+    }
+
+    /// Closest sensed organism passing `predicate` over (is_predator, is_prey, is_mate)
+    /// Returns (entity, position, distance)
+    pub fn nearest_organism_where<F>(&self, mut predicate: F) -> Option<(Entity, Vec2, f32)>
+    where
+        F: FnMut(bool, bool, bool) -> bool,
+    {
+        self.nearby_organisms
+            .iter()
+            .filter(|(_, _, _, is_predator, is_prey, is_mate)| predicate(*is_predator, *is_prey, *is_mate))
+            .min_by(|a, b| {
+                a.2.partial_cmp(&b.2)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(a.0.cmp(&b.0))
+            })
+            .map(|(entity, position, distance, _, _, _)| (*entity, *position, *distance))
+    }
+}
+
+/// Behavior level of detail: organisms far from the focus point re-sense and re-decide only
+/// every `EcosystemTuning::behavior_lod_interval` ticks, staggered by uid so the work is spread
+/// evenly over ticks. The visualization points the focus at the camera each frame; without
+/// one (headless) every organism counts as far away. Tracked organisms always update.
+/// Threat memory keeps fading on skipped ticks, so a far organism doesn't stay scared longer.
+#[derive(Resource, Debug, Default)]
+pub struct BehaviorLod {
+    pub focus: Option<Vec2>,
+    /// Behavior updates skipped since the last report
+    skipped: u64,
+    /// Behavior updates run since the last report
+    updated: u64,
+    /// Wall time spent in `update_behavior` since the last report
+    elapsed: std::time::Duration,
+    /// Ticks timed since the last report
+    timed_ticks: u32,
+}
+
+impl BehaviorLod {
+    /// Whether an organism at `position` updates its behavior this tick
+    pub fn updates(
+        &self,
+        tuning: &crate::organisms::EcosystemTuning,
+        position: Vec2,
+        uid: u64,
+        tick: u64,
+    ) -> bool {
+        let interval = tuning.behavior_lod_interval;
+        if interval <= 1 {
+            return true;
+        }
+        let near = self.focus.is_some_and(|focus| {
+            focus.distance_squared(position) <= tuning.behavior_lod_distance.powi(2)
+        });
+        near || uid.wrapping_add(tick) % interval == 0
+    }
+
+    pub fn record(&mut self, updated: bool) {
+        if updated {
+            self.updated += 1;
+        } else {
+            self.skipped += 1;
+        }
+    }
+
+    /// Add one tick's `update_behavior` wall time
+    pub fn record_time(&mut self, elapsed: std::time::Duration) {
+        self.elapsed += elapsed;
+        self.timed_ticks += 1;
+    }
+
+    /// Share of behavior updates skipped and mean milliseconds per tick spent deciding since
+    /// the last call, then reset the counts
+    pub fn take_report(&mut self) -> (f32, f64) {
+        let total = self.skipped + self.updated;
+        let share = if total == 0 {
+            0.0
+        } else {
+            self.skipped as f32 / total as f32
+        };
+        let mean_ms = if self.timed_ticks == 0 {
+            0.0
+        } else {
+            self.elapsed.as_secs_f64() * 1000.0 / self.timed_ticks as f64
+        };
+        *self = Self {
+            focus: self.focus,
+            ..Self::default()
+        };
+        (share, mean_ms)
+    }
+}
+
+/// Food is only worth targeting (or stopping for) at this multiple of its detection threshold
+const FOOD_THRESHOLD_RATIO: f32 = 2.0;
+
+/// Resource densities an organism notices, per `ResourceType`, and how far it scans for them
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceDetection {
+    pub thresholds: [f32; RESOURCE_TYPE_COUNT],
+    /// Cap on the radius of the resource cell scan, whatever the sensory range
+    /// (organisms are still sensed out to the full range)
+    pub max_scan_range: f32,
+}
+
+impl Default for ResourceDetection {
+    fn default() -> Self {
+        Self {
+            thresholds: [0.1; RESOURCE_TYPE_COUNT],
+            max_scan_range: f32::INFINITY,
+        }
+    }
+}
+
+impl ResourceDetection {
+    /// The tuned thresholds, lowered for organisms with above-average perception acuity and
+    /// raised for those below it (acuity 0.5 leaves them unchanged)
+    pub fn for_organism(tuning: &crate::organisms::EcosystemTuning, acuity: f32) -> Self {
+        let scale = (1.0 - tuning.perception_acuity_strength * (acuity - 0.5) * 2.0).max(0.0);
+        Self {
+            thresholds: tuning.resource_detection_thresholds.map(|threshold| threshold * scale),
+            max_scan_range: if tuning.resource_scan_range_cap > 0.0 {
+                tuning.resource_scan_range_cap
+            } else {
+                f32::INFINITY
+            },
         }
     }
+
+    /// Radius of the resource cell scan for an organism sensing out to `sensory_range`
+    pub fn scan_range(&self, sensory_range: f32) -> f32 {
+        sensory_range.min(self.max_scan_range)
+    }
+
+    /// Whether a cell's density of `resource_type` registers at all
+    pub fn detects(&self, resource_type: ResourceType, value: f32) -> bool {
+        value > self.thresholds[resource_type as usize]
+    }
+
+    /// Whether a cell holds enough `resource_type` to go for (or stay and eat)
+    pub fn is_food(&self, resource_type: ResourceType, value: f32) -> bool {
+        value > self.thresholds[resource_type as usize] * FOOD_THRESHOLD_RATIO
+    }
 }
 
 /// Cache sensory data for organisms that haven't moved much (optimization 3)
@@ -144,6 +423,11 @@ impl SensoryDataCache {
         data
     }
     
+    /// Most recent sensory data computed for an organism
+    pub fn get(&self, entity: Entity) -> Option<&SensoryData> {
+        self.cache.get(&entity).map(|(_, data, _)| data)
+    }
+
     pub fn invalidate(&mut self, entity: Entity) {
         self.cache.remove(&entity);
     }
@@ -154,6 +438,63 @@ impl SensoryDataCache {
     }
 }
 
+/// Line of sight for `collect_sensory_data`: ridges and forest between an organism and
+/// another hide it (built from `EcosystemTuning` only when `sensory_occlusion` is on)
+#[derive(Debug, Clone, Copy)]
+pub struct SensoryOcclusion {
+    /// Height above the sight line (fraction of the elevation range) that blocks sight
+    pub ridge_height: f32,
+    /// Share of sensory range lost per Forest cell crossed
+    pub forest_density: f32,
+}
+
+impl SensoryOcclusion {
+    pub fn from_tuning(tuning: &crate::organisms::EcosystemTuning) -> Option<Self> {
+        tuning.sensory_occlusion.then_some(Self {
+            ridge_height: tuning.occlusion_ridge_height,
+            forest_density: tuning.occlusion_forest_density,
+        })
+    }
+
+    /// Share of sensory range left for seeing `to` from `from` (0 = blocked)
+    /// Marches the cells strictly between the two; a cell rising `ridge_height` above the
+    /// straight line between their elevations blocks sight outright, and each Forest cell
+    /// thins it. Cells in chunks that don't exist yet don't occlude.
+    pub fn visibility(&self, world_grid: &WorldGrid, from: Vec2, to: Vec2) -> f32 {
+        let elevation = |point: Vec2| {
+            world_grid
+                .get_cell(point.x, point.y)
+                .map(|cell| cell.elevation as f32 / u16::MAX as f32)
+        };
+        let steps = from.distance(to).ceil() as usize;
+        if steps < 2 {
+            return 1.0;
+        }
+        let from_elevation = elevation(from).unwrap_or(0.0);
+        let to_elevation = elevation(to).unwrap_or(0.0);
+
+        let mut visibility = 1.0;
+        for step in 1..steps {
+            let t = step as f32 / steps as f32;
+            let point = from.lerp(to, t);
+            let Some(cell) = world_grid.get_cell(point.x, point.y) else {
+                continue;
+            };
+            let sight_line = from_elevation + (to_elevation - from_elevation) * t;
+            if cell.elevation as f32 / u16::MAX as f32 - sight_line > self.ridge_height {
+                return 0.0;
+            }
+            if cell.terrain == TerrainType::Forest {
+                visibility -= self.forest_density;
+                if visibility <= 0.0 {
+                    return 0.0;
+                }
+            }
+        }
+        visibility
+    }
+}
+
 /// Collect sensory information for an organism (OPTIMIZED - optimization 3)
 pub fn collect_sensory_data(
     entity: Entity,
@@ -162,19 +503,24 @@ pub fn collect_sensory_data(
     species_id: SpeciesId,
     organism_type: OrganismType,
     size: f32,
+    sex: Option<Sex>,
     world_grid: &WorldGrid,
     spatial_hash: &crate::utils::SpatialHash,
     organism_query: &Query<
-        (Entity, &Position, &SpeciesId, &OrganismType, &Size, &Energy),
+        (Entity, &Position, &SpeciesId, &OrganismType, &Size, &Energy, Option<&Sex>),
         With<Alive>,
     >,
+    occlusion: Option<SensoryOcclusion>,
+    intraspecific_predation: bool,
+    detection: ResourceDetection,
 ) -> SensoryData {
     let mut sensory = SensoryData::new();
+    sensory.detection = detection;
 
-    // Get current cell resources
-    if let Some(cell) = world_grid.get_cell(position.x, position.y) {
-        sensory.current_cell_resources = cell.resource_density;
-    }
+    // Get current cell resources (empty if the chunk hasn't been created yet)
+    sensory.current_cell_resources = world_grid
+        .get_cell_or_default(position.x, position.y)
+        .resource_density;
 
     // Query nearby organisms using spatial hash (much faster than iterating all)
     let nearby_entities = spatial_hash.query_radius(position, sensory_range);
@@ -185,24 +531,47 @@ pub fn collect_sensory_data(
             continue; // Skip self
         }
 
-        if let Ok((_, other_pos, other_species, other_type, other_size, other_energy)) =
+        if let Ok((_, other_pos, other_species, other_type, other_size, other_energy, other_sex)) =
             organism_query.get(other_entity)
         {
             // Use squared distance to avoid sqrt
             let distance_sq = (position - other_pos.0).length_squared();
             if distance_sq <= sensory_range_sq {
                 let distance = distance_sq.sqrt(); // Only compute sqrt when needed
-                let is_predator =
-                    is_predator_of(organism_type, *other_type, other_size.value(), size);
-                let is_prey = is_prey_of(organism_type, *other_type, size, other_size.value());
+                // Terrain in the way shortens (or blocks) how far this organism can be seen
+                if let Some(occlusion) = occlusion {
+                    let visibility = occlusion.visibility(world_grid, position, other_pos.0);
+                    if distance > sensory_range * visibility {
+                        continue;
+                    }
+                }
+                // Without cannibalism, members of the same species are neither threat nor food
+                let may_hunt = intraspecific_predation || *other_species != species_id;
+                let is_predator = may_hunt
+                    && is_predator_of(organism_type, *other_type, other_size.value(), size);
+                let is_prey =
+                    may_hunt && is_prey_of(organism_type, *other_type, size, other_size.value());
                 let is_mate = *other_species == species_id
+                    && Sex::compatible(sex, other_sex.copied())
                     && *other_type == organism_type
                     && !other_energy.is_dead()
                     && distance_sq <= (sensory_range * 0.5).powi(2); // Use squared for mate check
 
+                if *other_species == species_id
+                    && *other_type == organism_type
+                    && !is_predator
+                    && !other_energy.is_dead()
+                {
+                    sensory.nearby_allies += 1;
+                }
+
+                // Same rule as `SpatialHash::nearest_where` (ties go to the lower entity), kept
+                // inline because this pass already visits every neighbor
                 if is_predator {
                     match &mut sensory.nearest_predator {
-                        Some((_, _, current_distance)) if *current_distance <= distance => {}
+                        Some((current, _, current_distance))
+                            if *current_distance < distance
+                                || (*current_distance == distance && *current < other_entity) => {}
                         _ => sensory.nearest_predator = Some((other_entity, other_pos.0, distance)),
                     }
                 }
@@ -220,15 +589,18 @@ pub fn collect_sensory_data(
     }
 
     // OPTIMIZED: Find nearby resource-rich cells with early termination (optimization 3)
+    // With a cap set, the scan covers at most (2 * max_scan_range + 1)^2 cells, however far
+    // senses evolve
+    let scan_range = detection.scan_range(sensory_range);
     let cell_size = 1.0;
-    let search_radius = (sensory_range / cell_size).ceil() as i32;
-    let sensory_range_sq = sensory_range * sensory_range;
+    let search_radius = (scan_range / cell_size).ceil() as i32;
+    let sensory_range_sq = scan_range * scan_range;
     
     // Pre-compute bounds to avoid redundant checks
-    let min_x = (position.x - sensory_range) as i32;
-    let max_x = (position.x + sensory_range) as i32;
-    let min_y = (position.y - sensory_range) as i32;
-    let max_y = (position.y + sensory_range) as i32;
+    let min_x = (position.x - scan_range) as i32;
+    let max_x = (position.x + scan_range) as i32;
+    let min_y = (position.y - scan_range) as i32;
+    let max_y = (position.y + scan_range) as i32;
     
     let mut best_resource_value = 0.0f32;
     const MAX_RESOURCES_TO_CHECK: usize = 20; // Early termination limit
@@ -276,7 +648,7 @@ pub fn collect_sensory_data(
 
                 for resource_type in resource_types.iter() {
                     let value = cell.get_resource(*resource_type);
-                    if value > 0.1 {
+                    if detection.detects(*resource_type, value) {
                         let distance = distance_sq.sqrt();
                         let entry = (Vec2::new(check_x, check_y), *resource_type, distance, value);
                         
@@ -342,6 +714,37 @@ fn is_prey_of(
     is_predator_of(predator_type, prey_type, predator_size, prey_size)
 }
 
+/// When a threatened consumer mobs its predator instead of fleeing
+#[derive(Debug, Clone, Copy)]
+pub struct MobbingRule {
+    /// Allies that must be in sensory range (never below one, so lone organisms always flee)
+    pub ally_threshold: u32,
+    /// Mean of aggression and boldness needed to stand and fight
+    pub trait_threshold: f32,
+}
+
+impl MobbingRule {
+    pub fn from_tuning(tuning: &crate::organisms::EcosystemTuning) -> Self {
+        Self {
+            ally_threshold: tuning.mobbing_ally_threshold,
+            trait_threshold: tuning.mobbing_trait_threshold,
+        }
+    }
+
+    /// A rule no organism satisfies (always flee)
+    pub fn disabled() -> Self {
+        Self {
+            ally_threshold: u32::MAX,
+            trait_threshold: f32::INFINITY,
+        }
+    }
+
+    /// Whether an organism with these traits and allies mobs rather than flees
+    pub fn should_mob(&self, aggression: f32, boldness: f32, allies: u32) -> bool {
+        allies >= self.ally_threshold.max(1) && (aggression + boldness) * 0.5 >= self.trait_threshold
+    }
+}
+
 pub struct BehaviorDecision {
     pub state: BehaviorState,
     pub target_entity: Option<Entity>,
@@ -349,6 +752,12 @@ pub struct BehaviorDecision {
     pub migration_target: Option<Vec2>,
 }
 
+/// Decide the next behavior, giving up on a state that has stopped making progress
+/// An organism that would stay Eating without gaining energy, or keep Chasing a target
+/// it isn't closing on, for longer than `max_stalled_time` goes back to Wandering.
+/// Until the cooldown on that `abandoned_target` runs out, Eating or Chasing it again is
+/// refused as well, so a stalled organism doesn't loop straight back to the same spot.
+/// When `trace` is given it receives the branch taken and the values it was decided on.
 pub fn decide_behavior_with_memory(
     energy: &Energy,
     cached_traits: &crate::organisms::components::CachedTraits,
@@ -356,14 +765,73 @@ pub fn decide_behavior_with_memory(
     sensory: &SensoryData,
     current_state: BehaviorState,
     state_time: f32,
+    stalled_time: f32,
     hunger_memory: f32,
     threat_timer: f32,
     recent_threat: Option<Vec2>,
     has_migration_target: bool,
+    position: Vec2,
+    abandoned_target: Option<Vec2>,
+    mobbing: MobbingRule,
+    trace: Option<&mut DecisionTrace>,
+) -> BehaviorDecision {
+    let mut untraced = DecisionTrace::default();
+    let trace = trace.unwrap_or(&mut untraced);
+    let decision = choose_behavior(
+        energy,
+        cached_traits,
+        organism_type,
+        sensory,
+        current_state,
+        state_time,
+        hunger_memory,
+        threat_timer,
+        recent_threat,
+        has_migration_target,
+        mobbing,
+        trace,
+    );
+
+    let stuck = max_stalled_time(current_state).is_some_and(|limit| stalled_time > limit);
+    let revisits = abandoned_target.is_some_and(|abandoned| {
+        matches!(decision.state, BehaviorState::Eating | BehaviorState::Chasing)
+            && decision.target_position.unwrap_or(position).distance(abandoned)
+                <= ABANDONED_TARGET_RADIUS
+    });
+    if (stuck && decision.state == current_state) || revisits {
+        trace.branch = if revisits {
+            "abandoned target: keep wandering"
+        } else {
+            "stalled: give up"
+        };
+        return BehaviorDecision {
+            state: BehaviorState::Wandering,
+            target_entity: None,
+            target_position: None,
+            migration_target: None,
+        };
+    }
+    decision
+}
+
+fn choose_behavior(
+    energy: &Energy,
+    cached_traits: &crate::organisms::components::CachedTraits,
+    organism_type: OrganismType,
+    sensory: &SensoryData,
+    current_state: BehaviorState,
+    state_time: f32,
+    hunger_memory: f32,
+    threat_timer: f32,
+    recent_threat: Option<Vec2>,
+    has_migration_target: bool,
+    mobbing: MobbingRule,
+    trace: &mut DecisionTrace,
 ) -> BehaviorDecision {
     // Step 8: Improved behavior differentiation between organism types
     // Priority system: Survival > Reproduction > Exploration
-    
+    trace.allies = sensory.nearby_allies;
+
     let aggression = cached_traits.aggression;
     let boldness = cached_traits.boldness;
     let risk_tolerance = cached_traits.risk_tolerance;
@@ -375,9 +843,12 @@ pub fn decide_behavior_with_memory(
         
         let hunger_pressure = ((1.0 - energy.ratio()).max(0.0) * 0.8) + (hunger_memory * 0.2);
         let hunger_barrier = 0.4; // Producers are less sensitive to hunger
+        trace.hunger_pressure = Some(hunger_pressure);
+        trace.hunger_barrier = Some(hunger_barrier);
         
         if hunger_pressure > hunger_barrier {
             if is_at_food_source(organism_type, sensory) {
+                trace.branch = "producer: eat at food source";
                 return BehaviorDecision {
                     state: BehaviorState::Eating,
                     target_entity: None,
@@ -390,8 +861,10 @@ pub fn decide_behavior_with_memory(
                 organism_type,
                 sensory,
                 cached_traits.resource_selectivity,
+                cached_traits.diet_bias,
             ) {
                 if matches!(current_state, BehaviorState::Eating) && state_time < 5.0 {
+                    trace.branch = "producer: keep eating";
                     return BehaviorDecision {
                         state: BehaviorState::Eating,
                         target_entity: None,
@@ -401,6 +874,7 @@ pub fn decide_behavior_with_memory(
                 }
                 // Only move if resources are very low
                 if energy.ratio() < 0.3 {
+                    trace.branch = "producer: move to food";
                     return BehaviorDecision {
                         state: BehaviorState::Chasing,
                         target_entity: None,
@@ -413,6 +887,7 @@ pub fn decide_behavior_with_memory(
         
         // Producers rest when low energy (conserving resources)
         if energy.ratio() < 0.2 {
+            trace.branch = "producer: rest";
             return BehaviorDecision {
                 state: BehaviorState::Resting,
                 target_entity: None,
@@ -422,6 +897,7 @@ pub fn decide_behavior_with_memory(
         }
         
         // Producers mostly stay in place (wandering is minimal)
+        trace.branch = "producer: stay";
         return BehaviorDecision {
             state: BehaviorState::Wandering,
             target_entity: None,
@@ -437,9 +913,30 @@ pub fn decide_behavior_with_memory(
         
         let hunger_pressure = ((1.0 - energy.ratio()).max(0.0) * 0.6) + (hunger_memory * 0.4);
         let hunger_barrier = 0.35; // Decomposers are moderately sensitive
+        trace.hunger_pressure = Some(hunger_pressure);
+        trace.hunger_barrier = Some(hunger_barrier);
+
+        // Fresh carcasses are worth a trip even when only mildly hungry - sit on them
+        if let Some((carcass, freshness)) = sensory.nearest_carcass {
+            if hunger_pressure > hunger_barrier * (1.0 - freshness * 0.5) {
+                let state = if is_at_food_source(organism_type, sensory) {
+                    BehaviorState::Eating
+                } else {
+                    BehaviorState::Chasing
+                };
+                trace.branch = "decomposer: go to carcass";
+                return BehaviorDecision {
+                    state,
+                    target_entity: None,
+                    target_position: Some(carcass),
+                    migration_target: None,
+                };
+            }
+        }
         
         if hunger_pressure > hunger_barrier {
             if is_at_food_source(organism_type, sensory) {
+                trace.branch = "decomposer: eat at food source";
                 return BehaviorDecision {
                     state: BehaviorState::Eating,
                     target_entity: None,
@@ -453,8 +950,10 @@ pub fn decide_behavior_with_memory(
                 organism_type,
                 sensory,
                 cached_traits.resource_selectivity,
+                cached_traits.diet_bias,
             ) {
                 if matches!(current_state, BehaviorState::Eating) && state_time < 3.0 {
+                    trace.branch = "decomposer: keep eating";
                     return BehaviorDecision {
                         state: BehaviorState::Eating,
                         target_entity: None,
@@ -462,6 +961,7 @@ pub fn decide_behavior_with_memory(
                         migration_target: None,
                     };
                 }
+                trace.branch = "decomposer: move to food";
                 return BehaviorDecision {
                     state: BehaviorState::Chasing,
                     target_entity: None,
@@ -473,6 +973,7 @@ pub fn decide_behavior_with_memory(
         
         // Decomposers rest when low energy
         if energy.ratio() < 0.2 {
+            trace.branch = "decomposer: rest";
             return BehaviorDecision {
                 state: BehaviorState::Resting,
                 target_entity: None,
@@ -482,6 +983,7 @@ pub fn decide_behavior_with_memory(
         }
         
         // Decomposers wander slowly looking for detritus
+        trace.branch = "decomposer: wander";
         return BehaviorDecision {
             state: BehaviorState::Wandering,
             target_entity: None,
@@ -495,9 +997,21 @@ pub fn decide_behavior_with_memory(
     if let Some((entity, pred_pos, distance)) = sensory.nearest_predator {
         let flee_threshold = 8.0 + (boldness * 14.0) + (risk_tolerance * 6.0);
         let memory_bonus = if threat_timer > 0.0 { 5.0 } else { 0.0 };
+        trace.predator_distance = Some(distance);
+        trace.flee_threshold = Some(flee_threshold + memory_bonus);
         if distance < flee_threshold + memory_bonus {
+            // Bold, aggressive organisms with enough allies around turn on the predator
+            let state = if mobbing.should_mob(aggression, boldness, sensory.nearby_allies) {
+                BehaviorState::Mobbing
+            } else {
+                BehaviorState::Fleeing
+            };
+            trace.branch = match state {
+                BehaviorState::Mobbing => "mob predator",
+                _ => "flee predator",
+            };
             return BehaviorDecision {
-                state: BehaviorState::Fleeing,
+                state,
                 target_entity: Some(entity),
                 target_position: Some(pred_pos),
                 migration_target: None,
@@ -506,6 +1020,7 @@ pub fn decide_behavior_with_memory(
     } else if threat_timer > 0.0 {
         // Keep fleeing briefly even when predator left
         if let Some(threat_pos) = recent_threat {
+            trace.branch = "flee remembered threat";
             return BehaviorDecision {
                 state: BehaviorState::Fleeing,
                 target_entity: None,
@@ -517,28 +1032,30 @@ pub fn decide_behavior_with_memory(
 
     let hunger_pressure = ((1.0 - energy.ratio()).max(0.0) * 0.7) + (hunger_memory * 0.3);
     let hunger_barrier = (0.3 - cached_traits.foraging_drive * 0.15).clamp(0.1, 0.5);
+    trace.hunger_pressure = Some(hunger_pressure);
+    trace.hunger_barrier = Some(hunger_barrier);
 
     if hunger_pressure > hunger_barrier {
         // Consumers actively hunt prey
         if energy.ratio() > 0.4 && aggression > 0.4 {
-            if let Some((entity, prey_pos, distance, _, _is_prey, _)) = sensory
-                .nearby_organisms
-                .iter()
-                .filter(|(_, _, _, _, is_prey, _)| *is_prey)
-                .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+            if let Some((entity, prey_pos, distance)) =
+                sensory.nearest_organism_where(|_, is_prey, _| is_prey)
             {
-                if *distance < 5.0 {
+                trace.prey_distance = Some(distance);
+                if distance < 5.0 {
+                    trace.branch = "hunt: strike prey";
                     return BehaviorDecision {
                         state: BehaviorState::Eating,
-                        target_entity: Some(*entity),
-                        target_position: Some(*prey_pos),
+                        target_entity: Some(entity),
+                        target_position: Some(prey_pos),
                         migration_target: None,
                     };
-                } else if *distance < 30.0 {
+                } else if distance < 30.0 {
+                    trace.branch = "hunt: chase prey";
                     return BehaviorDecision {
                         state: BehaviorState::Chasing,
-                        target_entity: Some(*entity),
-                        target_position: Some(*prey_pos),
+                        target_entity: Some(entity),
+                        target_position: Some(prey_pos),
                         migration_target: None,
                     };
                 }
@@ -550,8 +1067,10 @@ pub fn decide_behavior_with_memory(
             organism_type,
             sensory,
             cached_traits.resource_selectivity,
+            cached_traits.diet_bias,
         ) {
             if matches!(current_state, BehaviorState::Eating) && state_time < 2.0 {
+                trace.branch = "forage: keep eating";
                 return BehaviorDecision {
                     state: BehaviorState::Eating,
                     target_entity: None,
@@ -559,6 +1078,7 @@ pub fn decide_behavior_with_memory(
                     migration_target: None,
                 };
             }
+            trace.branch = "forage: move to food";
             return BehaviorDecision {
                 state: BehaviorState::Chasing,
                 target_entity: None,
@@ -568,6 +1088,7 @@ pub fn decide_behavior_with_memory(
         }
 
         if is_at_food_source(organism_type, sensory) {
+            trace.branch = "forage: eat at food source";
             return BehaviorDecision {
                 state: BehaviorState::Eating,
                 target_entity: None,
@@ -579,17 +1100,16 @@ pub fn decide_behavior_with_memory(
 
     let reproduction_threshold = cached_traits.reproduction_threshold;
     if energy.ratio() >= reproduction_threshold {
-        if let Some((entity, mate_pos, distance, _, _, _is_mate)) = sensory
-            .nearby_organisms
-            .iter()
-            .filter(|(_, _, _, _, _, is_mate)| *is_mate)
-            .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+        if let Some((entity, mate_pos, distance)) =
+            sensory.nearest_organism_where(|_, _, is_mate| is_mate)
         {
-            if *distance < 15.0 {
+            trace.mate_distance = Some(distance);
+            if distance < 15.0 {
+                trace.branch = "mate";
                 return BehaviorDecision {
                     state: BehaviorState::Mating,
-                    target_entity: Some(*entity),
-                    target_position: Some(*mate_pos),
+                    target_entity: Some(entity),
+                    target_position: Some(mate_pos),
                     migration_target: None,
                 };
             }
@@ -597,6 +1117,7 @@ pub fn decide_behavior_with_memory(
     }
 
     if energy.ratio() < 0.15 {
+        trace.branch = "rest";
         return BehaviorDecision {
             state: BehaviorState::Resting,
             target_entity: None,
@@ -610,6 +1131,7 @@ pub fn decide_behavior_with_memory(
         && sensory.nearby_resources.is_empty()
     {
         if let Some((target_pos, _, _, _)) = sensory.richest_resource {
+            trace.branch = "migrate";
             return BehaviorDecision {
                 state: BehaviorState::Migrating,
                 target_entity: None,
@@ -619,6 +1141,7 @@ pub fn decide_behavior_with_memory(
         }
     }
 
+    trace.branch = "wander";
     BehaviorDecision {
         state: BehaviorState::Wandering,
         target_entity: None,
@@ -644,8 +1167,13 @@ pub fn decide_behavior(
         state_time,
         0.0,
         0.0,
+        0.0,
         None,
         false,
+        Vec2::ZERO,
+        None,
+        MobbingRule::disabled(),
+        None,
     );
     (
         decision.state,
@@ -656,13 +1184,14 @@ pub fn decide_behavior(
 
 /// Find the best food source for an organism type
 fn find_best_food_source(organism_type: OrganismType, sensory: &SensoryData) -> Option<Vec2> {
-    find_best_food_source_weighted(organism_type, sensory, 0.0)
+    find_best_food_source_weighted(organism_type, sensory, 0.0, 0.5)
 }
 
 fn find_best_food_source_weighted(
     organism_type: OrganismType,
     sensory: &SensoryData,
     selectivity: f32,
+    diet_bias: f32,
 ) -> Option<Vec2> {
     let preferred_resources = match organism_type {
         OrganismType::Producer => vec![
@@ -680,11 +1209,13 @@ fn find_best_food_source_weighted(
             continue;
         }
 
-        if *value <= 0.2 {
+        if !sensory.detection.is_food(*resource_type, *value) {
             continue;
         }
 
-        let score = value * (1.0 + selectivity) - distance * (0.1 + (1.0 - selectivity) * 0.05);
+        let preference = diet_preference(organism_type, *resource_type, diet_bias);
+        let score = value * (1.0 + selectivity) * preference
+            - distance * (0.1 + (1.0 - selectivity) * 0.05);
         match &best {
             Some((_, best_score)) if score <= *best_score => {}
             _ => best = Some((*pos, score)),
@@ -694,6 +1225,45 @@ fn find_best_food_source_weighted(
     best.map(|(pos, _)| pos)
 }
 
+/// Food score multiplier from diet bias (1.0 = neutral)
+/// Consumers slide between Plant (bias 0) and Prey (bias 1); other types ignore the bias.
+fn diet_preference(organism_type: OrganismType, resource_type: ResourceType, diet_bias: f32) -> f32 {
+    match (organism_type, resource_type) {
+        (OrganismType::Consumer, ResourceType::Plant) => 2.0 * (1.0 - diet_bias),
+        (OrganismType::Consumer, ResourceType::Prey) => 2.0 * diet_bias,
+        _ => 1.0,
+    }
+}
+
+/// Food cell a chasing organism is heading for, if it has been grazed below `threshold` since
+/// it was sensed (chases after prey organisms aren't affected)
+pub fn depleted_food_target(
+    behavior: &Behavior,
+    organism_type: OrganismType,
+    world_grid: &WorldGrid,
+    threshold: f32,
+) -> Option<Vec2> {
+    behavior
+        .target_position
+        .filter(|_| behavior.state == BehaviorState::Chasing && behavior.target_entity.is_none())
+        .filter(|target| {
+            let cell = world_grid.get_cell_or_default(target.x, target.y);
+            food_value(organism_type, &cell) < threshold
+        })
+}
+
+/// Density of the richest food an organism type chases in a cell
+pub fn food_value(organism_type: OrganismType, cell: &Cell) -> f32 {
+    let food: &[ResourceType] = match organism_type {
+        OrganismType::Producer => &[ResourceType::Sunlight, ResourceType::Water, ResourceType::Mineral],
+        OrganismType::Consumer => &[ResourceType::Prey, ResourceType::Plant],
+        OrganismType::Decomposer => &[ResourceType::Detritus],
+    };
+    food.iter()
+        .map(|resource_type| cell.get_resource(*resource_type))
+        .fold(0.0, f32::max)
+}
+
 /// Check if organism is at a food source
 fn is_at_food_source(organism_type: OrganismType, sensory: &SensoryData) -> bool {
     let preferred_resources = match organism_type {
@@ -704,7 +1274,10 @@ fn is_at_food_source(organism_type: OrganismType, sensory: &SensoryData) -> bool
 
     for resource_type in preferred_resources {
         let idx = resource_type as usize;
-        if sensory.current_cell_resources[idx] > 0.2 {
+        if sensory
+            .detection
+            .is_food(resource_type, sensory.current_cell_resources[idx])
+        {
             return true;
         }
     }
@@ -712,14 +1285,122 @@ fn is_at_food_source(organism_type: OrganismType, sensory: &SensoryData) -> bool
     false
 }
 
-/// Calculate velocity for a behavior state
+/// Whether the cell at a world position is terrain the organism can't enter
+/// Cells that don't exist yet are treated as passable.
+pub fn is_impassable(world_grid: &WorldGrid, position: Vec2, impassable: &[TerrainType]) -> bool {
+    !impassable.is_empty()
+        && world_grid
+            .get_cell(position.x, position.y)
+            .is_some_and(|cell| impassable.contains(&cell.terrain))
+}
+
+/// Turn a velocity away from impassable terrain just ahead
+/// Tries progressively sharper turns (alternating sides) until the look-ahead point is
+/// passable; stops if every direction is blocked.
+fn steer_around_obstacles(
+    velocity: Vec2,
+    position: Vec2,
+    world_grid: &WorldGrid,
+    impassable: &[TerrainType],
+) -> Vec2 {
+    let speed = velocity.length();
+    if impassable.is_empty() || speed <= f32::EPSILON {
+        return velocity;
+    }
+
+    let look_ahead = (speed * 0.5).max(1.5);
+    let heading = velocity / speed;
+    const TURNS_DEGREES: [f32; 9] = [0.0, 30.0, -30.0, 60.0, -60.0, 90.0, -90.0, 135.0, -135.0];
+    for turn in TURNS_DEGREES {
+        let direction = Vec2::from_angle(turn.to_radians()).rotate(heading);
+        if !is_impassable(world_grid, position + direction * look_ahead, impassable) {
+            return direction * speed;
+        }
+    }
+    Vec2::ZERO
+}
+
+/// Calculate velocity for a behavior state, steering around impassable terrain
 pub fn calculate_behavior_velocity(
     behavior: &Behavior,
     position: Vec2,
     cached_traits: &crate::organisms::components::CachedTraits,
     _organism_type: OrganismType,
     energy: &Energy,
-    time: f32,
+    world_grid: &WorldGrid,
+    impassable: &[TerrainType],
+    thermotaxis: Option<Thermotaxis>,
+    territory_avoidance: Vec2,
+) -> Vec2 {
+    let mut velocity = behavior_velocity(behavior, position, cached_traits, _organism_type, energy);
+    // Only undirected movement bends toward comfort or away from rivals' territory; chases,
+    // flight and mating keep their aim
+    if matches!(behavior.state, BehaviorState::Wandering | BehaviorState::Migrating) {
+        let mut steering = territory_avoidance;
+        if let Some(thermotaxis) = thermotaxis {
+            steering += thermotaxis.steering(position, world_grid);
+        }
+        velocity += steering * velocity.length();
+    }
+    steer_around_obstacles(velocity, position, world_grid, impassable)
+}
+
+/// Heading toward comfortable temperatures for thermally stressed organisms
+/// (built from `EcosystemTuning` only when `thermotaxis_strength` is positive)
+#[derive(Debug, Clone, Copy)]
+pub struct Thermotaxis {
+    pub strength: f32,
+    pub sample_distance: f32,
+    pub comfort_range: f32,
+}
+
+impl Thermotaxis {
+    pub fn from_tuning(tuning: &crate::organisms::EcosystemTuning) -> Option<Self> {
+        (tuning.thermotaxis_strength > 0.0).then_some(Self {
+            strength: tuning.thermotaxis_strength,
+            sample_distance: tuning.thermotaxis_sample_distance.max(1.0),
+            comfort_range: tuning.thermal_comfort_range,
+        })
+    }
+
+    /// Steering term (as a fraction of current speed) toward cells closer to the ideal temperature
+    /// Eight cells `sample_distance` away are compared with the organism's own cell; each pulls
+    /// in proportion to how much more comfortable it is. The pull is scaled by the organism's
+    /// current thermal stress, so comfortable organisms aren't steered at all.
+    pub fn steering(&self, position: Vec2, world_grid: &WorldGrid) -> Vec2 {
+        use crate::organisms::stress::{thermal_stress, IDEAL_TEMPERATURE};
+
+        let Some(here) = world_grid.get_cell(position.x, position.y) else {
+            return Vec2::ZERO;
+        };
+        let stress = thermal_stress(here.temperature, self.comfort_range);
+        if stress <= 0.0 {
+            return Vec2::ZERO;
+        }
+        let discomfort_here = (here.temperature - IDEAL_TEMPERATURE).abs();
+
+        let mut pull = Vec2::ZERO;
+        for step in 0..8 {
+            let direction = Vec2::from_angle(step as f32 * std::f32::consts::FRAC_PI_4);
+            let sample = position + direction * self.sample_distance;
+            if let Some(cell) = world_grid.get_cell(sample.x, sample.y) {
+                let improvement = discomfort_here - (cell.temperature - IDEAL_TEMPERATURE).abs();
+                if improvement > 0.0 {
+                    pull += direction * improvement;
+                }
+            }
+        }
+        pull.normalize_or_zero() * self.strength * stress
+    }
+}
+
+/// Velocity a behavior state asks for, ignoring terrain
+fn behavior_velocity(
+    behavior: &Behavior,
+    position: Vec2,
+    cached_traits: &crate::organisms::components::CachedTraits,
+    _organism_type: OrganismType,
+    energy: &Energy,
 ) -> Vec2 {
     let max_speed = cached_traits.speed;
     let speed_factor = energy.ratio().max(0.3); // Minimum 30% speed even when low energy
@@ -733,9 +1414,17 @@ pub fn calculate_behavior_velocity(
                 let direction = (position - flee_from).normalize_or_zero();
                 direction * current_speed // Flee at max speed
             } else {
-                // Random direction if no target
-                let angle = (time * 2.0).sin() * std::f32::consts::PI;
-                Vec2::from_angle(angle) * current_speed
+                // Own random heading if no target
+                behavior.wander_direction() * current_speed
+            }
+        }
+        BehaviorState::Mobbing => {
+            match behavior.target_position {
+                // Close in, then hold position around the predator
+                Some(target) if position.distance_squared(target) > 4.0 => {
+                    (target - position).normalize_or_zero() * current_speed
+                }
+                _ => Vec2::ZERO,
             }
         }
         BehaviorState::Chasing => {
@@ -769,9 +1458,7 @@ pub fn calculate_behavior_velocity(
                 let direction = (target - position).normalize_or_zero();
                 direction * current_speed * 0.8
             } else {
-                let angle = (time * 0.4 + (position.x * 0.3) + (position.y * 0.17)).cos()
-                    * std::f32::consts::TAU;
-                Vec2::from_angle(angle) * current_speed * 0.5
+                behavior.wander_direction() * current_speed * 0.5
             }
         }
         BehaviorState::Wandering => {
@@ -781,10 +1468,273 @@ pub fn calculate_behavior_velocity(
                 OrganismType::Decomposer => 0.4, // Decomposers move slowly
                 OrganismType::Consumer => 0.7, // Consumers move more actively
             };
-            // Random walk with occasional direction changes
-            let angle =
-                (time * 0.5 + (position.x + position.y) * 0.1).sin() * std::f32::consts::TAU;
-            Vec2::from_angle(angle) * current_speed * wander_speed_mult
+            // Random walk on the organism's own heading
+            behavior.wander_direction() * current_speed * wander_speed_mult
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::genetics::{Genome, GENOME_SIZE};
+
+    #[test]
+    fn threat_memory_fades_and_clears() {
+        let mut behavior = Behavior::new();
+        behavior.threat_timer = 1.0;
+        behavior.recent_threat = Some(Vec2::new(3.0, 4.0));
+
+        behavior.decay_threat(0.5, 1.0);
+        assert!((behavior.threat_timer - 0.5).abs() < 1e-6);
+        assert!(behavior.recent_threat.is_some());
+
+        behavior.decay_threat(0.5, 1.0);
+        assert_eq!(behavior.threat_timer, 0.0);
+        assert!(behavior.recent_threat.is_none());
+    }
+
+    fn decide_decomposer(
+        carcass: Vec2,
+        current_state: BehaviorState,
+        stalled_time: f32,
+        abandoned_target: Option<Vec2>,
+    ) -> BehaviorDecision {
+        let genome = Genome::new(vec![0.5; GENOME_SIZE]);
+        let traits = CachedTraits::from_genome(&genome);
+        let mut sensory = SensoryData::new();
+        sensory.nearest_carcass = Some((carcass, 1.0));
+        decide_behavior_with_memory(
+            &Energy::with_energy(100.0, 10.0),
+            &traits,
+            OrganismType::Decomposer,
+            &sensory,
+            current_state,
+            0.0,
+            stalled_time,
+            0.0,
+            0.0,
+            None,
+            false,
+            Vec2::ZERO,
+            abandoned_target,
+            MobbingRule::disabled(),
+            None,
+        )
+    }
+
+    #[test]
+    fn stalled_chase_does_not_loop_back_to_its_target() {
+        let carcass = Vec2::new(10.0, 0.0);
+        let chase = decide_decomposer(carcass, BehaviorState::Wandering, 0.0, None);
+        assert_eq!(chase.state, BehaviorState::Chasing);
+
+        let give_up = decide_decomposer(carcass, BehaviorState::Chasing, 9.0, None);
+        assert_eq!(give_up.state, BehaviorState::Wandering);
+
+        // The next decision would pick the same carcass again without the cooldown
+        let next = decide_decomposer(carcass, BehaviorState::Wandering, 0.0, Some(carcass));
+        assert_eq!(next.state, BehaviorState::Wandering);
+
+        // Other targets are still fair game
+        let elsewhere =
+            decide_decomposer(Vec2::new(50.0, 0.0), BehaviorState::Wandering, 0.0, Some(carcass));
+        assert_eq!(elsewhere.state, BehaviorState::Chasing);
+    }
+
+    #[test]
+    fn abandoned_target_expires_after_cooldown() {
+        let mut behavior = Behavior::new();
+        behavior.abandon_target(Vec2::new(1.0, 2.0));
+        behavior.age_abandoned_target(ABANDONED_TARGET_COOLDOWN * 0.5);
+        assert_eq!(behavior.abandoned_target(), Some(Vec2::new(1.0, 2.0)));
+        behavior.age_abandoned_target(ABANDONED_TARGET_COOLDOWN);
+        assert_eq!(behavior.abandoned_target(), None);
+    }
+
+    #[test]
+    fn headless_lod_staggers_updates_by_uid() {
+        let tuning = crate::organisms::EcosystemTuning {
+            behavior_lod_interval: 4,
+            ..Default::default()
+        };
+        let lod = BehaviorLod::default();
+        for uid in 0..8 {
+            let updates = (0..4)
+                .filter(|tick| lod.updates(&tuning, Vec2::ZERO, uid, *tick))
+                .count();
+            assert_eq!(updates, 1, "uid {uid} should update once per interval");
+        }
+    }
+
+    #[test]
+    fn high_acuity_detects_a_cell_low_acuity_ignores() {
+        let tuning = crate::organisms::EcosystemTuning {
+            perception_acuity_strength: 0.5,
+            ..Default::default()
+        };
+        let sharp = ResourceDetection::for_organism(&tuning, 1.0);
+        let average = ResourceDetection::for_organism(&tuning, 0.5);
+        let dull = ResourceDetection::for_organism(&tuning, 0.0);
+
+        // Just above the tuned threshold for an average organism
+        let sparse = tuning.resource_detection_thresholds[ResourceType::Plant.index()] * 1.2;
+        assert!(sharp.detects(ResourceType::Plant, sparse));
+        assert!(average.detects(ResourceType::Plant, sparse));
+        assert!(!dull.detects(ResourceType::Plant, sparse));
+
+        // Below the average threshold only sharp senses pick it up
+        let faint = tuning.resource_detection_thresholds[ResourceType::Plant.index()] * 0.8;
+        assert!(sharp.detects(ResourceType::Plant, faint));
+        assert!(!average.detects(ResourceType::Plant, faint));
+    }
+
+    #[test]
+    fn acuity_has_no_effect_at_zero_strength() {
+        let tuning = crate::organisms::EcosystemTuning {
+            perception_acuity_strength: 0.0,
+            ..Default::default()
+        };
+        let sharp = ResourceDetection::for_organism(&tuning, 1.0);
+        let dull = ResourceDetection::for_organism(&tuning, 0.0);
+        assert_eq!(sharp.thresholds, dull.thresholds);
+    }
+
+    #[test]
+    fn scan_range_is_uncapped_by_default() {
+        let tuning = crate::organisms::EcosystemTuning::default();
+        let detection = ResourceDetection::for_organism(&tuning, 0.5);
+        assert_eq!(detection.scan_range(65.0), 65.0);
+    }
+
+    #[test]
+    fn scan_cap_bounds_the_resource_scan() {
+        let tuning = crate::organisms::EcosystemTuning {
+            resource_scan_range_cap: 30.0,
+            ..Default::default()
+        };
+        let detection = ResourceDetection::for_organism(&tuning, 0.5);
+        assert_eq!(detection.scan_range(65.0), 30.0);
+        assert_eq!(detection.scan_range(12.0), 12.0);
+    }
+
+    #[test]
+    fn diet_bias_picks_between_plant_and_prey_cells() {
+        let plants = Vec2::new(5.0, 0.0);
+        let prey = Vec2::new(-5.0, 0.0);
+        let mut sensory = SensoryData::new();
+        sensory.nearby_resources = vec![
+            (plants, ResourceType::Plant, 5.0, 0.8),
+            (prey, ResourceType::Prey, 5.0, 0.8),
+        ];
+        let pick = |diet_bias| {
+            find_best_food_source_weighted(OrganismType::Consumer, &sensory, 0.0, diet_bias)
+        };
+        assert_eq!(pick(0.9), Some(prey));
+        assert_eq!(pick(0.1), Some(plants));
+    }
+
+    #[test]
+    fn lone_organisms_always_flee() {
+        // Even a zero ally threshold and the most fearsome traits don't make a loner mob
+        let rule = MobbingRule {
+            ally_threshold: 0,
+            trait_threshold: 0.0,
+        };
+        assert!(!rule.should_mob(1.0, 1.0, 0));
+        assert!(rule.should_mob(1.0, 1.0, 1));
+
+        let tuned = MobbingRule::from_tuning(&crate::organisms::EcosystemTuning::default());
+        assert!(!tuned.should_mob(1.0, 1.0, 0));
+        assert!(tuned.should_mob(1.0, 1.0, tuned.ally_threshold));
+        // Enough allies but too timid: flee
+        assert!(!tuned.should_mob(0.2, 0.2, tuned.ally_threshold));
+    }
+
+    #[test]
+    fn depleted_chase_target_is_dropped_for_a_richer_cell() {
+        let depleted = Vec2::new(10.5, 10.5);
+        let fresh = Vec2::new(14.5, 10.5);
+        let mut chunk = crate::world::Chunk::new(0, 0);
+        chunk.get_cell_mut(10, 10).unwrap().set_resource(ResourceType::Plant, 0.05);
+        chunk.get_cell_mut(14, 10).unwrap().set_resource(ResourceType::Plant, 0.6);
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+
+        let mut behavior = Behavior::new();
+        behavior.state = BehaviorState::Chasing;
+        behavior.target_position = Some(depleted);
+        let threshold = crate::organisms::EcosystemTuning::default().chase_retarget_threshold;
+        let target = depleted_food_target(&behavior, OrganismType::Consumer, &grid, threshold);
+        assert_eq!(target, Some(depleted));
+
+        // Sensed before it was grazed, the depleted cell still looks like the best food
+        let mut sensory = SensoryData::new();
+        sensory.nearby_resources = vec![
+            (depleted, ResourceType::Plant, 1.0, 0.9),
+            (fresh, ResourceType::Plant, 4.0, 0.6),
+        ];
+        assert_eq!(find_best_food_source(OrganismType::Consumer, &sensory), Some(depleted));
+        sensory.forget_resource(depleted);
+        assert_eq!(find_best_food_source(OrganismType::Consumer, &sensory), Some(fresh));
+
+        // A cell that still holds food is kept
+        behavior.target_position = Some(fresh);
+        assert_eq!(depleted_food_target(&behavior, OrganismType::Consumer, &grid, threshold), None);
+    }
+
+    #[test]
+    fn ridge_hides_a_target_only_when_occlusion_is_on() {
+        // Low ground with a high ridge running along x = 20
+        let mut chunk = crate::world::Chunk::new(0, 0);
+        for (index, cell) in chunk.cells_mut().iter_mut().enumerate() {
+            cell.elevation = if index % crate::world::CHUNK_SIZE == 20 { 60_000 } else { 10_000 };
+        }
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+        let watcher = Vec2::new(10.5, 32.5);
+        let behind_ridge = Vec2::new(30.5, 32.5);
+        let same_side = Vec2::new(10.5, 52.5);
+
+        let mut tuning = crate::organisms::EcosystemTuning {
+            sensory_occlusion: true,
+            ..Default::default()
+        };
+        let occlusion = SensoryOcclusion::from_tuning(&tuning).unwrap();
+        assert_eq!(occlusion.visibility(&grid, watcher, behind_ridge), 0.0);
+        assert_eq!(occlusion.visibility(&grid, watcher, same_side), 1.0);
+
+        tuning.sensory_occlusion = false;
+        assert!(SensoryOcclusion::from_tuning(&tuning).is_none());
+    }
+
+    #[test]
+    fn cold_stressed_wanderers_drift_toward_warmth() {
+        // Too cold everywhere, warming steadily toward +x
+        let mut chunk = crate::world::Chunk::new(0, 0);
+        for (index, cell) in chunk.cells_mut().iter_mut().enumerate() {
+            cell.temperature = (index % crate::world::CHUNK_SIZE) as f32 * 0.004;
+        }
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+        let thermotaxis = Thermotaxis::from_tuning(&crate::organisms::EcosystemTuning::default()).unwrap();
+
+        // A random walk plus the thermal pull, one small step at a time
+        let start = Vec2::new(32.5, 32.5);
+        let mut position = start;
+        let mut rng = fastrand::Rng::with_seed(3);
+        for _ in 0..300 {
+            let heading = Vec2::from_angle(rng.f32() * std::f32::consts::TAU);
+            position += (heading + thermotaxis.steering(position, &grid)) * 0.1;
+        }
+        assert!(position.x > start.x + 3.0, "ended at {position}");
+
+        // Comfortable organisms aren't steered
+        let mut mild = crate::world::Chunk::new(1, 0);
+        for cell in mild.cells_mut().iter_mut() {
+            cell.temperature = crate::organisms::stress::IDEAL_TEMPERATURE;
         }
+        grid.insert_chunk(mild);
+        assert_eq!(thermotaxis.steering(Vec2::new(96.5, 32.5), &grid), Vec2::ZERO);
     }
 }
@@ -0,0 +1,139 @@
+use crate::organisms::components::{Alive, OrganismUid, SpeciesId};
+use crate::organisms::systems::TrackedOrganisms;
+use crate::organisms::EcosystemTuning;
+use crate::utils::{SimTime, SpatialHashGrid};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Survivors left by the F9 bottleneck key unless `--bottleneck-survivors` says otherwise
+const DEFAULT_BOTTLENECK_SURVIVORS: usize = 10;
+
+/// Population bottlenecks for founder-effect experiments
+/// `cull_to(n)` removes all but `n` randomly chosen organisms on the next tick. Survivors
+/// are drawn from a stream seeded by `EcosystemTuning::organism_seed` and the tick, so the
+/// same run culls the same organisms. Culled organisms are removed outright (no carcass).
+/// `--bottleneck <tick> <n>` schedules one from the command line, and every cull is logged
+/// and kept in `history` for the stats export.
+#[derive(Resource)]
+pub struct Bottleneck {
+    /// Survivors for the F9 key
+    pub survivors: usize,
+    /// Cull requested for the next tick
+    requested: Option<usize>,
+    /// Cull scheduled from the command line: (tick, survivors)
+    scheduled: Option<(u64, usize)>,
+    pub history: Vec<BottleneckRecord>,
+}
+
+/// One applied bottleneck
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BottleneckRecord {
+    pub tick: u64,
+    pub population_before: usize,
+    pub survivors: usize,
+    pub species_before: usize,
+    pub species_after: usize,
+}
+
+impl Default for Bottleneck {
+    fn default() -> Self {
+        Self {
+            survivors: DEFAULT_BOTTLENECK_SURVIVORS,
+            requested: None,
+            scheduled: None,
+            history: Vec::new(),
+        }
+    }
+}
+
+impl Bottleneck {
+    pub fn from_args() -> Self {
+        let mut bottleneck = Self::default();
+        if let Some(value) = crate::utils::cli::option_value("bottleneck-survivors") {
+            match value.trim().parse() {
+                Ok(survivors) => bottleneck.survivors = survivors,
+                Err(_) => warn!("Invalid --bottleneck-survivors '{}', using the default", value),
+            }
+        }
+        if let Some((tick, survivors)) = crate::utils::cli::option_pair("bottleneck") {
+            match (tick.trim().parse(), survivors.trim().parse()) {
+                (Ok(tick), Ok(survivors)) => bottleneck.scheduled = Some((tick, survivors)),
+                _ => warn!("Invalid --bottleneck '{} {}', expected <tick> <survivors>", tick, survivors),
+            }
+        }
+        bottleneck
+    }
+
+    /// Cull the population down to `survivors` organisms on the next tick
+    pub fn cull_to(&mut self, survivors: usize) {
+        self.requested = Some(survivors);
+    }
+}
+
+/// Apply a requested or scheduled bottleneck
+pub fn apply_bottleneck(
+    mut commands: Commands,
+    mut bottleneck: ResMut<Bottleneck>,
+    mut tracked: ResMut<TrackedOrganisms>,
+    mut spatial_hash: ResMut<SpatialHashGrid>,
+    query: Query<(Entity, &OrganismUid, &SpeciesId), With<Alive>>,
+    tuning: Res<EcosystemTuning>,
+    sim_time: Res<SimTime>,
+) {
+    if let Some((tick, survivors)) = bottleneck.scheduled {
+        if sim_time.tick >= tick {
+            bottleneck.scheduled = None;
+            bottleneck.requested = Some(survivors);
+        }
+    }
+    let Some(survivors) = bottleneck.requested.take() else {
+        return;
+    };
+
+    // Uid order first, so the shuffle doesn't depend on query iteration order
+    let mut organisms: Vec<(Entity, u64, u32)> = query
+        .iter()
+        .map(|(entity, uid, species)| (entity, uid.0, species.value()))
+        .collect();
+    let population_before = organisms.len();
+    if population_before <= survivors {
+        info!(
+            "[BOTTLENECK] Population {} is already at or below {}, nothing culled",
+            population_before, survivors
+        );
+        return;
+    }
+    organisms.sort_unstable_by_key(|&(_, uid, _)| uid);
+    let species_before = organisms.iter().map(|&(_, _, species)| species).collect::<HashSet<_>>().len();
+
+    let mut rng = fastrand::Rng::with_seed(tuning.organism_seed ^ sim_time.tick);
+    rng.shuffle(&mut organisms);
+    let (kept, culled) = organisms.split_at(survivors);
+
+    for &(entity, _, _) in culled {
+        if tracked.untrack(entity) {
+            info!("[TRACKED] Organism {:?} was culled in a bottleneck", entity);
+        }
+        spatial_hash.organisms.remove(entity);
+        commands.entity(entity).despawn();
+    }
+
+    let species_after = kept.iter().map(|&(_, _, species)| species).collect::<HashSet<_>>().len();
+    info!(
+        "[BOTTLENECK] Tick {}: culled {} of {} organisms, {} survivors from {} of {} species",
+        sim_time.tick,
+        culled.len(),
+        population_before,
+        survivors,
+        species_after,
+        species_before
+    );
+    bottleneck.history.push(BottleneckRecord {
+        tick: sim_time.tick,
+        population_before,
+        survivors,
+        species_before,
+        species_after,
+    });
+}
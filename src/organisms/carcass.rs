@@ -0,0 +1,221 @@
+use crate::organisms::{EcosystemTuning, TrophicFlows};
+use crate::world::{resource_capacity, ResourceType, WorldGrid};
+use bevy::prelude::*;
+
+/// A place where an organism recently died
+#[derive(Debug, Clone, Copy)]
+pub struct CarcassSite {
+    pub position: Vec2,
+    /// Seconds since death
+    pub age: f32,
+    /// Detritus the body holds in total
+    pub total: f32,
+    /// Detritus not yet released into the cell
+    pub remaining: f32,
+}
+
+impl CarcassSite {
+    /// Detritus offered to the cell this step: an even share of `total` per second of the
+    /// decay window, or everything left once the window is over (or without one)
+    fn release_due(&self, dt: f32, decay_seconds: f32) -> f32 {
+        if decay_seconds <= 0.0 || self.age >= decay_seconds {
+            self.remaining
+        } else {
+            (self.total * dt / decay_seconds).min(self.remaining)
+        }
+    }
+}
+
+/// Carcasses: organisms leave detritus where they die
+/// With `EcosystemTuning::carcass_decay_seconds` set, a body releases its detritus evenly over
+/// that window instead of all at once. Sites are remembered while fresh or still decaying so
+/// decomposers can home in on them; after that it's just ordinary detritus.
+/// Detritus the cell can't hold stays on the body and is offered again on later ticks, so the
+/// releases add up to the body's total unless the cell stays full for another
+/// `carcass_freshness_window` after decay ends; whatever is left then is dropped.
+#[derive(Resource, Default)]
+pub struct Carcasses {
+    sites: Vec<CarcassSite>,
+}
+
+impl Carcasses {
+    /// Age every site by `dt`, release decaying bodies' detritus, and forget sites that are
+    /// neither fresh nor holding detritus any more. Returns the detritus added to cells.
+    pub fn age(&mut self, world_grid: &mut WorldGrid, tuning: &EcosystemTuning, dt: f32) -> f32 {
+        let window = tuning.carcass_freshness_window;
+        let decay_seconds = tuning.carcass_decay_seconds.max(0.0);
+        let mut deposited = 0.0;
+        self.sites.retain_mut(|site| {
+            site.age += dt;
+            if site.remaining > 0.0 {
+                let release = site.release_due(dt, decay_seconds);
+                let added = deposit_detritus(world_grid, site.position, release);
+                site.remaining -= added;
+                deposited += added;
+            }
+            site.age < window || (site.remaining > 0.0 && site.age < decay_seconds + window)
+        });
+        deposited
+    }
+
+    /// Best fresh carcass within `range` of `position` as (position, freshness)
+    /// Freshness runs from 1.0 (just died) to 0.0 (end of the window), and never drops below
+    /// the share of the body still to decay; fresher and closer sites are preferred.
+    pub fn freshest_near(&self, position: Vec2, range: f32, window: f32) -> Option<(Vec2, f32)> {
+        let range_sq = range * range;
+        self.sites
+            .iter()
+            .filter(|site| (site.position - position).length_squared() <= range_sq)
+            .map(|site| {
+                let age_freshness = if window > 0.0 {
+                    (1.0 - site.age / window).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let freshness = age_freshness.max(site.remaining / site.total.max(f32::EPSILON));
+                (site, freshness)
+            })
+            .filter(|(_, freshness)| *freshness > 0.0)
+            .map(|(site, freshness)| {
+                let distance = (site.position - position).length() / range.max(f32::EPSILON);
+                (site.position, freshness, freshness - distance * 0.5)
+            })
+            .max_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(position, freshness, _)| (position, freshness))
+    }
+}
+
+/// Detritus a body holds: its tissue (by size) plus whatever energy it still had stored
+/// Body size stands in for the energy bound up in tissue: most organisms die of starvation
+/// with almost no stored energy left, so stored energy alone would leave next to nothing.
+pub fn carcass_detritus(tuning: &EcosystemTuning, size: f32, energy: f32) -> f32 {
+    size.max(0.0) * tuning.carcass_detritus_per_size
+        + energy.max(0.0) * tuning.carcass_detritus_per_energy
+}
+
+/// Leave a carcass in the cell where the organism died (see `carcass_detritus`)
+/// Only existing cells receive detritus (a placeholder chunk would be overwritten anyway).
+/// Returns the detritus added now; the rest (all of it for a decaying carcass, or what the cell
+/// couldn't hold) is added in `age_carcasses`.
+pub(crate) fn deposit_carcass(
+    world_grid: &mut WorldGrid,
+    carcasses: &mut Carcasses,
+    tuning: &EcosystemTuning,
+    position: Vec2,
+    size: f32,
+    energy: f32,
+) -> f32 {
+    let amount = carcass_detritus(tuning, size, energy);
+    if amount <= 0.0 || world_grid.get_cell(position.x, position.y).is_none() {
+        return 0.0;
+    }
+    let deposited = if tuning.carcass_decay_seconds > 0.0 {
+        0.0
+    } else {
+        deposit_detritus(world_grid, position, amount)
+    };
+    carcasses.sites.push(CarcassSite {
+        position,
+        age: 0.0,
+        total: amount,
+        remaining: amount - deposited,
+    });
+    deposited
+}
+
+/// Add detritus to the existing cell at `position`, up to its terrain's capacity
+/// Returns the detritus actually added (0 where no chunk exists yet).
+pub(crate) fn deposit_detritus(world_grid: &mut WorldGrid, position: Vec2, amount: f32) -> f32 {
+    if amount <= 0.0 || world_grid.get_cell(position.x, position.y).is_none() {
+        return 0.0;
+    }
+    let Some(cell) = world_grid.get_cell_mut(position.x, position.y) else {
+        return 0.0;
+    };
+    let detritus = cell.get_resource(ResourceType::Detritus);
+    let capacity = resource_capacity(cell.terrain, ResourceType::Detritus);
+    let updated = (detritus + amount).min(capacity).max(detritus);
+    cell.set_resource(ResourceType::Detritus, updated);
+    updated - detritus
+}
+
+/// Age carcass sites and release decaying bodies' detritus (see `Carcasses::age`)
+/// A body releases `total / carcass_decay_seconds` per second; the final step releases
+/// whatever is left, so the releases add up to `total`.
+pub fn age_carcasses(
+    mut carcasses: ResMut<Carcasses>,
+    mut world_grid: ResMut<WorldGrid>,
+    mut trophic: ResMut<TrophicFlows>,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+) {
+    let deposited = carcasses.age(&mut world_grid, &tuning, time.delta_seconds());
+    trophic.record_detritus(deposited);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::Chunk;
+
+    fn grid_with_origin_chunk() -> WorldGrid {
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(Chunk::new(0, 0));
+        grid
+    }
+
+    fn detritus_at(grid: &WorldGrid, position: Vec2) -> f32 {
+        grid.get_cell(position.x, position.y)
+            .unwrap()
+            .get_resource(ResourceType::Detritus)
+    }
+
+    #[test]
+    fn decaying_carcass_releases_its_whole_total() {
+        let mut grid = grid_with_origin_chunk();
+        let mut carcasses = Carcasses::default();
+        let tuning = EcosystemTuning {
+            carcass_detritus_per_size: 0.2,
+            carcass_detritus_per_energy: 0.001,
+            carcass_decay_seconds: 10.0,
+            ..Default::default()
+        };
+        let position = Vec2::new(3.5, 3.5);
+        let total = carcass_detritus(&tuning, 1.5, 40.0);
+
+        let mut released = deposit_carcass(&mut grid, &mut carcasses, &tuning, position, 1.5, 40.0);
+        assert_eq!(released, 0.0);
+        for _ in 0..200 {
+            released += carcasses.age(&mut grid, &tuning, 0.07);
+        }
+        assert!((released - total).abs() < 1e-4, "released {released}, body held {total}");
+        assert!((detritus_at(&grid, position) - total).abs() < 1e-4);
+    }
+
+    #[test]
+    fn detritus_a_full_cell_cannot_hold_is_carried_over() {
+        let mut grid = grid_with_origin_chunk();
+        let mut carcasses = Carcasses::default();
+        let tuning = EcosystemTuning {
+            carcass_detritus_per_size: 0.4,
+            carcass_decay_seconds: 0.0,
+            carcass_freshness_window: 30.0,
+            ..Default::default()
+        };
+        let position = Vec2::new(3.5, 3.5);
+        let cell = grid.get_cell_mut(position.x, position.y).unwrap();
+        let capacity = resource_capacity(cell.terrain, ResourceType::Detritus);
+        cell.set_resource(ResourceType::Detritus, capacity - 0.1);
+
+        let total = carcass_detritus(&tuning, 1.0, 0.0);
+        let mut released = deposit_carcass(&mut grid, &mut carcasses, &tuning, position, 1.0, 0.0);
+        assert!((released - 0.1).abs() < 1e-5);
+
+        // Decomposers clear the cell; the rest of the body follows on the next tick
+        grid.get_cell_mut(position.x, position.y)
+            .unwrap()
+            .set_resource(ResourceType::Detritus, 0.0);
+        released += carcasses.age(&mut grid, &tuning, 0.1);
+        assert!((released - total).abs() < 1e-5, "released {released}, body held {total}");
+    }
+}
@@ -0,0 +1,365 @@
+use crate::organisms::behavior::Behavior;
+use crate::organisms::components::{
+    Alive, CachedTraits, Energy, EnergyBudget, Health, OrganismUid, Position,
+};
+use crate::organisms::{DeterministicOrder, EcosystemTuning};
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Ticks between `[CARE]` reports
+const CARE_REPORT_INTERVAL: u64 = 600;
+
+/// Newborn still being tended by a parent (see `ParentalCare`)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CaredFor {
+    pub parent: Entity,
+    /// Ticks of care left
+    pub remaining: u32,
+}
+
+impl CaredFor {
+    /// Care link for a newborn of `parent`, if a parent with this much care tends at all
+    pub fn from_parent(parent: Entity, care: f32, tuning: &EcosystemTuning) -> Option<Self> {
+        if tuning.parental_care_ticks == 0 || care < tuning.parental_care_min_trait {
+            return None;
+        }
+        let remaining = (tuning.parental_care_ticks as f32 * care.clamp(0.0, 1.0)).round() as u32;
+        (remaining > 0).then_some(Self { parent, remaining })
+    }
+}
+
+/// Newborn whose survival through its first `parental_care_ticks` ticks is being recorded,
+/// tended or not, so the `[CARE]` report can compare the two
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EarlyLife {
+    /// Whether a parent tends it (it had a `CaredFor` link at birth)
+    pub tended: bool,
+    /// Ticks of the early-life window left
+    pub remaining: u32,
+}
+
+impl EarlyLife {
+    /// Early-life record for a newborn (None while parental care is off)
+    pub fn at_birth(tended: bool, tuning: &EcosystemTuning) -> Option<Self> {
+        (tuning.parental_care_ticks > 0).then_some(Self {
+            tended,
+            remaining: tuning.parental_care_ticks,
+        })
+    }
+}
+
+/// Newborns that survived or died within their early-life window, untended and tended
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EarlySurvival {
+    pub survived: [u32; 2],
+    pub died: [u32; 2],
+}
+
+impl EarlySurvival {
+    fn record(&mut self, tended: bool, survived: bool) {
+        let counts = if survived { &mut self.survived } else { &mut self.died };
+        counts[tended as usize] += 1;
+    }
+
+    /// Share of finished windows survived, and how many finished (None before any did)
+    pub fn survival(&self, tended: bool) -> Option<(f32, u32)> {
+        let index = tended as usize;
+        let total = self.survived[index] + self.died[index];
+        (total > 0).then(|| (self.survived[index] as f32 / total as f32, total))
+    }
+}
+
+/// Parental care (enabled by `EcosystemTuning::parental_care_ticks`)
+/// Newborns of parents with enough `parental_care` stay linked to the parent for a while. During
+/// that time the parent feeds them energy, parent and brood share alarms, and the parent is held
+/// near its brood, which costs it foraging. High care and small clutches trade quantity for
+/// quality against low care and large clutches.
+#[derive(Resource, Default)]
+pub struct ParentalCare {
+    /// Centroid of each tending parent's brood, as of this tick
+    broods: HashMap<Entity, Vec2>,
+    /// Energy passed from parents to offspring since the last report
+    shared: f32,
+    /// Care periods that ran their full length since the last report
+    completed: u32,
+    /// Offspring that died while tended since the last report
+    lost: u32,
+    /// Offspring linked to a parent as of the last tick (so despawned ones count as lost)
+    tending: HashSet<Entity>,
+    /// Newborns in their early-life window as of the last tick, and whether they're tended
+    cohort: HashMap<Entity, bool>,
+    /// Early survival of the windows that ended since the last report
+    pub early: EarlySurvival,
+}
+
+impl ParentalCare {
+    /// Bend a tending parent's desired velocity back toward its brood once it strays past
+    /// `parental_care_radius`; the pull grows with the parent's care trait
+    pub fn tether(
+        &self,
+        parent: Entity,
+        position: Vec2,
+        desired: Vec2,
+        cached_traits: &CachedTraits,
+        tuning: &EcosystemTuning,
+    ) -> Vec2 {
+        let Some(&brood) = self.broods.get(&parent) else {
+            return desired;
+        };
+        let offset = brood - position;
+        let distance = offset.length();
+        if distance <= tuning.parental_care_radius {
+            return desired;
+        }
+        let speed = desired.length().max(cached_traits.speed * 0.5);
+        desired.lerp(offset / distance * speed, cached_traits.parental_care.clamp(0.0, 1.0))
+    }
+}
+
+/// Age care links, share energy and alarms along them, and record where each brood is
+pub fn update_parental_care(
+    mut commands: Commands,
+    mut care: ResMut<ParentalCare>,
+    mut links_query: Query<(Entity, &mut CaredFor)>,
+    mut early_query: Query<(Entity, &mut EarlyLife)>,
+    mut query: Query<
+        (
+            &Position,
+            &mut Energy,
+            &mut Behavior,
+            &CachedTraits,
+            Option<&Health>,
+            Option<&mut EnergyBudget>,
+        ),
+        With<Alive>,
+    >,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+    sim_time: Res<SimTime>,
+    order: Res<DeterministicOrder>,
+    uid_query: Query<&OrganismUid>,
+) {
+    care.broods.clear();
+    let dt = time.delta_seconds();
+    let dying = |entity: Entity| {
+        query.get(entity).map_or(true, |(_, energy, _, _, health, _)| {
+            energy.is_depleted(tuning.death_energy_fraction)
+                || health.is_some_and(|health| health.is_dead())
+        })
+    };
+
+    // Early-life windows: newborns that died (or were despawned, e.g. eaten) before the
+    // window ended count against their cohort
+    let mut cohort = HashMap::with_capacity(care.cohort.len());
+    for (offspring, mut early) in early_query.iter_mut() {
+        care.cohort.remove(&offspring);
+        if dying(offspring) {
+            care.early.record(early.tended, false);
+            commands.entity(offspring).remove::<EarlyLife>();
+        } else if early.remaining == 0 {
+            care.early.record(early.tended, true);
+            commands.entity(offspring).remove::<EarlyLife>();
+        } else {
+            early.remaining -= 1;
+            cohort.insert(offspring, early.tended);
+        }
+    }
+    for (_, tended) in std::mem::replace(&mut care.cohort, cohort) {
+        care.early.record(tended, false);
+    }
+
+    // (offspring, parent) links still active this tick
+    let mut links = Vec::new();
+    let mut tending = HashSet::with_capacity(care.tending.len());
+    for (offspring, mut cared) in links_query.iter_mut() {
+        care.tending.remove(&offspring);
+        if dying(offspring) {
+            care.lost += 1;
+            commands.entity(offspring).remove::<CaredFor>();
+            continue;
+        }
+        if cared.remaining == 0 {
+            care.completed += 1;
+            commands.entity(offspring).remove::<CaredFor>();
+            continue;
+        }
+        // Orphans simply fend for themselves from here on
+        if query.get(cared.parent).is_err() {
+            commands.entity(offspring).remove::<CaredFor>();
+            continue;
+        }
+        cared.remaining -= 1;
+        links.push((offspring, cared.parent));
+        tending.insert(offspring);
+    }
+    // Linked last tick but gone now: despawned while tended
+    care.lost += care.tending.len() as u32;
+    care.tending = tending;
+
+    // A parent's spare energy runs out partway through its brood, so who's fed first matters
+    order.sort(&mut links, &uid_query, |(offspring, _)| *offspring);
+    let tended = links.len();
+
+    let alarm_radius_sq = tuning.alarm_radius * tuning.alarm_radius;
+    let mut brood_sums: HashMap<Entity, (Vec2, f32)> = HashMap::new();
+    for (offspring, parent) in links {
+        let Ok((parent_position, parent_energy, parent_behavior, parent_traits, _, _)) =
+            query.get(parent)
+        else {
+            continue;
+        };
+        let parent_position = parent_position.0;
+        let spare = (parent_energy.current - parent_energy.max * tuning.parental_care_reserve)
+            .max(0.0);
+        let rate = tuning.parental_care_energy_rate * parent_traits.parental_care;
+        let parent_alarm = (parent_behavior.threat_timer, parent_behavior.recent_threat);
+
+        let Ok((position, mut energy, mut behavior, _, _, budget)) = query.get_mut(offspring) else {
+            continue;
+        };
+        let position = position.0;
+        let transfer = (rate * dt).min(spare).min((energy.max - energy.current).max(0.0));
+        energy.current += transfer;
+        if let Some(mut budget) = budget {
+            budget.eaten += transfer;
+        }
+
+        // Alarms pass both ways while parent and offspring are within earshot
+        let within_earshot = position.distance_squared(parent_position) <= alarm_radius_sq;
+        let offspring_alarm = (behavior.threat_timer, behavior.recent_threat);
+        if within_earshot && parent_alarm.0 > behavior.threat_timer {
+            behavior.threat_timer = parent_alarm.0;
+            behavior.recent_threat = parent_alarm.1;
+        }
+
+        let Ok((_, mut parent_energy, mut parent_behavior, _, _, parent_budget)) =
+            query.get_mut(parent)
+        else {
+            continue;
+        };
+        parent_energy.current -= transfer;
+        if let Some(mut budget) = parent_budget {
+            budget.reproduction += transfer;
+        }
+        if within_earshot && offspring_alarm.0 > parent_behavior.threat_timer {
+            parent_behavior.threat_timer = offspring_alarm.0;
+            parent_behavior.recent_threat = offspring_alarm.1;
+        }
+        care.shared += transfer;
+
+        let (sum, count) = brood_sums.entry(parent).or_insert((Vec2::ZERO, 0.0));
+        *sum += position;
+        *count += 1.0;
+    }
+    care.broods = brood_sums
+        .into_iter()
+        .map(|(parent, (sum, count))| (parent, sum / count))
+        .collect();
+
+    if tuning.parental_care_ticks > 0 && sim_time.every(CARE_REPORT_INTERVAL) {
+        let survival = |tended: bool| match care.early.survival(tended) {
+            Some((share, total)) => format!("{:.0}% of {}", share * 100.0, total),
+            None => "n/a".to_string(),
+        };
+        info!(
+            "[CARE] Tick {} | {} offspring tended by {} parents | {:.1} energy shared | {} care periods completed, {} tended offspring died | Early survival: tended {}, untended {}",
+            sim_time.tick,
+            tended,
+            care.broods.len(),
+            care.shared,
+            care.completed,
+            care.lost,
+            survival(true),
+            survival(false)
+        );
+        care.shared = 0.0;
+        care.completed = 0;
+        care.lost = 0;
+        care.early = EarlySurvival::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::genetics::{Genome, GENOME_SIZE};
+
+    fn care_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ParentalCare>();
+        world.insert_resource(EcosystemTuning {
+            parental_care_ticks: 2,
+            ..EcosystemTuning::default()
+        });
+        world.init_resource::<Time>();
+        // Off the report tick, so the counters aren't reset under the test
+        world.insert_resource(SimTime { tick: 1 });
+        world.init_resource::<DeterministicOrder>();
+        world
+    }
+
+    fn spawn_organism(world: &mut World, energy: f32) -> Entity {
+        let traits = CachedTraits::from_genome(&Genome::new(vec![0.5; GENOME_SIZE]));
+        world
+            .spawn((
+                Position::new(0.0, 0.0),
+                Energy::with_energy(100.0, energy),
+                Behavior::new(),
+                traits,
+                Alive,
+            ))
+            .id()
+    }
+
+    fn run_care(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_parental_care);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn early_survival_is_split_by_care() {
+        let mut world = care_world();
+        let tended = spawn_organism(&mut world, 50.0);
+        world.entity_mut(tended).insert(EarlyLife { tended: true, remaining: 2 });
+        let survivor = spawn_organism(&mut world, 50.0);
+        world.entity_mut(survivor).insert(EarlyLife { tended: false, remaining: 2 });
+        let starved = spawn_organism(&mut world, 0.0);
+        world.entity_mut(starved).insert(EarlyLife { tended: false, remaining: 2 });
+
+        run_care(&mut world);
+        // Eaten: despawned without passing through a dying state
+        world.despawn(tended);
+        run_care(&mut world);
+        run_care(&mut world);
+
+        let early = world.resource::<ParentalCare>().early;
+        assert_eq!(early.died, [1, 1]);
+        assert_eq!(early.survived, [1, 0]);
+        assert_eq!(early.survival(false), Some((0.5, 2)));
+        assert_eq!(early.survival(true), Some((0.0, 1)));
+        assert!(world.get::<EarlyLife>(survivor).is_none());
+    }
+
+    #[test]
+    fn despawned_tended_offspring_count_as_lost() {
+        let mut world = care_world();
+        let parent = spawn_organism(&mut world, 100.0);
+        let offspring = spawn_organism(&mut world, 50.0);
+        world.entity_mut(offspring).insert(CaredFor { parent, remaining: 5 });
+
+        run_care(&mut world);
+        assert_eq!(world.resource::<ParentalCare>().lost, 0);
+        world.despawn(offspring);
+        run_care(&mut world);
+        assert_eq!(world.resource::<ParentalCare>().lost, 1);
+    }
+
+    #[test]
+    fn no_early_life_record_without_care() {
+        let tuning = EcosystemTuning::default();
+        assert_eq!(tuning.parental_care_ticks, 0);
+        assert!(EarlyLife::at_birth(true, &tuning).is_none());
+    }
+}
@@ -0,0 +1,172 @@
+/// How a clutch's energy budget is divided between the offspring (selected via `EcosystemTuning`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClutchEnergyStrategy {
+    /// Every offspring gets the same share
+    #[default]
+    Even,
+    /// Each offspring gets a fixed fraction of its older sibling's share, favoring the first-born
+    Exponential,
+    /// Each offspring costs a fixed fraction of its own max energy; the clutch ends when the budget does
+    FixedCost,
+}
+
+impl ClutchEnergyStrategy {
+    pub fn label(self) -> &'static str {
+        match self {
+            ClutchEnergyStrategy::Even => "even",
+            ClutchEnergyStrategy::Exponential => "exponential",
+            ClutchEnergyStrategy::FixedCost => "fixed-cost",
+        }
+    }
+
+    /// Divide `budget` between offspring (in birth order) given each one's max energy.
+    /// Returns the energy each offspring is provisioned with; the clutch may come back shorter
+    /// (fixed-cost only, never empty). Shares never exceed what fills an offspring after transfer
+    /// losses, and they add up to at most `budget` - that sum is what the parents pay.
+    pub fn split(
+        self,
+        budget: f32,
+        offspring_max_energy: &[f32],
+        exponential_ratio: f32,
+        fixed_cost_fraction: f32,
+    ) -> Vec<f32> {
+        let budget = budget.max(0.0);
+        let caps: Vec<f32> = offspring_max_energy
+            .iter()
+            .map(|max_energy| max_energy.max(0.0) / OFFSPRING_ENERGY_TRANSFER)
+            .collect();
+
+        match self {
+            ClutchEnergyStrategy::Even => weighted_fill(budget, &vec![1.0; caps.len()], &caps),
+            ClutchEnergyStrategy::Exponential => {
+                let ratio = exponential_ratio.clamp(0.01, 1.0);
+                let weights: Vec<f32> = (0..caps.len()).map(|i| ratio.powi(i as i32)).collect();
+                weighted_fill(budget, &weights, &caps)
+            }
+            ClutchEnergyStrategy::FixedCost => {
+                let mut shares = Vec::with_capacity(caps.len());
+                let mut remaining = budget;
+                for (cap, max_energy) in caps.iter().zip(offspring_max_energy) {
+                    let cost = (max_energy * fixed_cost_fraction.max(0.0)).min(*cap);
+                    if remaining < cost {
+                        // The first-born is always laid, with whatever the parents could spare
+                        if shares.is_empty() && !caps.is_empty() {
+                            shares.push(remaining);
+                        }
+                        break;
+                    }
+                    shares.push(cost);
+                    remaining -= cost;
+                }
+                shares
+            }
+        }
+    }
+}
+
+/// Share of the parents' contribution that reaches the offspring (the rest is lost to birth)
+pub const OFFSPRING_ENERGY_TRANSFER: f32 = 0.9;
+
+/// Starting energy for a newborn: what its parents contributed (less transfer losses),
+/// never below `min_fraction` of its max energy and never above the max
+pub fn offspring_initial_energy(share: f32, max_energy: f32, min_fraction: f32) -> f32 {
+    (share * OFFSPRING_ENERGY_TRANSFER)
+        .max(max_energy * min_fraction.clamp(0.0, 1.0))
+        .min(max_energy)
+}
+
+/// Hand out `budget` in proportion to `weights`; whatever a capped offspring can't take
+/// is passed on to its uncapped siblings, and anything left once all are full stays unspent
+fn weighted_fill(budget: f32, weights: &[f32], caps: &[f32]) -> Vec<f32> {
+    let mut shares = vec![0.0; caps.len()];
+    let mut open: Vec<usize> = (0..caps.len()).filter(|&i| weights[i] > 0.0).collect();
+    let mut remaining = budget;
+
+    while remaining > 0.0 && !open.is_empty() {
+        let total_weight: f32 = open.iter().map(|&i| weights[i]).sum();
+        let mut handed_out = 0.0;
+        let open_before = open.len();
+        open.retain(|&i| {
+            let room = caps[i] - shares[i];
+            let give = (remaining * weights[i] / total_weight).min(room);
+            shares[i] += give;
+            handed_out += give;
+            give < room
+        });
+        remaining -= handed_out;
+        // Nobody filled up, so the whole remainder went out this round
+        if open.len() == open_before {
+            break;
+        }
+    }
+
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn offspring_energy_follows_the_parents_contribution() {
+        // Above the floor, the newborn keeps what survives the transfer
+        assert_close(offspring_initial_energy(50.0, 100.0, 0.15), 50.0 * OFFSPRING_ENERGY_TRANSFER);
+    }
+
+    #[test]
+    fn offspring_energy_respects_the_floor_and_the_max() {
+        // A meager contribution is topped up to the configured floor
+        assert_close(offspring_initial_energy(5.0, 100.0, 0.15), 15.0);
+        assert_close(offspring_initial_energy(5.0, 100.0, 0.4), 40.0);
+        // A lavish one never overfills the newborn
+        assert_close(offspring_initial_energy(500.0, 100.0, 0.15), 100.0);
+    }
+
+    const STRATEGIES: [ClutchEnergyStrategy; 3] = [
+        ClutchEnergyStrategy::Even,
+        ClutchEnergyStrategy::Exponential,
+        ClutchEnergyStrategy::FixedCost,
+    ];
+
+    #[test]
+    fn clutch_shares_never_overfill_or_overspend() {
+        let max_energy = [100.0, 80.0, 120.0];
+        for strategy in STRATEGIES {
+            for budget in [10.0, 150.0, 1000.0] {
+                let shares = strategy.split(budget, &max_energy, 0.5, 0.3);
+                assert!(!shares.is_empty());
+                let paid: f32 = shares.iter().sum();
+                assert!(paid <= budget + 1e-3, "{strategy:?} paid {paid} of {budget}");
+                for (share, max) in shares.iter().zip(max_energy) {
+                    assert!(share * OFFSPRING_ENERGY_TRANSFER <= max + 1e-3, "{strategy:?} overfilled");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn proportional_strategies_spend_the_whole_budget() {
+        // Plenty of room in every offspring: nothing is left with the parents
+        let max_energy = [100.0, 100.0, 100.0];
+        for strategy in [ClutchEnergyStrategy::Even, ClutchEnergyStrategy::Exponential] {
+            assert_close(strategy.split(150.0, &max_energy, 0.5, 0.3).iter().sum(), 150.0);
+        }
+        // Fixed cost pays exactly for the offspring it lays
+        let shares = ClutchEnergyStrategy::FixedCost.split(150.0, &max_energy, 0.5, 0.3);
+        assert_eq!(shares.len(), 3);
+        for share in shares {
+            assert_close(share, 30.0);
+        }
+    }
+
+    #[test]
+    fn capped_offspring_pass_the_excess_to_siblings() {
+        let shares = ClutchEnergyStrategy::Even.split(150.0, &[18.0, 200.0], 0.5, 0.3);
+        assert_close(shares[0], 20.0);
+        assert_close(shares[1], 130.0);
+    }
+}
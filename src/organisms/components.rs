@@ -56,6 +56,13 @@ impl Energy {
         }
     }
 
+    /// Start at `fraction` of max energy (1.0 = full)
+    pub fn with_fraction(max: f32, fraction: f32) -> Self {
+        let mut energy = Self::new(max);
+        energy.current *= fraction.clamp(0.0, 1.0);
+        energy
+    }
+
     pub fn ratio(&self) -> f32 {
         if self.max > 0.0 {
             self.current / self.max
@@ -67,6 +74,50 @@ impl Energy {
     pub fn is_dead(&self) -> bool {
         self.current <= 0.0
     }
+
+    /// True at or below `fraction` of max energy (0.0 behaves like `is_dead`)
+    pub fn is_depleted(&self, fraction: f32) -> bool {
+        self.current <= self.max * fraction.max(0.0)
+    }
+}
+
+/// Physical condition, separate from energy: lost to predation wounds, regained slowly when fed
+/// An organism dies when either its health or its energy runs out.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    /// Full health for an organism (see `EcosystemTuning::health_per_size`)
+    pub fn for_size(size: f32, health_per_size: f32) -> Self {
+        let max = (size * health_per_size).max(f32::EPSILON);
+        Self { current: max, max }
+    }
+
+    pub fn ratio(&self) -> f32 {
+        if self.max > 0.0 {
+            self.current / self.max
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Take `amount` damage, returning the health actually lost
+    pub fn damage(&mut self, amount: f32) -> f32 {
+        let previous = self.current;
+        self.current = (self.current - amount.max(0.0)).max(0.0);
+        previous - self.current
+    }
+
+    pub fn heal(&mut self, amount: f32) {
+        self.current = (self.current + amount.max(0.0)).min(self.max);
+    }
 }
 
 /// Age in simulation ticks
@@ -127,6 +178,122 @@ impl Metabolism {
     }
 }
 
+/// Energy in vs out since the last logging interval (for diagnosing starvation)
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct EnergyBudget {
+    /// Base metabolic cost
+    pub metabolism: f32,
+    /// Movement cost
+    pub movement: f32,
+    /// Sensing cost (scales with sensory range)
+    pub sensing: f32,
+    /// Energy gained from eating
+    pub eaten: f32,
+    /// Energy given to offspring
+    pub reproduction: f32,
+}
+
+impl EnergyBudget {
+    /// Net energy change over the interval
+    pub fn net(&self) -> f32 {
+        self.eaten - self.metabolism - self.movement - self.sensing - self.reproduction
+    }
+
+    /// Start a new interval
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Local density around an organism, refreshed each tick by `update_crowding`
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Crowding {
+    /// Living organisms within `EcosystemTuning::crowding_radius`
+    pub neighbors: u32,
+    /// How far the neighbor count exceeds the size-scaled threshold (0 = not crowded, 1 = twice the threshold)
+    pub level: f32,
+}
+
+impl Crowding {
+    /// Fraction of the usual reproduction chance left at this crowding level
+    pub fn reproduction_factor(&self, suppression: f32) -> f32 {
+        (1.0 - self.level * suppression).clamp(0.0, 1.0)
+    }
+}
+
+/// Sustained environmental stress, refreshed each tick by `update_stress`
+/// Only tracked when stress-induced mutagenesis is enabled; raises the mutation rate
+/// passed on to offspring (see `EcosystemTuning::stress_mutagenesis_strength`).
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Stress {
+    /// Running average of starvation and thermal stress (0 = comfortable, 1 = extreme)
+    pub level: f32,
+}
+
+impl Stress {
+    /// Mutation rate passed on to offspring at this stress level
+    pub fn mutation_rate(&self, base_rate: f32, strength: f32) -> f32 {
+        (base_rate * (1.0 + strength.max(0.0) * self.level)).min(MAX_STRESSED_MUTATION_RATE)
+    }
+}
+
+/// Ceiling for stress-elevated mutation rates (well above the genome's own range)
+pub const MAX_STRESSED_MUTATION_RATE: f32 = 0.25;
+
+/// Undigested food (as energy value) waiting to be converted to energy
+/// Fills while eating and drains each tick through digestion; a full stomach can't eat more
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Stomach {
+    pub contents: f32,
+    pub capacity: f32,
+}
+
+impl Stomach {
+    pub fn new(capacity: f32) -> Self {
+        Self {
+            contents: 0.0,
+            capacity: capacity.max(0.0),
+        }
+    }
+
+    /// Stomach sized for an organism (see `EcosystemTuning::stomach_capacity_per_size`)
+    pub fn for_size(size: f32, capacity_per_size: f32) -> Self {
+        Self::new(size * capacity_per_size)
+    }
+
+    /// Remaining room for food
+    pub fn free_space(&self) -> f32 {
+        (self.capacity - self.contents).max(0.0)
+    }
+
+    /// Fill fraction (0.0 = empty, 1.0 = full)
+    pub fn fullness(&self) -> f32 {
+        if self.capacity > 0.0 {
+            self.contents / self.capacity
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free_space() <= f32::EPSILON
+    }
+
+    /// Add food, returning the amount that didn't fit
+    pub fn fill(&mut self, amount: f32) -> f32 {
+        let accepted = amount.min(self.free_space());
+        self.contents += accepted;
+        amount - accepted
+    }
+
+    /// Remove up to `amount` of food for digestion, returning what was removed
+    pub fn digest(&mut self, amount: f32) -> f32 {
+        let digested = amount.clamp(0.0, self.contents);
+        self.contents -= digested;
+        digested
+    }
+}
+
 /// Species ID for tracking and speciation (Stage 4+)
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SpeciesId(pub u32);
@@ -153,11 +320,108 @@ pub enum OrganismType {
     Decomposer, // Fungi, bacteria - consume detritus
 }
 
+/// Biological sex (only present when `EcosystemTuning::sexes_enabled`; otherwise hermaphroditic)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sex {
+    Male,
+    Female,
+}
+
+impl Sex {
+    /// Draw a sex with equal odds
+    pub fn random(rng: &mut fastrand::Rng) -> Self {
+        if rng.bool() {
+            Sex::Female
+        } else {
+            Sex::Male
+        }
+    }
+
+    /// Whether two organisms can mate (hermaphrodites are compatible with anyone)
+    pub fn compatible(a: Option<Sex>, b: Option<Sex>) -> bool {
+        match (a, b) {
+            (Some(a), Some(b)) => a != b,
+            _ => true,
+        }
+    }
+}
+
+/// Stable identifier for an organism, unique for the whole run
+/// Bevy reuses entity indices after despawn, so logs and analysis use this instead.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OrganismUid(pub u64);
+
+/// Hands out `OrganismUid`s in spawn order
+#[derive(Resource, Default)]
+pub struct OrganismUids {
+    next: u64,
+}
+
+impl OrganismUids {
+    pub fn allocate(&mut self) -> OrganismUid {
+        let uid = OrganismUid(self.next);
+        self.next += 1;
+        uid
+    }
+}
+
+/// Per-organism random stream so reproduction and mutation draws don't depend on
+/// system iteration order.
+///
+/// Founders are seeded from `EcosystemTuning::organism_seed` mixed with their spawn index.
+/// Offspring are seeded from the next draw of the parent's stream, so a lineage replays
+/// identically as long as each parent makes the same sequence of draws.
+#[derive(Component, Debug, Clone)]
+pub struct RngSeed {
+    /// Seed the stream was created from (stable for the organism's lifetime)
+    pub seed: u64,
+    rng: fastrand::Rng,
+}
+
+impl RngSeed {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: fastrand::Rng::with_seed(seed),
+        }
+    }
+
+    /// Seed for a founder organism from the world seed and its spawn index
+    pub fn founder(world_seed: u64, spawn_index: u64) -> Self {
+        Self::new(splitmix64(world_seed ^ splitmix64(spawn_index)))
+    }
+
+    /// Derive the stream for one offspring, advancing this organism's stream
+    pub fn derive_child(&mut self) -> Self {
+        let draw = self.rng.u64(..);
+        Self::new(splitmix64(self.seed ^ draw))
+    }
+
+    /// Access the organism's random stream
+    pub fn rng(&mut self) -> &mut fastrand::Rng {
+        &mut self.rng
+    }
+}
+
+/// SplitMix64 finalizer - spreads nearby inputs across the full seed space
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// Reproduction cooldown (ticks remaining until organism can reproduce again)
 #[derive(Component, Debug, Clone, Copy)]
 pub struct ReproductionCooldown(pub u32);
 
-/// Cached trait values derived from genome (updated when genome changes)
+/// Marks an organism whose `Genome` changed after spawn
+/// `refresh_dirty_traits` recomputes its `CachedTraits` and removes the marker; insert it
+/// wherever a living organism's genome is mutated.
+#[derive(Component, Debug, Default)]
+pub struct TraitsDirty;
+
+/// Cached trait values derived from genome (recomputed for organisms marked `TraitsDirty`)
 /// This avoids recalculating traits every frame
 #[derive(Component, Debug, Clone)]
 pub struct CachedTraits {
@@ -180,6 +444,10 @@ pub struct CachedTraits {
     pub hunger_memory_rate: f32,
     pub threat_decay_rate: f32,
     pub resource_selectivity: f32,
+    pub diet_bias: f32,
+    pub social_sensitivity: f32,
+    pub perception_acuity: f32,
+    pub parental_care: f32,
 }
 
 impl CachedTraits {
@@ -205,8 +473,57 @@ impl CachedTraits {
             hunger_memory_rate: traits::express_hunger_memory_rate(genome),
             threat_decay_rate: traits::express_threat_decay_rate(genome),
             resource_selectivity: traits::express_resource_selectivity(genome),
+            diet_bias: traits::express_diet_bias(genome),
+            social_sensitivity: traits::express_social_sensitivity(genome),
+            perception_acuity: traits::express_perception_acuity(genome),
+            parental_care: traits::express_parental_care(genome),
         }
     }
+
+    /// Position on the r/K spectrum from clutch size and per-offspring investment
+    /// (0.0 = r: many cheap offspring, 1.0 = K: few well-provisioned offspring)
+    pub fn reproductive_strategy_index(&self) -> f32 {
+        use crate::organisms::genetics::traits;
+        let clutch = traits::CLUTCH_SIZE_BOUNDS.normalize(self.clutch_size);
+        let investment = traits::OFFSPRING_ENERGY_SHARE_BOUNDS.normalize(self.offspring_energy_share);
+        ((1.0 - clutch) + investment) * 0.5
+    }
+
+    /// Trait value by name (names as in `traits::TRAIT_RANGES`)
+    pub fn value_of(&self, name: &str) -> Option<f32> {
+        Some(match name {
+            "speed" => self.speed,
+            "size" => self.size,
+            "metabolism_rate" => self.metabolism_rate,
+            "movement_cost" => self.movement_cost,
+            "max_energy" => self.max_energy,
+            "reproduction_cooldown" => self.reproduction_cooldown,
+            "reproduction_threshold" => self.reproduction_threshold,
+            "sensory_range" => self.sensory_range,
+            "aggression" => self.aggression,
+            "boldness" => self.boldness,
+            "mutation_rate" => self.mutation_rate,
+            "foraging_drive" => self.foraging_drive,
+            "risk_tolerance" => self.risk_tolerance,
+            "exploration_drive" => self.exploration_drive,
+            "clutch_size" => self.clutch_size,
+            "offspring_energy_share" => self.offspring_energy_share,
+            "hunger_memory_rate" => self.hunger_memory_rate,
+            "threat_decay_rate" => self.threat_decay_rate,
+            "resource_selectivity" => self.resource_selectivity,
+            "diet_bias" => self.diet_bias,
+            "social_sensitivity" => self.social_sensitivity,
+            "perception_acuity" => self.perception_acuity,
+            "parental_care" => self.parental_care,
+            _ => return None,
+        })
+    }
+
+    /// Trait value by name mapped onto [0, 1] within its expression range
+    pub fn normalized(&self, name: &str) -> Option<f32> {
+        let range = crate::organisms::genetics::traits::range_of(name)?;
+        self.value_of(name).map(|value| range.normalize(value))
+    }
 }
 
 impl ReproductionCooldown {
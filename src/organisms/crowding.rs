@@ -0,0 +1,47 @@
+use crate::organisms::components::{Alive, Crowding, Position, Size};
+use crate::organisms::EcosystemTuning;
+use crate::utils::SpatialHashGrid;
+use bevy::prelude::*;
+
+/// Neighbors a size-1 organism tolerates before crowding stress sets in
+/// Larger organisms claim more room, so their threshold shrinks with size (never below one).
+pub fn crowding_threshold(base_threshold: f32, size: f32) -> f32 {
+    (base_threshold / size.max(0.1)).max(1.0)
+}
+
+/// Count each organism's neighbors within `crowding_radius` and derive its crowding level
+/// The energy penalty is charged in `update_metabolism`; reproduction is suppressed in
+/// `handle_reproduction`.
+pub fn update_crowding(
+    mut query: Query<(Entity, &Position, &Size, &mut Crowding), With<Alive>>,
+    positions: Query<&Position, With<Alive>>,
+    spatial_hash: Res<SpatialHashGrid>,
+    tuning: Res<EcosystemTuning>,
+) {
+    let radius = tuning.crowding_radius;
+    if radius <= 0.0 {
+        for (_, _, _, mut crowding) in query.iter_mut() {
+            *crowding = Crowding::default();
+        }
+        return;
+    }
+    let radius_sq = radius * radius;
+
+    for (entity, position, size, mut crowding) in query.iter_mut() {
+        let neighbors = spatial_hash
+            .organisms
+            .query_radius(position.0, radius)
+            .into_iter()
+            .filter(|&other| other != entity)
+            .filter(|&other| {
+                positions
+                    .get(other)
+                    .is_ok_and(|other_pos| (other_pos.0 - position.0).length_squared() <= radius_sq)
+            })
+            .count() as u32;
+
+        let threshold = crowding_threshold(tuning.crowding_neighbor_threshold, size.value());
+        crowding.neighbors = neighbors;
+        crowding.level = ((neighbors as f32 - threshold) / threshold).max(0.0);
+    }
+}
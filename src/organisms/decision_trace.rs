@@ -0,0 +1,124 @@
+use crate::organisms::behavior::BehaviorState;
+use crate::organisms::components::OrganismUid;
+use bevy::prelude::*;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Which branch of `decide_behavior_with_memory` fired, and the values it was decided on
+/// Values the branch never reached stay None.
+#[derive(Debug, Clone, Default)]
+pub struct DecisionTrace {
+    pub branch: &'static str,
+    pub hunger_pressure: Option<f32>,
+    pub hunger_barrier: Option<f32>,
+    /// Flee distance including the threat-memory bonus
+    pub flee_threshold: Option<f32>,
+    pub predator_distance: Option<f32>,
+    pub prey_distance: Option<f32>,
+    pub mate_distance: Option<f32>,
+    pub allies: u32,
+}
+
+/// Per-tick decision traces for the tracked organisms, written to CSV
+/// Only filled while `LoggingConfig::decision_trace_enabled` is set (`--trace-decisions`).
+#[derive(Resource)]
+pub struct DecisionTraceLog {
+    csv_writer: Option<BufWriter<File>>,
+    csv_path: PathBuf,
+    header_written: bool,
+}
+
+impl Default for DecisionTraceLog {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            csv_writer: None,
+            csv_path: logs_dir.join(format!("decision_trace_{}.csv", timestamp)),
+            header_written: false,
+        }
+    }
+}
+
+impl DecisionTraceLog {
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            let file = match OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.csv_path)
+            {
+                Ok(file) => file,
+                Err(err) => {
+                    error!("Failed to open decision trace CSV file: {err}");
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+            if !self.header_written {
+                let header = "tick,organism_uid,previous_state,state,branch,energy_ratio,\
+                              hunger_pressure,hunger_barrier,flee_threshold,predator_distance,\
+                              prey_distance,mate_distance,allies,threat_timer,stalled_time";
+                if let Err(err) = writeln!(writer, "{header}") {
+                    error!("Failed to write decision trace CSV header: {err}");
+                    return None;
+                }
+                self.header_written = true;
+            }
+
+            info!("[TRACE] Writing decision traces to {}", self.csv_path.display());
+            self.csv_writer = Some(writer);
+        }
+        self.csv_writer.as_mut()
+    }
+
+    /// Append one decision
+    pub fn record(
+        &mut self,
+        tick: u64,
+        uid: Option<OrganismUid>,
+        previous_state: BehaviorState,
+        state: BehaviorState,
+        energy_ratio: f32,
+        threat_timer: f32,
+        stalled_time: f32,
+        trace: &DecisionTrace,
+    ) {
+        let value = |value: Option<f32>| value.map(|value| format!("{value:.4}")).unwrap_or_default();
+        let row = format!(
+            "{tick},{},{:?},{:?},{},{energy_ratio:.4},{},{},{},{},{},{},{},{threat_timer:.4},{stalled_time:.4}",
+            uid.map(|uid| uid.0.to_string()).unwrap_or_default(),
+            previous_state,
+            state,
+            trace.branch,
+            value(trace.hunger_pressure),
+            value(trace.hunger_barrier),
+            value(trace.flee_threshold),
+            value(trace.predator_distance),
+            value(trace.prey_distance),
+            value(trace.mate_distance),
+            trace.allies,
+        );
+        if let Some(writer) = self.ensure_writer() {
+            if let Err(err) = writeln!(writer, "{row}") {
+                error!("Failed to write decision trace CSV row: {err}");
+            }
+        }
+    }
+
+    /// Flush and close the CSV writer (a later row reopens it in append mode)
+    pub fn close(&mut self) {
+        crate::organisms::systems::close_csv_writer(&mut self.csv_writer, &self.csv_path);
+    }
+}
+
+impl Drop for DecisionTraceLog {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
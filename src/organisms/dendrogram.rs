@@ -0,0 +1,183 @@
+use crate::organisms::speciation::{species_name, SpeciesTracker};
+use crate::organisms::LoggingConfig;
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Periodic UPGMA clustering of the current species centroids, exported as Newick trees
+/// Clustering is O(k³) in the species count, so it runs only every `LoggingConfig::dendrogram_interval` ticks.
+/// Each line of the file is one snapshot: `[tick=N] (...);`
+#[derive(Resource)]
+pub struct SpeciesDendrogram {
+    writer: Option<BufWriter<File>>,
+    path: PathBuf,
+}
+
+impl Default for SpeciesDendrogram {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            writer: None,
+            path: logs_dir.join(format!("species_dendrogram_{}.nwk", timestamp)),
+        }
+    }
+}
+
+impl SpeciesDendrogram {
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.writer.is_none() {
+            let file = match File::create(&self.path) {
+                Ok(file) => file,
+                Err(err) => {
+                    error!("Failed to create species dendrogram file: {err}");
+                    return None;
+                }
+            };
+            info!("[SPECIATION] Writing species dendrograms to {}", self.path.display());
+            self.writer = Some(BufWriter::new(file));
+        }
+        self.writer.as_mut()
+    }
+}
+
+/// A subtree while clustering: its Newick text, leaf count and height (half the merge distance)
+struct Cluster {
+    newick: String,
+    size: usize,
+    height: f32,
+}
+
+/// UPGMA tree over `labels` given their pairwise distances, as a Newick string
+/// Branch lengths make the tree ultrametric (each merge sits at half the average distance
+/// between its two clusters). Ties merge the lowest-indexed pair, so the output is
+/// deterministic for a given input order. Returns None when there are no labels.
+pub fn upgma_newick(labels: &[String], distances: &[Vec<f32>]) -> Option<String> {
+    let mut clusters: Vec<Option<Cluster>> = labels
+        .iter()
+        .map(|label| {
+            Some(Cluster {
+                newick: label.clone(),
+                size: 1,
+                height: 0.0,
+            })
+        })
+        .collect();
+    let mut distances: Vec<Vec<f32>> = distances.to_vec();
+
+    loop {
+        let active: Vec<usize> = (0..clusters.len()).filter(|&i| clusters[i].is_some()).collect();
+        if active.len() <= 1 {
+            break;
+        }
+
+        let mut closest = (active[0], active[1], f32::INFINITY);
+        for (position, &i) in active.iter().enumerate() {
+            for &j in &active[position + 1..] {
+                if distances[i][j] < closest.2 {
+                    closest = (i, j, distances[i][j]);
+                }
+            }
+        }
+        let (i, j, distance) = closest;
+        let distance = if distance.is_finite() { distance } else { 0.0 };
+
+        let (Some(a), Some(b)) = (clusters[i].take(), clusters[j].take()) else {
+            break;
+        };
+        let height = (distance * 0.5).max(a.height).max(b.height);
+        let size = a.size + b.size;
+
+        // Average linkage: the merged cluster's distance is the size-weighted mean
+        for &k in &active {
+            if k == i || k == j {
+                continue;
+            }
+            let merged = (distances[i][k] * a.size as f32 + distances[j][k] * b.size as f32)
+                / size as f32;
+            distances[i][k] = merged;
+            distances[k][i] = merged;
+        }
+
+        clusters[i] = Some(Cluster {
+            newick: format!(
+                "({}:{:.6},{}:{:.6})",
+                a.newick,
+                height - a.height,
+                b.newick,
+                height - b.height
+            ),
+            size,
+            height,
+        });
+    }
+
+    clusters
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|root| format!("{};", root.newick))
+}
+
+/// Newick-safe leaf label for a species (`<id>_<name>_n<members>`)
+fn species_label(species_id: u32, members: usize) -> String {
+    let name: String = species_name(species_id)
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{species_id}_{name}_n{members}")
+}
+
+/// Cluster the populated species by centroid distance and append the tree to the Newick file
+pub fn export_species_dendrogram(
+    mut dendrogram: ResMut<SpeciesDendrogram>,
+    tracker: Res<SpeciesTracker>,
+    sim_time: Res<SimTime>,
+    logging: Res<LoggingConfig>,
+) {
+    if !logging.dendrogram_enabled || !sim_time.every(logging.dendrogram_interval) {
+        return;
+    }
+
+    let species = tracker.populated_centroids();
+    if species.is_empty() {
+        return;
+    }
+
+    let metric = tracker.metric();
+    let labels: Vec<String> = species
+        .iter()
+        .map(|(species_id, members, _)| species_label(*species_id, *members))
+        .collect();
+    let distances: Vec<Vec<f32>> = species
+        .iter()
+        .map(|(_, _, a)| {
+            species
+                .iter()
+                .map(|(_, _, b)| a.distance_with(b, metric))
+                .collect()
+        })
+        .collect();
+
+    let Some(newick) = upgma_newick(&labels, &distances) else {
+        return;
+    };
+    info!(
+        "[SPECIATION] Dendrogram of {} species at tick {}",
+        species.len(),
+        sim_time.tick
+    );
+
+    let tick = sim_time.tick;
+    if let Some(writer) = dendrogram.ensure_writer() {
+        if let Err(err) = writeln!(writer, "[tick={tick}] {newick}").and_then(|_| writer.flush()) {
+            error!("Failed to write species dendrogram: {err}");
+        }
+    }
+}
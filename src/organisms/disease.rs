@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use glam::Vec2;
-use crate::organisms::components::{Position, Energy, SpeciesId, Alive, CachedTraits};
+use crate::organisms::components::{Position, Energy, SpeciesId, Alive, CachedTraits, OrganismUid};
 use std::collections::HashMap;
 
 /// Disease system resource
@@ -14,15 +14,28 @@ pub struct DiseaseSystem {
     pub total_diseases: u32,
     /// Cooldown before next disease can spawn
     pub spawn_cooldown: f32,
+    /// Seed for infection rolls, which are keyed per contact (see `contact_roll`)
+    seed: u64,
+    /// Stream for disease timing and strength
+    rng: fastrand::Rng,
 }
 
 impl Default for DiseaseSystem {
     fn default() -> Self {
+        Self::seeded(0)
+    }
+}
+
+impl DiseaseSystem {
+    /// Diseases that replay from `seed` (the tuning's `organism_seed`)
+    pub fn seeded(seed: u64) -> Self {
         Self {
             active_diseases: Vec::new(),
             species_resistance: HashMap::new(),
             total_diseases: 0,
             spawn_cooldown: 800.0, // Initial cooldown
+            seed,
+            rng: fastrand::Rng::with_seed(seed.rotate_left(32)),
         }
     }
 }
@@ -80,6 +93,8 @@ pub fn update_disease_system(
     organism_query: Query<(Entity, &Position, &SpeciesId), With<Alive>>,
     infected_query: Query<(Entity, &Position, &Infected), With<Alive>>,
     spatial_hash: Res<crate::utils::SpatialHashGrid>,
+    sim_time: Res<crate::utils::SimTime>,
+    uid_query: Query<&OrganismUid>,
 ) {
     let dt = time.delta_seconds();
 
@@ -94,10 +109,10 @@ pub fn update_disease_system(
     // Spawn new diseases
     disease_system.spawn_cooldown -= dt;
     if disease_system.spawn_cooldown <= 0.0 {
-        if fastrand::f32() < 0.0005 { // Very rare
+        if disease_system.rng.f32() < 0.0005 { // Very rare
             spawn_random_disease(&mut disease_system, &organism_query);
         }
-        disease_system.spawn_cooldown = fastrand::f32() * 1200.0 + 600.0;
+        disease_system.spawn_cooldown = disease_system.rng.f32() * 1200.0 + 600.0;
     }
 
     // Spread diseases (read-only access to Infected)
@@ -107,6 +122,8 @@ pub fn update_disease_system(
         &organism_query,
         &infected_query,
         &spatial_hash,
+        &uid_query,
+        sim_time.tick,
         dt,
     );
 }
@@ -134,6 +151,8 @@ fn spread_diseases(
     organism_query: &Query<(Entity, &Position, &SpeciesId), With<Alive>>,
     infected_query: &Query<(Entity, &Position, &Infected), With<Alive>>,
     spatial_hash: &Res<crate::utils::SpatialHashGrid>,
+    uid_query: &Query<&OrganismUid>,
+    tick: u64,
     dt: f32,
 ) {
     let mut new_infections = Vec::new();
@@ -196,7 +215,14 @@ fn spread_diseases(
                         // Infection probability
                         let infection_chance = disease.virulence * distance_factor * (1.0 - resistance) * dt * 0.1;
                         
-                        if fastrand::f32() < infection_chance {
+                        let roll = contact_roll(
+                            disease_system.seed,
+                            tick,
+                            uid_of(uid_query, *infected_entity),
+                            uid_of(uid_query, entity),
+                            disease.id,
+                        );
+                        if roll < infection_chance {
                             new_infections.push((entity, disease.id));
                             break; // Only one infection per disease per tick per organism
                         }
@@ -221,6 +247,22 @@ fn spread_diseases(
     }
 }
 
+/// Infection roll for one infected-to-healthy contact this tick
+/// Keyed by the pair rather than drawn in sequence, so the outcome doesn't depend on query or
+/// spatial hash order.
+fn contact_roll(seed: u64, tick: u64, source_uid: u64, target_uid: u64, disease_id: u32) -> f32 {
+    let key = seed
+        ^ tick.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ source_uid.wrapping_mul(0xBF58_476D_1CE4_E5B9)
+        ^ target_uid.wrapping_mul(0x94D0_49BB_1331_11EB)
+        ^ (disease_id as u64).rotate_left(32);
+    fastrand::Rng::with_seed(key).f32()
+}
+
+fn uid_of(uid_query: &Query<&OrganismUid>, entity: Entity) -> u64 {
+    uid_query.get(entity).map_or(entity.to_bits(), |uid| uid.0)
+}
+
 /// Update infected organisms (apply damage)
 fn update_infected_organisms(
     commands: &mut Commands,
@@ -273,8 +315,10 @@ fn spawn_random_disease(
     disease_system: &mut DiseaseSystem,
     organism_query: &Query<(Entity, &Position, &SpeciesId), With<Alive>>,
 ) {
+    let rng = &mut disease_system.rng;
+
     // Choose random disease type
-    let disease_type = match fastrand::u8(..4) {
+    let disease_type = match rng.u8(..4) {
         0 => DiseaseType::Viral,
         1 => DiseaseType::Bacterial,
         2 => DiseaseType::Parasitic,
@@ -283,20 +327,22 @@ fn spawn_random_disease(
 
     // Set parameters based on type
     let (virulence, lethality, contagion_radius, duration) = match disease_type {
-        DiseaseType::Viral => (0.8 + fastrand::f32() * 0.2, 0.4 + fastrand::f32() * 0.3, 15.0, 200.0),
-        DiseaseType::Bacterial => (0.5 + fastrand::f32() * 0.3, 0.3 + fastrand::f32() * 0.5, 10.0, 300.0),
-        DiseaseType::Parasitic => (0.2 + fastrand::f32() * 0.3, 0.7 + fastrand::f32() * 0.3, 8.0, 500.0),
-        DiseaseType::Fungal => (0.3 + fastrand::f32() * 0.2, 0.5 + fastrand::f32() * 0.3, 12.0, 400.0),
+        DiseaseType::Viral => (0.8 + rng.f32() * 0.2, 0.4 + rng.f32() * 0.3, 15.0, 200.0),
+        DiseaseType::Bacterial => (0.5 + rng.f32() * 0.3, 0.3 + rng.f32() * 0.5, 10.0, 300.0),
+        DiseaseType::Parasitic => (0.2 + rng.f32() * 0.3, 0.7 + rng.f32() * 0.3, 8.0, 500.0),
+        DiseaseType::Fungal => (0.3 + rng.f32() * 0.2, 0.5 + rng.f32() * 0.3, 12.0, 400.0),
     };
 
     // Optionally target a random species (50% chance)
-    let target_species = if fastrand::f32() < 0.5 {
-        // Get a random species from organisms
-        let species_ids: Vec<u32> = organism_query.iter()
+    let target_species = if rng.f32() < 0.5 {
+        // Get a random species from organisms (sorted, since query order isn't stable)
+        let mut species_ids: Vec<u32> = organism_query.iter()
             .map(|(_, _, species_id)| species_id.value())
             .collect();
+        species_ids.sort_unstable();
+        species_ids.dedup();
         if !species_ids.is_empty() {
-            Some(species_ids[fastrand::usize(..species_ids.len())])
+            Some(species_ids[rng.usize(..species_ids.len())])
         } else {
             None
         }
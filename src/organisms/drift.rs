@@ -0,0 +1,164 @@
+use crate::organisms::components::Alive;
+use crate::organisms::genetics::{traits, Genome};
+use crate::organisms::LoggingConfig;
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Drift vs selection attribution
+///
+/// Neutral genes (see `traits::NEUTRAL_GENES`) are never expressed, so their population
+/// variance only changes through drift and mutation. Each functional gene's variance is
+/// tracked relative to its first sample ("retention") and compared with the neutral
+/// retention: a selection index well below 1.0 means the gene is losing variance faster
+/// than drift alone explains, i.e. it is under selection.
+#[derive(Resource)]
+pub struct DriftAnalysis {
+    /// Variances from the first sample (per gene), used as the retention baseline
+    baseline: Option<Vec<f32>>,
+    /// Latest neutral variance retention (current / baseline)
+    pub neutral_retention: f32,
+    /// Latest selection index per functional gene (gene retention / neutral retention)
+    pub selection_index: Vec<f32>,
+    csv_writer: Option<BufWriter<File>>,
+    csv_path: PathBuf,
+}
+
+impl Default for DriftAnalysis {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            baseline: None,
+            neutral_retention: 1.0,
+            selection_index: Vec::new(),
+            csv_writer: None,
+            csv_path: logs_dir.join(format!("drift_selection_{}.csv", timestamp)),
+        }
+    }
+}
+
+impl DriftAnalysis {
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            let file = match File::create(&self.csv_path) {
+                Ok(file) => file,
+                Err(err) => {
+                    error!("Failed to create drift CSV file: {err}");
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+
+            let mut header = String::from("tick,population,neutral_variance,neutral_retention");
+            for gene in traits::FUNCTIONAL_GENES {
+                header.push_str(&format!(",gene{gene}_variance,gene{gene}_selection_index"));
+            }
+            if let Err(err) = writeln!(writer, "{header}") {
+                error!("Failed to write drift CSV header: {err}");
+                return None;
+            }
+
+            info!("[DRIFT] Writing drift vs selection metrics to {}", self.csv_path.display());
+            self.csv_writer = Some(writer);
+        }
+        self.csv_writer.as_mut()
+    }
+}
+
+/// Per-gene population variance
+fn gene_variances(genomes: &[&Genome]) -> Vec<f32> {
+    let count = genomes.len() as f32;
+    (0..crate::organisms::genetics::GENOME_SIZE)
+        .map(|gene| {
+            let mean = genomes.iter().map(|g| g.get_gene(gene)).sum::<f32>() / count;
+            genomes
+                .iter()
+                .map(|g| (g.get_gene(gene) - mean).powi(2))
+                .sum::<f32>()
+                / count
+        })
+        .collect()
+}
+
+/// Sample gene variances and export drift vs selection metrics
+pub fn update_drift_analysis(
+    mut analysis: ResMut<DriftAnalysis>,
+    query: Query<&Genome, With<Alive>>,
+    sim_time: Res<SimTime>,
+    logging: Res<LoggingConfig>,
+) {
+    if !logging.drift_enabled || !sim_time.every(logging.drift_interval) {
+        return;
+    }
+
+    let genomes: Vec<&Genome> = query.iter().collect();
+    if genomes.len() < 2 {
+        return;
+    }
+
+    let variances = gene_variances(&genomes);
+    let baseline = analysis.baseline.get_or_insert_with(|| variances.clone()).clone();
+
+    let retention = |gene: usize| -> f32 {
+        if baseline[gene] > f32::EPSILON {
+            variances[gene] / baseline[gene]
+        } else {
+            1.0
+        }
+    };
+
+    let neutral_count = traits::NEUTRAL_GENES.len() as f32;
+    let neutral_variance = traits::NEUTRAL_GENES.map(|g| variances[g]).sum::<f32>() / neutral_count;
+    let neutral_retention = traits::NEUTRAL_GENES.map(retention).sum::<f32>() / neutral_count;
+
+    let selection_index: Vec<f32> = traits::FUNCTIONAL_GENES
+        .map(|gene| {
+            if neutral_retention > f32::EPSILON {
+                retention(gene) / neutral_retention
+            } else {
+                1.0
+            }
+        })
+        .collect();
+
+    // Genes losing variance fastest relative to the neutral baseline
+    let mut strongest: Vec<(usize, f32)> = traits::FUNCTIONAL_GENES
+        .zip(selection_index.iter().copied())
+        .collect();
+    strongest.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    let summary: Vec<String> = strongest
+        .iter()
+        .take(3)
+        .map(|(gene, index)| format!("gene {gene}: {index:.2}"))
+        .collect();
+    info!(
+        "[DRIFT] Tick {} | Neutral variance {:.4} (retention {:.2}) | Strongest selection: {}",
+        sim_time.tick,
+        neutral_variance,
+        neutral_retention,
+        summary.join(", ")
+    );
+
+    let tick = sim_time.tick;
+    let population = genomes.len();
+    let mut row = format!("{tick},{population},{neutral_variance:.6},{neutral_retention:.6}");
+    for (gene, index) in traits::FUNCTIONAL_GENES.zip(selection_index.iter()) {
+        row.push_str(&format!(",{:.6},{:.6}", variances[gene], index));
+    }
+
+    analysis.neutral_retention = neutral_retention;
+    analysis.selection_index = selection_index;
+
+    if let Some(writer) = analysis.ensure_writer() {
+        if let Err(err) = writeln!(writer, "{row}").and_then(|_| writer.flush()) {
+            error!("Failed to write drift CSV row: {err}");
+        }
+    }
+}
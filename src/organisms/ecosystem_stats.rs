@@ -1,9 +1,15 @@
 use crate::organisms::components::*;
+use crate::organisms::genetics::Genome;
+use crate::utils::{SimError, SimTime};
+use crate::world::{ResourceType, WorldGrid, RESOURCE_TYPE_COUNT};
 use bevy::prelude::*;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 /// Ecosystem statistics for Step 8 - Tuning and analysis
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct EcosystemStats {
     /// Total population count
     pub total_population: u32,
@@ -13,8 +19,161 @@ pub struct EcosystemStats {
     pub population_by_species: HashMap<u32, u32>,
     /// Average traits per species
     pub species_traits: HashMap<u32, SpeciesTraits>,
-    /// Tick counter for logging
-    pub tick_counter: u64,
+    /// (males, females) per species; empty while sexes are disabled
+    pub sex_counts_by_species: HashMap<u32, (u32, u32)>,
+    /// World-wide resource totals [Plant, Mineral, Sunlight, Water, Detritus, Prey]
+    /// (refreshed on each ecosystem summary log)
+    pub resource_totals: [f64; RESOURCE_TYPE_COUNT],
+    /// Distribution of `CachedTraits::reproductive_strategy_index` (r = 0.0, K = 1.0)
+    pub reproductive_strategy: StrategyDistribution,
+    /// Population mean of each trait normalized to its expression range, in `TRAIT_RANGES` order
+    pub normalized_trait_means: Vec<(&'static str, f32)>,
+    /// Species count at the last collection
+    pub species_count: u32,
+    /// Moving averages of the above over recent collections (kept across `reset`)
+    pub smoothed: SmoothedStats,
+    /// Mean pairwise genetic distance over a random sample of genomes (see `genetic_diversity`)
+    pub genetic_diversity: f32,
+    /// Genomes sampled for `genetic_diversity` (kept across `reset`)
+    pub diversity_sample_size: usize,
+}
+
+/// Collections averaged by default (one collection every 100 ticks)
+pub const DEFAULT_STATS_WINDOW: usize = 10;
+
+/// Genomes sampled for the diversity index by default (~20k distance computations)
+pub const DEFAULT_DIVERSITY_SAMPLE: usize = 200;
+
+/// Population health as a single number: mean pairwise RMS genetic distance (0 = clones)
+/// All pairs in the sample are compared, so cost grows with the square of the sample size
+/// rather than the population.
+pub fn genetic_diversity(sample: &[&Genome]) -> f32 {
+    let mut total = 0.0;
+    let mut pairs = 0u32;
+    for (index, genome) in sample.iter().enumerate() {
+        for other in &sample[index + 1..] {
+            total += genome.distance(other);
+            pairs += 1;
+        }
+    }
+    if pairs == 0 {
+        0.0
+    } else {
+        total / pairs as f32
+    }
+}
+
+/// Ring buffer of the most recent samples of one statistic
+#[derive(Default, Clone, Debug)]
+pub struct SlidingWindow {
+    samples: VecDeque<f32>,
+}
+
+impl SlidingWindow {
+    /// Add a sample, dropping the oldest ones beyond `capacity`
+    pub fn push(&mut self, value: f32, capacity: usize) {
+        self.samples.push_back(value);
+        while self.samples.len() > capacity.max(1) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Mean of the buffered samples (0 before the first one)
+    pub fn mean(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().sum::<f32>() / self.samples.len() as f32
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+/// Moving averages of population, species count and normalized trait means
+/// Damps the spikes from bursts of births and deaths; the instantaneous values stay on
+/// `EcosystemStats`.
+#[derive(Clone, Debug)]
+pub struct SmoothedStats {
+    /// Number of collections averaged
+    pub window: usize,
+    pub population: SlidingWindow,
+    pub species_count: SlidingWindow,
+    /// Per trait, in `TRAIT_RANGES` order
+    pub trait_means: Vec<(&'static str, SlidingWindow)>,
+}
+
+impl Default for SmoothedStats {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_STATS_WINDOW,
+            population: SlidingWindow::default(),
+            species_count: SlidingWindow::default(),
+            trait_means: Vec::new(),
+        }
+    }
+}
+
+impl SmoothedStats {
+    fn record(&mut self, population: u32, species_count: u32, trait_means: &[(&'static str, f32)]) {
+        let window = self.window;
+        self.population.push(population as f32, window);
+        self.species_count.push(species_count as f32, window);
+        if self.trait_means.len() != trait_means.len() {
+            self.trait_means = trait_means
+                .iter()
+                .map(|(name, _)| (*name, SlidingWindow::default()))
+                .collect();
+        }
+        for ((_, samples), (_, mean)) in self.trait_means.iter_mut().zip(trait_means) {
+            samples.push(*mean, window);
+        }
+    }
+
+    /// Smoothed mean of each normalized trait
+    pub fn trait_means(&self) -> impl Iterator<Item = (&'static str, f32)> + '_ {
+        self.trait_means.iter().map(|(name, samples)| (*name, samples.mean()))
+    }
+}
+
+/// Number of r/K histogram bins
+pub const STRATEGY_BINS: usize = 5;
+
+/// Population distribution on the r/K spectrum
+#[derive(Default, Clone, Debug)]
+pub struct StrategyDistribution {
+    pub mean: f32,
+    pub std_dev: f32,
+    /// Organism counts per equal-width bin from r (first) to K (last)
+    pub histogram: [u32; STRATEGY_BINS],
+}
+
+impl StrategyDistribution {
+    fn from_samples(samples: &[f32]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let count = samples.len() as f32;
+        let mean = samples.iter().sum::<f32>() / count;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / count;
+        let mut histogram = [0; STRATEGY_BINS];
+        for sample in samples {
+            let bin = ((sample * STRATEGY_BINS as f32) as usize).min(STRATEGY_BINS - 1);
+            histogram[bin] += 1;
+        }
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            histogram,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -26,12 +185,55 @@ pub struct SpeciesTraits {
     pub count: u32,
 }
 
+impl Default for EcosystemStats {
+    fn default() -> Self {
+        Self {
+            total_population: 0,
+            population_by_type: HashMap::new(),
+            population_by_species: HashMap::new(),
+            species_traits: HashMap::new(),
+            sex_counts_by_species: HashMap::new(),
+            resource_totals: [0.0; RESOURCE_TYPE_COUNT],
+            reproductive_strategy: StrategyDistribution::default(),
+            normalized_trait_means: Vec::new(),
+            species_count: 0,
+            smoothed: SmoothedStats::default(),
+            genetic_diversity: 0.0,
+            diversity_sample_size: DEFAULT_DIVERSITY_SAMPLE,
+        }
+    }
+}
+
 impl EcosystemStats {
+    /// Default stats with the smoothing window from `--stats-window <collections>` and the
+    /// diversity sample from `--diversity-sample <genomes>`
+    pub fn from_args() -> Self {
+        let mut stats = Self::default();
+        if let Some(value) = crate::utils::cli::option_value("stats-window") {
+            match value.trim().parse::<usize>() {
+                Ok(window) if window > 0 => stats.smoothed.window = window,
+                _ => warn!("Invalid stats window '{}', using {}", value, DEFAULT_STATS_WINDOW),
+            }
+        }
+        if let Some(value) = crate::utils::cli::option_value("diversity-sample") {
+            match value.trim().parse::<usize>() {
+                Ok(sample) if sample >= 2 => stats.diversity_sample_size = sample,
+                _ => warn!("Invalid diversity sample '{}', using {}", value, DEFAULT_DIVERSITY_SAMPLE),
+            }
+        }
+        stats
+    }
+
     pub fn reset(&mut self) {
         self.total_population = 0;
         self.population_by_type.clear();
         self.population_by_species.clear();
         self.species_traits.clear();
+        self.sex_counts_by_species.clear();
+        self.reproductive_strategy = StrategyDistribution::default();
+        self.normalized_trait_means.clear();
+        self.species_count = 0;
+        self.genetic_diversity = 0.0;
     }
 }
 
@@ -45,24 +247,42 @@ pub fn collect_ecosystem_stats(
             &Size,
             &Energy,
             &CachedTraits,
+            Option<&Sex>,
+            &Genome,
         ),
         With<Alive>,
     >,
     species_tracker: Option<Res<crate::organisms::speciation::SpeciesTracker>>,
+    world_grid: Option<Res<WorldGrid>>,
+    predation: Option<Res<crate::organisms::Predation>>,
+    sim_time: Res<SimTime>,
 ) {
-    stats.tick_counter += 1;
-    
     // Collect stats every 100 ticks (not every tick for performance)
-    if stats.tick_counter % 100 != 0 {
+    if !sim_time.every(100) {
         return;
     }
 
     stats.reset();
 
     let mut species_trait_data: HashMap<u32, (f32, f32, f32, f32, u32)> = HashMap::new();
+    let mut strategy_samples = Vec::new();
+    let trait_ranges = &crate::organisms::genetics::traits::TRAIT_RANGES;
+    let mut normalized_trait_sums = vec![0.0f32; trait_ranges.len()];
+    // Reservoir sample of genomes for the diversity index (seeded by tick, so runs repeat)
+    let sample_size = stats.diversity_sample_size;
+    let mut diversity_sample: Vec<&Genome> = Vec::with_capacity(sample_size);
+    let mut sample_rng = fastrand::Rng::with_seed(sim_time.tick);
 
-    for (species_id, org_type, size, energy, traits) in query.iter() {
+    for (species_id, org_type, size, energy, traits, sex, genome) in query.iter() {
         stats.total_population += 1;
+        if diversity_sample.len() < sample_size {
+            diversity_sample.push(genome);
+        } else {
+            let slot = sample_rng.usize(..stats.total_population as usize);
+            if slot < sample_size {
+                diversity_sample[slot] = genome;
+            }
+        }
         
         // Count by type
         *stats.population_by_type.entry(*org_type).or_insert(0) += 1;
@@ -70,6 +290,14 @@ pub fn collect_ecosystem_stats(
         // Count by species
         let species_id_val = species_id.value();
         *stats.population_by_species.entry(species_id_val).or_insert(0) += 1;
+
+        if let Some(sex) = sex {
+            let counts = stats.sex_counts_by_species.entry(species_id_val).or_insert((0, 0));
+            match sex {
+                Sex::Male => counts.0 += 1,
+                Sex::Female => counts.1 += 1,
+            }
+        }
         
         // Accumulate trait data per species
         let entry = species_trait_data.entry(species_id_val).or_insert((0.0, 0.0, 0.0, 0.0, 0));
@@ -78,7 +306,32 @@ pub fn collect_ecosystem_stats(
         entry.2 += traits.speed;
         entry.3 += traits.sensory_range;
         entry.4 += 1;
+
+        strategy_samples.push(traits.reproductive_strategy_index());
+        for (sum, range) in normalized_trait_sums.iter_mut().zip(trait_ranges.iter()) {
+            *sum += traits.normalized(range.name).unwrap_or(0.0);
+        }
     }
+    let population = stats.total_population.max(1) as f32;
+    stats.normalized_trait_means = trait_ranges
+        .iter()
+        .zip(normalized_trait_sums)
+        .map(|(range, sum)| (range.name, sum / population))
+        .collect();
+    stats.reproductive_strategy = StrategyDistribution::from_samples(&strategy_samples);
+    stats.genetic_diversity = genetic_diversity(&diversity_sample);
+    stats.species_count = species_tracker
+        .as_deref()
+        .map(|t| t.species_count() as u32)
+        .unwrap_or(0);
+    let EcosystemStats {
+        total_population,
+        species_count,
+        normalized_trait_means,
+        smoothed,
+        ..
+    } = &mut *stats;
+    smoothed.record(*total_population, *species_count, normalized_trait_means);
 
     // Calculate averages
     for (species_id, (size_sum, energy_sum, speed_sum, sensory_sum, count)) in species_trait_data {
@@ -97,24 +350,266 @@ pub fn collect_ecosystem_stats(
     }
 
     // Log ecosystem summary every 500 ticks
-    if stats.tick_counter % 500 == 0 {
-        let species_count = species_tracker
-            .map(|t| t.species_count())
-            .unwrap_or(0);
-        
+    if sim_time.every(500) {
         let producers = stats.population_by_type.get(&OrganismType::Producer).copied().unwrap_or(0);
         let consumers = stats.population_by_type.get(&OrganismType::Consumer).copied().unwrap_or(0);
         let decomposers = stats.population_by_type.get(&OrganismType::Decomposer).copied().unwrap_or(0);
 
         info!(
-            "[ECOSYSTEM] Tick {} | Population: {} | Species: {} | Producers: {} | Consumers: {} | Decomposers: {}",
-            stats.tick_counter,
+            "[ECOSYSTEM] Tick {} | Population: {} (avg {:.0}) | Species: {} (avg {:.1}) | Diversity: {:.3} | Producers: {} | Consumers: {} | Decomposers: {}",
+            sim_time.tick,
             stats.total_population,
-            species_count,
+            stats.smoothed.population.mean(),
+            stats.species_count,
+            stats.smoothed.species_count.mean(),
+            stats.genetic_diversity,
             producers,
             consumers,
             decomposers
         );
+
+        let strategy = &stats.reproductive_strategy;
+        info!(
+            "[ECOSYSTEM] r/K strategy | Mean: {:.3} | StdDev: {:.3} | Histogram (r->K): {:?}",
+            strategy.mean,
+            strategy.std_dev,
+            strategy.histogram
+        );
+
+        if let Some(predation) = predation {
+            let attempts = predation.captures + predation.wounds + predation.escapes;
+            if attempts > 0 {
+                info!(
+                    "[ECOSYSTEM] Predation | Captures: {} | Escapes: {} | Success rate: {:.1}% | Wounded prey: {} | Predator injuries: {} | Strike energy lost: {:.1}",
+                    predation.captures,
+                    predation.escapes,
+                    predation.captures as f32 / attempts as f32 * 100.0,
+                    predation.wounds,
+                    predation.injuries,
+                    predation.strike_energy_lost
+                );
+            }
+        }
+
+        // Normalized means are directly comparable across traits (0 = range min, 1 = range max)
+        let trait_means: Vec<String> = stats
+            .normalized_trait_means
+            .iter()
+            .map(|(name, mean)| format!("{name} {mean:.2}"))
+            .collect();
+        debug!("[ECOSYSTEM] Normalized trait means | {}", trait_means.join(" | "));
+
+        // Sex ratios per species (skewed species included - they're the interesting ones)
+        let mut sex_counts: Vec<_> = stats.sex_counts_by_species.iter().collect();
+        sex_counts.sort_by_key(|(species, _)| **species);
+        for (species, (males, females)) in sex_counts {
+            let female_share = *females as f32 / (*males + *females).max(1) as f32;
+            let skewed = !(0.25..=0.75).contains(&female_share);
+            info!(
+                "[ECOSYSTEM] Species {} ({}) sex ratio | Males: {} | Females: {} ({:.0}% female){}",
+                species,
+                crate::organisms::species_name(*species),
+                males,
+                females,
+                female_share * 100.0,
+                if skewed { " | SKEWED" } else { "" }
+            );
+        }
+
+        if let Some(world_grid) = world_grid {
+            stats.resource_totals = world_grid.total_resources();
+            let totals = &stats.resource_totals;
+            info!(
+                "[ECOSYSTEM] Resource totals | {}",
+                crate::world::format_resource_amounts(totals)
+            );
+
+            let mut by_terrain: Vec<_> = world_grid.total_resources_by_terrain().into_iter().collect();
+            by_terrain.sort_by_key(|(terrain, _)| *terrain as u8);
+            for (terrain, totals) in by_terrain {
+                debug!(
+                    "[ECOSYSTEM] {:?} resources | {}",
+                    terrain,
+                    crate::world::format_resource_amounts(&totals)
+                );
+            }
+        }
+    }
+}
+
+
+/// Machine-readable stats dump for external dashboards
+/// Every `interval` ticks the latest `EcosystemStats`, a climate summary, and world resource
+/// totals are written to `path` (replaced atomically, so readers never see partial JSON)
+/// and, if `history_path` is set, appended as one line to a JSONL history.
+#[derive(Resource)]
+pub struct StatsExport {
+    pub interval: u64,
+    pub path: PathBuf,
+    pub history_path: Option<PathBuf>,
+}
+
+impl Default for StatsExport {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        Self {
+            interval: 500,
+            path: logs_dir.join("stats.json"),
+            history_path: None,
+        }
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ClimateSnapshot {
+    pub base_temperature: f32,
+    pub base_humidity: f32,
+    pub season: f32,
+    pub wind: [f32; 2],
+    pub active_events: usize,
+}
+
+/// One exported stats dump (also read back by the `--diff` report)
+#[derive(Serialize, Deserialize)]
+pub struct StatsSnapshot {
+    pub tick: u64,
+    /// Older exports predate these two fields
+    #[serde(default)]
+    pub world_seed: Option<u64>,
+    #[serde(default)]
+    pub chunk_count: usize,
+    pub total_population: u32,
+    pub population_by_type: BTreeMap<String, u32>,
+    pub population_by_species: BTreeMap<u32, u32>,
+    pub species_count: usize,
+    pub reproductive_strategy_mean: f32,
+    pub reproductive_strategy_std_dev: f32,
+    pub reproductive_strategy_histogram: [u32; STRATEGY_BINS],
+    pub normalized_trait_means: BTreeMap<String, f32>,
+    pub climate: Option<ClimateSnapshot>,
+    pub resource_totals: BTreeMap<String, f64>,
+    /// Moving averages over the last `smoothing_window` collections (absent in older exports)
+    #[serde(default)]
+    pub smoothing_window: usize,
+    #[serde(default)]
+    pub smoothed_population: f32,
+    #[serde(default)]
+    pub smoothed_species_count: f32,
+    #[serde(default)]
+    pub smoothed_trait_means: BTreeMap<String, f32>,
+    /// Mean pairwise genetic distance over `diversity_sample_size` sampled genomes
+    #[serde(default)]
+    pub genetic_diversity: f32,
+    #[serde(default)]
+    pub diversity_sample_size: usize,
+    /// Every bottleneck applied so far (see `Bottleneck`)
+    #[serde(default)]
+    pub bottlenecks: Vec<crate::organisms::BottleneckRecord>,
+}
+
+impl StatsSnapshot {
+    /// Read a snapshot previously written by `export_stats_json`
+    pub fn load(path: &Path) -> Result<Self, SimError> {
+        let contents = std::fs::read_to_string(path).map_err(|err| SimError::io(path, err))?;
+        serde_json::from_str(&contents).map_err(|err| SimError::serialization(path, err))
+    }
+}
+
+/// Write `contents` to a sibling temp file, then rename it over `path`
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path)
+}
+
+/// Serialize the latest stats to JSON every `StatsExport::interval` ticks
+pub fn export_stats_json(
+    export: Res<StatsExport>,
+    stats: Res<EcosystemStats>,
+    species_tracker: Option<Res<crate::organisms::speciation::SpeciesTracker>>,
+    climate: Option<Res<crate::world::ClimateState>>,
+    world_grid: Option<Res<WorldGrid>>,
+    world_seed: Option<Res<crate::world::WorldSeed>>,
+    bottleneck: Option<Res<crate::organisms::Bottleneck>>,
+    sim_time: Res<SimTime>,
+) {
+    if !sim_time.every(export.interval) {
+        return;
+    }
+
+    let chunk_count = world_grid.as_deref().map(|grid| grid.chunk_count()).unwrap_or(0);
+    let totals = world_grid
+        .map(|grid| grid.total_resources())
+        .unwrap_or([0.0; RESOURCE_TYPE_COUNT]);
+    let snapshot = StatsSnapshot {
+        tick: sim_time.tick,
+        world_seed: world_seed.map(|seed| seed.0),
+        chunk_count,
+        total_population: stats.total_population,
+        population_by_type: stats
+            .population_by_type
+            .iter()
+            .map(|(org_type, count)| (format!("{:?}", org_type), *count))
+            .collect(),
+        population_by_species: stats
+            .population_by_species
+            .iter()
+            .map(|(species, count)| (*species, *count))
+            .collect(),
+        species_count: species_tracker.map(|t| t.species_count()).unwrap_or(0),
+        reproductive_strategy_mean: stats.reproductive_strategy.mean,
+        reproductive_strategy_std_dev: stats.reproductive_strategy.std_dev,
+        reproductive_strategy_histogram: stats.reproductive_strategy.histogram,
+        normalized_trait_means: stats
+            .normalized_trait_means
+            .iter()
+            .map(|(name, mean)| (name.to_string(), *mean))
+            .collect(),
+        climate: climate.map(|climate| ClimateSnapshot {
+            base_temperature: climate.base_temperature,
+            base_humidity: climate.base_humidity,
+            season: climate.season,
+            wind: [climate.wind.x, climate.wind.y],
+            active_events: climate.events.len(),
+        }),
+        resource_totals: ResourceType::all()
+            .map(|resource| (resource.name().to_string(), totals[resource.index()]))
+            .collect(),
+        smoothing_window: stats.smoothed.window,
+        smoothed_population: stats.smoothed.population.mean(),
+        smoothed_species_count: stats.smoothed.species_count.mean(),
+        smoothed_trait_means: stats
+            .smoothed
+            .trait_means()
+            .map(|(name, mean)| (name.to_string(), mean))
+            .collect(),
+        genetic_diversity: stats.genetic_diversity,
+        diversity_sample_size: stats.diversity_sample_size,
+        bottlenecks: bottleneck.map(|bottleneck| bottleneck.history.clone()).unwrap_or_default(),
+    };
+
+    let json = match serde_json::to_string(&snapshot) {
+        Ok(json) => json,
+        Err(err) => {
+            error!("Failed to serialize stats: {err}");
+            return;
+        }
+    };
+
+    if let Err(err) = write_atomically(&export.path, &json) {
+        error!("Failed to write stats JSON to {}: {err}", export.path.display());
+    }
+
+    if let Some(history_path) = &export.history_path {
+        let appended = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(history_path)
+            .and_then(|mut file| writeln!(file, "{json}"));
+        if let Err(err) = appended {
+            error!("Failed to append stats to {}: {err}", history_path.display());
+        }
+    }
+}
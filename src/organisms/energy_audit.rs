@@ -0,0 +1,258 @@
+use crate::organisms::components::{Energy, Stomach};
+use crate::organisms::TrophicFlows;
+use crate::world::{resource_capacity, ResourceType, WorldGrid, CHUNK_SIZE};
+use bevy::prelude::*;
+
+/// Absolute slack for balance and bound checks
+const BALANCE_TOLERANCE: f64 = 1e-3;
+
+/// Per-tick invariant checks for energy and resource accounting (`energy-audit` feature)
+/// Snapshots are taken around `update_metabolism` and `handle_eating` so each system's net
+/// change can be compared with what it reported to `TrophicFlows`. Violations are logged
+/// with the offending entity/cell and fail a debug assertion.
+#[derive(Resource, Default)]
+pub struct EnergyAudit {
+    before: Option<AuditSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AuditSnapshot {
+    /// Energy held by organisms, including undigested stomach contents
+    organism_energy: f64,
+    /// Resources across all loaded cells
+    world_resources: f64,
+    /// Running trophic totals (intake, metabolism) for the current export interval
+    intake: f64,
+    metabolism: f64,
+}
+
+impl AuditSnapshot {
+    fn take(
+        organisms: &Query<(Entity, &Energy, Option<&Stomach>)>,
+        world_grid: &WorldGrid,
+        trophic: &TrophicFlows,
+    ) -> Self {
+        let totals = trophic.current;
+        Self {
+            organism_energy: organisms
+                .iter()
+                .map(|(_, energy, stomach)| {
+                    energy.current as f64 + stomach.map_or(0.0, |stomach| stomach.contents as f64)
+                })
+                .sum(),
+            world_resources: world_grid.total_resources().iter().sum(),
+            intake: totals.environment_to_producers
+                + totals.producers_to_consumers
+                + totals.consumers_to_consumers
+                + totals.into_decomposers,
+            metabolism: totals.producer_metabolism
+                + totals.consumer_metabolism
+                + totals.decomposer_metabolism,
+        }
+    }
+}
+
+/// Whether two totals agree, allowing f32 rounding across a stock of size `scale`
+/// Every organism rounds its own update, so the error grows with total stored energy.
+fn within_tolerance(actual: f64, expected: f64, scale: f64) -> bool {
+    let slack = BALANCE_TOLERANCE.max(scale.abs() * f32::EPSILON as f64 * 4.0);
+    (actual - expected).abs() <= slack
+}
+
+/// The snapshot taken before `update_metabolism`, the first of each tick
+/// (`snapshot_energy` runs twice, so its own system set can't be used for ordering)
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FirstSnapshotSet;
+
+/// Record totals before an audited system runs
+pub fn snapshot_energy(
+    mut audit: ResMut<EnergyAudit>,
+    organisms: Query<(Entity, &Energy, Option<&Stomach>)>,
+    world_grid: Res<WorldGrid>,
+    trophic: Res<TrophicFlows>,
+) {
+    audit.before = Some(AuditSnapshot::take(&organisms, &world_grid, &trophic));
+}
+
+/// Metabolism may only remove organism energy, and exactly as much as it reported
+pub fn audit_metabolism(
+    mut audit: ResMut<EnergyAudit>,
+    organisms: Query<(Entity, &Energy, Option<&Stomach>)>,
+    world_grid: Res<WorldGrid>,
+    trophic: Res<TrophicFlows>,
+) {
+    let Some(before) = audit.before.take() else {
+        return;
+    };
+    let after = AuditSnapshot::take(&organisms, &world_grid, &trophic);
+
+    let spent = before.organism_energy - after.organism_energy;
+    let reported = after.metabolism - before.metabolism;
+    let balanced = within_tolerance(spent, reported, before.organism_energy);
+    if !balanced {
+        error!(
+            "[ENERGY AUDIT] update_metabolism removed {:.6} energy but reported {:.6} as metabolism",
+            spent, reported
+        );
+    }
+    debug_assert!(balanced, "update_metabolism energy imbalance");
+}
+
+/// Eating may only move resources into organisms: stored energy grows by exactly the
+/// reported intake, and world resources never grow
+pub fn audit_eating(
+    mut audit: ResMut<EnergyAudit>,
+    organisms: Query<(Entity, &Energy, Option<&Stomach>)>,
+    world_grid: Res<WorldGrid>,
+    trophic: Res<TrophicFlows>,
+) {
+    let Some(before) = audit.before.take() else {
+        return;
+    };
+    let after = AuditSnapshot::take(&organisms, &world_grid, &trophic);
+
+    let gained = after.organism_energy - before.organism_energy;
+    let reported = after.intake - before.intake;
+    let balanced = within_tolerance(gained, reported, after.organism_energy);
+    if !balanced {
+        error!(
+            "[ENERGY AUDIT] handle_eating added {:.6} energy but reported {:.6} as intake",
+            gained, reported
+        );
+    }
+
+    let resources_added = after.world_resources - before.world_resources;
+    let conserved = within_tolerance(resources_added.max(0.0), 0.0, before.world_resources);
+    if !conserved {
+        error!(
+            "[ENERGY AUDIT] handle_eating added {:.6} resources to the world",
+            resources_added
+        );
+    }
+    debug_assert!(balanced && conserved, "handle_eating energy/resource imbalance");
+}
+
+/// Energy stays within [0, max] and resources within [0, terrain capacity]
+pub fn audit_bounds(
+    organisms: Query<(Entity, &Energy, Option<&Stomach>)>,
+    world_grid: Res<WorldGrid>,
+) {
+    let mut violations = 0usize;
+
+    for (entity, energy, _) in organisms.iter() {
+        let slack = BALANCE_TOLERANCE as f32 * energy.max.max(1.0);
+        if !energy.current.is_finite()
+            || energy.current < 0.0
+            || energy.current > energy.max + slack
+        {
+            error!(
+                "[ENERGY AUDIT] {:?} energy {:.6} outside [0, {:.6}]",
+                entity, energy.current, energy.max
+            );
+            violations += 1;
+        }
+    }
+
+    for (chunk_x, chunk_y) in world_grid.get_chunk_coords() {
+        let Some(chunk) = world_grid.get_chunk(chunk_x, chunk_y) else {
+            continue;
+        };
+        for (index, cell) in chunk.cells().iter().enumerate() {
+            for resource_type in ResourceType::all() {
+                let value = cell.get_resource(resource_type);
+                let capacity = resource_capacity(cell.terrain, resource_type);
+                if !value.is_finite() || value < 0.0 || value > capacity + BALANCE_TOLERANCE as f32 {
+                    error!(
+                        "[ENERGY AUDIT] Cell ({}, {}) in chunk ({}, {}) has {} {:.6} outside [0, {:.6}] ({:?})",
+                        index % CHUNK_SIZE,
+                        index / CHUNK_SIZE,
+                        chunk_x,
+                        chunk_y,
+                        resource_type.name(),
+                        value,
+                        capacity,
+                        cell.terrain
+                    );
+                    violations += 1;
+                }
+            }
+        }
+    }
+
+    debug_assert!(violations == 0, "{violations} energy/resource bound violations");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::components::OrganismType;
+
+    /// One organism with `energy` of 100 max, plus an empty world and fresh trophic totals
+    fn audit_world(energy: f32) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<EnergyAudit>();
+        world.init_resource::<WorldGrid>();
+        world.init_resource::<TrophicFlows>();
+        let organism = world.spawn(Energy::with_energy(100.0, energy)).id();
+        (world, organism)
+    }
+
+    fn run<M>(world: &mut World, system: impl IntoSystemConfigs<M>) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(system);
+        schedule.run(world);
+    }
+
+    /// Snapshot, spend `spent` energy while reporting `reported` as metabolism, then audit
+    fn audit_spending(spent: f32, reported: f32) {
+        let (mut world, organism) = audit_world(50.0);
+        run(&mut world, snapshot_energy);
+        world.get_mut::<Energy>(organism).unwrap().current -= spent;
+        world
+            .resource_mut::<TrophicFlows>()
+            .record_metabolism(OrganismType::Consumer, reported);
+        run(&mut world, audit_metabolism);
+    }
+
+    #[test]
+    fn tolerance_grows_with_the_stock() {
+        assert!(within_tolerance(1.0, 1.0005, 1.0));
+        assert!(!within_tolerance(1.0, 1.01, 1.0));
+        // f32 rounding across a large stock isn't a violation
+        assert!(within_tolerance(1.0e6, 1.0e6 + 0.2, 1.0e6));
+    }
+
+    #[test]
+    fn reported_metabolism_balances() {
+        audit_spending(5.0, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "update_metabolism energy imbalance")]
+    fn unreported_metabolism_is_caught() {
+        audit_spending(5.0, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bound violations")]
+    fn energy_above_max_is_caught() {
+        let (mut world, organism) = audit_world(50.0);
+        world.get_mut::<Energy>(organism).unwrap().current = 150.0;
+        run(&mut world, audit_bounds);
+    }
+
+    #[test]
+    fn energy_within_bounds_passes() {
+        let (mut world, _) = audit_world(50.0);
+        run(&mut world, audit_bounds);
+    }
+
+    #[test]
+    fn organism_plugin_schedule_builds_with_the_audit() {
+        // Both snapshots are `snapshot_energy`; ordering against its own set would be ambiguous
+        let mut app = crate::instance::SimInstance::new("energy_audit_schedule_test", 1).into_app();
+        app.world.resource_scope(|world, mut schedules: Mut<Schedules>| {
+            schedules.get_mut(Update).unwrap().initialize(world).unwrap();
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use crate::organisms::components::{Alive, OrganismUids};
+use crate::organisms::speciation::SpeciesTracker;
+use crate::organisms::systems::{spawn_founders, DeterministicOrder, TrackedOrganisms};
+use crate::organisms::EcosystemTuning;
+use crate::utils::SimTime;
+use bevy::prelude::*;
+
+/// Process exit code for an `--instances` run in which an instance ended in extinction
+pub const EXTINCTION_EXIT_CODE: i32 = 3;
+
+/// Ecosystem extinction tracking
+/// While extinct (and not restarting), world and organism updates are paused.
+#[derive(Resource, Default)]
+pub struct Extinction {
+    /// Tick at which the population reached zero (None while organisms are alive)
+    pub extinct_at: Option<u64>,
+    /// Number of extinctions so far (also offsets the founder seed on restart)
+    pub count: u64,
+}
+
+/// Run condition: the ecosystem still has organisms to simulate
+pub fn ecosystem_active(extinction: Res<Extinction>) -> bool {
+    extinction.extinct_at.is_none()
+}
+
+/// Detect total extinction and either restart or pause
+pub fn handle_extinction(
+    mut commands: Commands,
+    mut extinction: ResMut<Extinction>,
+    alive_query: Query<(), With<Alive>>,
+    sim_time: Res<SimTime>,
+    tuning: Res<EcosystemTuning>,
+    mut tracked: ResMut<TrackedOrganisms>,
+    mut species_tracker: ResMut<SpeciesTracker>,
+    mut uids: ResMut<OrganismUids>,
+    order: Res<DeterministicOrder>,
+) {
+    if extinction.extinct_at.is_some() || !alive_query.is_empty() {
+        return;
+    }
+
+    extinction.count += 1;
+    warn!(
+        "[EXTINCTION] Ecosystem extinction at tick {} (extinction #{})",
+        sim_time.tick, extinction.count
+    );
+
+    if tuning.restart_on_extinction {
+        info!("[EXTINCTION] Respawning a fresh founder population");
+        let seed = tuning.organism_seed.wrapping_add(extinction.count);
+        spawn_founders(
+            &mut commands,
+            &mut tracked,
+            &mut species_tracker,
+            &mut uids,
+            &tuning,
+            seed,
+            *order,
+        );
+        return;
+    }
+
+    extinction.extinct_at = Some(sim_time.tick);
+    info!("[EXTINCTION] Simulation paused (set restart_on_extinction to respawn instead)");
+}
@@ -13,10 +13,8 @@ pub struct Genome {
 }
 
 impl Genome {
-    /// Create a new random genome
-    /// Optimized: Uses fastrand for better performance
-    pub fn random() -> Self {
-        let mut rng = fastrand::Rng::new();
+    /// Create a new random genome drawing from the given stream
+    pub fn random_with_rng(rng: &mut fastrand::Rng) -> Self {
         let mut genes = SmallVec::new();
         for _ in 0..GENOME_SIZE {
             genes.push(rng.f32());
@@ -53,33 +51,28 @@ impl Genome {
         }
     }
 
-    /// Clone genome with optional mutations
-    /// Optimized: Uses faster uniform mutation instead of expensive Box-Muller transform
-    pub fn clone_with_mutation(&self, mutation_rate: f32) -> Self {
-        let mut new_genes = SmallVec::new();
-        let mut rng = fastrand::Rng::new();
-
-        for &gene in self.genes.iter() {
-            let mut new_gene = gene;
-
-            // Apply mutation with probability
-            if rng.f32() < mutation_rate {
-                // Uniform mutation: add random value in range [-0.1, 0.1]
-                // This is faster than Box-Muller and produces similar results for small mutations
-                let mutation = (rng.f32() - 0.5) * 0.2;
-                new_gene = (new_gene + mutation).clamp(0.0, 1.0);
-            }
-
-            new_genes.push(new_gene);
-        }
-
-        Self { genes: new_genes }
+    /// Clone genome, mutating genes with the given operator mix drawn from the given stream
+    /// Also returns the mutations that were applied.
+    pub fn clone_with_operators(
+        &self,
+        mutation_rate: f32,
+        operators: &MutationOperators,
+        rng: &mut fastrand::Rng,
+    ) -> (Self, MutationSpectrum) {
+        let mut genome = self.clone();
+        let spectrum = genome.mutate(mutation_rate, operators, rng);
+        (genome, spectrum)
     }
 
-    /// Crossover two genomes (sexual reproduction)
-    /// Optimized: Uses faster uniform mutation instead of expensive Box-Muller transform
-    pub fn crossover(parent_a: &Genome, parent_b: &Genome, mutation_rate: f32) -> Self {
-        let mut rng = fastrand::Rng::new();
+    /// Crossover two genomes, then mutate the child with the given operator mix
+    /// (draws come from the given stream). Also returns the mutations applied to the child.
+    pub fn crossover_with_operators(
+        parent_a: &Genome,
+        parent_b: &Genome,
+        mutation_rate: f32,
+        operators: &MutationOperators,
+        rng: &mut fastrand::Rng,
+    ) -> (Self, MutationSpectrum) {
         let mut new_genes = SmallVec::new();
 
         // Uniform crossover: for each gene, randomly choose from parent A or B
@@ -88,35 +81,273 @@ impl Genome {
             let gene_b = parent_b.get_gene(i);
 
             // 50/50 chance to choose from each parent
-            let mut new_gene = if rng.bool() { gene_a } else { gene_b };
+            new_genes.push(if rng.bool() { gene_a } else { gene_b });
+        }
 
-            // Apply mutation with probability
-            if rng.f32() < mutation_rate {
-                // Uniform mutation: add random value in range [-0.1, 0.1]
-                let mutation = (rng.f32() - 0.5) * 0.2;
-                new_gene = (new_gene + mutation).clamp(0.0, 1.0);
+        let mut child = Self { genes: new_genes };
+        let spectrum = child.mutate(mutation_rate, operators, rng);
+        (child, spectrum)
+    }
+
+    /// Mutate each gene with probability `mutation_rate`, picking an operator per mutation
+    fn mutate(
+        &mut self,
+        mutation_rate: f32,
+        operators: &MutationOperators,
+        rng: &mut fastrand::Rng,
+    ) -> MutationSpectrum {
+        let mut spectrum = MutationSpectrum::default();
+        for index in 0..self.genes.len() {
+            if rng.f32() >= mutation_rate {
+                continue;
             }
 
-            new_genes.push(new_gene);
+            let operator = operators.pick(rng);
+            let before = self.genes[index];
+            let delta = match operator {
+                MutationOperator::Uniform => {
+                    // Add random value in range [-range, range]
+                    let mutation = (rng.f32() - 0.5) * 2.0 * operators.uniform_range;
+                    self.genes[index] = (self.genes[index] + mutation).clamp(0.0, 1.0);
+                    self.genes[index] - before
+                }
+                MutationOperator::Gaussian => {
+                    let mutation = standard_normal(rng) * operators.gaussian_sigma;
+                    self.genes[index] = (self.genes[index] + mutation).clamp(0.0, 1.0);
+                    self.genes[index] - before
+                }
+                MutationOperator::Duplication => {
+                    // Tandem duplication: copy this gene into the next slot and shift the rest
+                    // right. The genome stays GENOME_SIZE long (trait indices are fixed), so the
+                    // last gene falls off the end.
+                    // Every gene after `index` moves, so the change is the shift summed over them.
+                    let length = self.genes.len();
+                    let shifted: SmallVec<[f32; GENOME_SIZE]> =
+                        self.genes[index + 1..].iter().copied().collect();
+                    let copy = self.genes[index].clamp(0.0, 1.0);
+                    self.genes.insert(index + 1, copy);
+                    self.genes.truncate(length);
+                    shifted
+                        .iter()
+                        .zip(&self.genes[index + 1..])
+                        .map(|(old, new)| (new - old).abs())
+                        .sum::<f32>()
+                }
+                MutationOperator::Macro => {
+                    // Reset the gene entirely
+                    self.genes[index] = rng.f32();
+                    self.genes[index] - before
+                }
+            };
+            spectrum.mutations.push(GeneMutation {
+                gene: index,
+                operator,
+                delta,
+            });
         }
-
-        Self { genes: new_genes }
+        spectrum
     }
 
     /// Calculate genetic distance between two genomes (for speciation)
     pub fn distance(&self, other: &Genome) -> f32 {
+        self.distance_with(other, GeneticDistanceMetric::Rms)
+    }
+
+    /// Genetic distance under the given metric
+    /// Every metric is a (weighted) root mean squared difference over the overlapping genes,
+    /// so thresholds stay on the same [0,1] scale whichever one is selected.
+    pub fn distance_with(&self, other: &Genome, metric: GeneticDistanceMetric) -> f32 {
         let mut sum = 0.0;
+        let mut total_weight = 0.0;
         let min_len = self.genes.len().min(other.genes.len());
 
         for i in 0..min_len {
+            let weight = metric.gene_weight(i);
+            if weight <= 0.0 {
+                continue;
+            }
             let diff = (self.genes[i] - other.genes[i]).abs();
-            sum += diff * diff; // Squared difference
+            sum += weight * diff * diff; // Weighted squared difference
+            total_weight += weight;
+        }
+
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        (sum / total_weight).sqrt()
+    }
+}
+
+/// How genetic distance weighs individual genes (selected via `EcosystemTuning`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeneticDistanceMetric {
+    /// Every gene counts equally, neutral genes included
+    #[default]
+    Rms,
+    /// Primary trait drivers count fully, modifier genes half, neutral genes not at all
+    Weighted,
+    /// Only genes that feed trait expression, weighted equally
+    Functional,
+}
+
+impl GeneticDistanceMetric {
+    pub fn label(self) -> &'static str {
+        match self {
+            GeneticDistanceMetric::Rms => "rms",
+            GeneticDistanceMetric::Weighted => "weighted",
+            GeneticDistanceMetric::Functional => "functional",
+        }
+    }
+
+    /// The following metric, wrapping around (for cycling through them at runtime)
+    pub fn next(self) -> Self {
+        match self {
+            GeneticDistanceMetric::Rms => GeneticDistanceMetric::Weighted,
+            GeneticDistanceMetric::Weighted => GeneticDistanceMetric::Functional,
+            GeneticDistanceMetric::Functional => GeneticDistanceMetric::Rms,
+        }
+    }
+
+    /// Weight of a gene's difference under this metric
+    pub fn gene_weight(self, index: usize) -> f32 {
+        match self {
+            GeneticDistanceMetric::Rms => 1.0,
+            GeneticDistanceMetric::Weighted => {
+                if traits::PRIMARY_GENES.contains(&index) {
+                    1.0
+                } else if traits::FUNCTIONAL_GENES.contains(&index) {
+                    0.5
+                } else {
+                    0.0
+                }
+            }
+            GeneticDistanceMetric::Functional => {
+                if traits::FUNCTIONAL_GENES.contains(&index) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Individual mutation operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutationOperator {
+    /// Small uniform jitter around the current value
+    Uniform,
+    /// Normally distributed jitter (Box-Muller)
+    Gaussian,
+    /// Tandem duplication of a gene into its neighbor slot
+    Duplication,
+    /// Rare large jump: gene replaced with a fresh random value
+    Macro,
+}
+
+impl MutationOperator {
+    pub fn label(self) -> &'static str {
+        match self {
+            MutationOperator::Uniform => "uniform",
+            MutationOperator::Gaussian => "gaussian",
+            MutationOperator::Duplication => "duplication",
+            MutationOperator::Macro => "macro",
         }
+    }
+}
+
+/// One mutation applied while copying a genome
+#[derive(Debug, Clone, Copy)]
+pub struct GeneMutation {
+    /// Gene the mutation hit
+    pub gene: usize,
+    pub operator: MutationOperator,
+    /// Change in the gene's value; for duplication, the summed absolute change of every gene
+    /// after it, all of which shift one slot right
+    pub delta: f32,
+}
+
+/// Every mutation applied to one offspring genome, in gene order
+#[derive(Debug, Clone, Default)]
+pub struct MutationSpectrum {
+    /// Mutations are rare at typical rates, so a few fit inline
+    pub mutations: SmallVec<[GeneMutation; 4]>,
+}
 
-        (sum / min_len as f32).sqrt() // Root mean squared difference
+impl MutationSpectrum {
+    /// Mean and largest absolute change over the mutations (0 if there were none)
+    pub fn magnitudes(&self) -> (f32, f32) {
+        if self.mutations.is_empty() {
+            return (0.0, 0.0);
+        }
+        let (sum, max) = self.mutations.iter().fold((0.0f32, 0.0f32), |(sum, max), mutation| {
+            let magnitude = mutation.delta.abs();
+            (sum + magnitude, max.max(magnitude))
+        });
+        (sum / self.mutations.len() as f32, max)
+    }
+}
+
+/// Relative weights of each operator applied when a gene mutates
+/// (configured through `EcosystemTuning::mutation_operators`)
+#[derive(Debug, Clone, Copy)]
+pub struct MutationOperators {
+    pub uniform_weight: f32,
+    pub gaussian_weight: f32,
+    pub duplication_weight: f32,
+    pub macro_weight: f32,
+    /// Half-width of uniform jitter
+    pub uniform_range: f32,
+    /// Standard deviation of Gaussian jitter
+    pub gaussian_sigma: f32,
+}
+
+impl Default for MutationOperators {
+    fn default() -> Self {
+        // Uniform-only, matching the original ±0.1 jitter
+        Self {
+            uniform_weight: 1.0,
+            gaussian_weight: 0.0,
+            duplication_weight: 0.0,
+            macro_weight: 0.0,
+            uniform_range: 0.1,
+            gaussian_sigma: 0.05,
+        }
     }
 }
 
+impl MutationOperators {
+    /// Choose an operator according to the configured weights
+    pub fn pick(&self, rng: &mut fastrand::Rng) -> MutationOperator {
+        let weights = [
+            (MutationOperator::Uniform, self.uniform_weight.max(0.0)),
+            (MutationOperator::Gaussian, self.gaussian_weight.max(0.0)),
+            (MutationOperator::Duplication, self.duplication_weight.max(0.0)),
+            (MutationOperator::Macro, self.macro_weight.max(0.0)),
+        ];
+        let total: f32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return MutationOperator::Uniform;
+        }
+
+        let mut roll = rng.f32() * total;
+        for (operator, weight) in weights {
+            if roll < weight {
+                return operator;
+            }
+            roll -= weight;
+        }
+        MutationOperator::Uniform
+    }
+}
+
+/// Sample a standard normal value using the Box-Muller transform
+fn standard_normal(rng: &mut fastrand::Rng) -> f32 {
+    let u1 = rng.f32().max(f32::MIN_POSITIVE);
+    let u2 = rng.f32();
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
+
 /// Trait indices in the genome
 /// Each trait is encoded by one or more genes
 pub mod traits {
@@ -127,9 +358,46 @@ pub mod traits {
         (value * 2.0) - 1.0
     }
 
-    /// Helper: sigmoid activation for smoother response curves
-    fn sigmoid(x: f32) -> f32 {
-        1.0 / (1.0 + (-x).exp())
+    /// Weighted gene sums are clamped to +/- this before the activation curve
+    const MAX_GENE_SUM: f32 = 6.0;
+
+    /// Steepness of `ExpressionCurve::Exponential`
+    const EXPONENTIAL_STEEPNESS: f32 = 4.0;
+
+    /// Activation curve from a trait's weighted gene sum to its position in its range
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum ExpressionCurve {
+        /// Smooth S-curve: responsive around the middle, saturating at both ends
+        #[default]
+        Sigmoid,
+        /// Straight line with the sigmoid's midpoint slope, hitting the bounds at sums of +/-2
+        Linear,
+        /// Flat for low sums, then rising ever faster: a threshold-like response
+        Exponential,
+    }
+
+    impl ExpressionCurve {
+        pub fn from_name(name: &str) -> Option<Self> {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "sigmoid" => Some(ExpressionCurve::Sigmoid),
+                "linear" => Some(ExpressionCurve::Linear),
+                "exponential" => Some(ExpressionCurve::Exponential),
+                _ => None,
+            }
+        }
+
+        /// Map a weighted gene sum onto [0, 1] (monotonically non-decreasing)
+        pub fn activate(self, sum: f32) -> f32 {
+            let sum = sum.clamp(-MAX_GENE_SUM, MAX_GENE_SUM);
+            match self {
+                ExpressionCurve::Sigmoid => 1.0 / (1.0 + (-sum).exp()),
+                ExpressionCurve::Linear => (0.5 + sum * 0.25).clamp(0.0, 1.0),
+                ExpressionCurve::Exponential => {
+                    let t = (sum + MAX_GENE_SUM) / (2.0 * MAX_GENE_SUM);
+                    (EXPONENTIAL_STEEPNESS * t).exp_m1() / EXPONENTIAL_STEEPNESS.exp_m1()
+                }
+            }
+        }
     }
 
     /// Maps a weighted sum of genes into the desired output range.
@@ -139,6 +407,7 @@ pub mod traits {
         bias: f32,
         min: f32,
         max: f32,
+        curve: ExpressionCurve,
     ) -> f32 {
         let mut sum = bias;
         for (index, weight) in weights {
@@ -146,8 +415,47 @@ pub mod traits {
             sum += gene_to_signed(gene_value) * *weight;
         }
 
-        let normalized = sigmoid(sum.clamp(-6.0, 6.0));
-        min + normalized * (max - min)
+        min + curve.activate(sum) * (max - min)
+    }
+
+    /// `express_with_weights` over a trait's published range and curve (see `TRAIT_RANGES`),
+    /// unless the curve was overridden with `--trait-curve`
+    fn express_in_range(genome: &Genome, weights: &[(usize, f32)], bias: f32, range: TraitRange) -> f32 {
+        let range = curve_override(range.name).map_or(range, |curve| range.with_curve(curve));
+        express_with_weights(genome, weights, bias, range.min, range.max, range.curve)
+    }
+
+    /// Curves chosen with `--trait-curve <trait>=<curve>[,<trait>=<curve>...]`, e.g.
+    /// `--trait-curve clutch_size=exponential,speed=linear`; read once, on first expression
+    static CURVE_OVERRIDES: std::sync::OnceLock<Vec<(&'static str, ExpressionCurve)>> =
+        std::sync::OnceLock::new();
+
+    fn curve_override(name: &str) -> Option<ExpressionCurve> {
+        CURVE_OVERRIDES
+            .get_or_init(|| {
+                crate::utils::cli::option_value("trait-curve")
+                    .map_or_else(Vec::new, |value| parse_curve_overrides(&value))
+            })
+            .iter()
+            .find(|(trait_name, _)| *trait_name == name)
+            .map(|(_, curve)| *curve)
+    }
+
+    /// Parse `<trait>=<curve>` pairs, skipping (with a warning) unknown traits and curves
+    fn parse_curve_overrides(value: &str) -> Vec<(&'static str, ExpressionCurve)> {
+        value
+            .split(',')
+            .filter(|pair| !pair.trim().is_empty())
+            .filter_map(|pair| {
+                let parsed = pair.split_once('=').and_then(|(name, curve)| {
+                    Some((range_of(name.trim())?.name, ExpressionCurve::from_name(curve)?))
+                });
+                if parsed.is_none() {
+                    warn!("Invalid --trait-curve '{}', expected <trait>=<sigmoid|linear|exponential>", pair);
+                }
+                parsed
+            })
+            .collect()
     }
 
     /// Base trait indices (primary drivers)
@@ -182,10 +490,112 @@ pub mod traits {
     pub const THREAT_DECAY: usize = 26;
     pub const RESOURCE_SELECTIVITY: usize = 27;
     pub const MIGRATION_DRIVE: usize = 28;
+    pub const PARENTAL_CARE: usize = 29;
+
+    /// Primary trait drivers (`SPEED` through `BOLDNESS`)
+    pub const PRIMARY_GENES: std::ops::Range<usize> = SPEED..BOLDNESS + 1;
+    /// Genes no trait expresses - they drift freely and serve as a neutral baseline
+    pub const NEUTRAL_GENES: std::ops::Range<usize> = 30..super::GENOME_SIZE;
+    /// Genes that feed trait expression
+    pub const FUNCTIONAL_GENES: std::ops::Range<usize> = 0..NEUTRAL_GENES.start;
+
+    /// Output bounds and activation curve of an expressed trait (names match the
+    /// `CachedTraits` fields)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct TraitRange {
+        pub name: &'static str,
+        pub min: f32,
+        pub max: f32,
+        pub curve: ExpressionCurve,
+    }
+
+    impl TraitRange {
+        /// A sigmoid-expressed trait
+        pub const fn new(name: &'static str, min: f32, max: f32) -> Self {
+            Self {
+                name,
+                min,
+                max,
+                curve: ExpressionCurve::Sigmoid,
+            }
+        }
+
+        /// The same range expressed through another curve, e.g.
+        /// `TraitRange::new("clutch_size", 1.0, 6.0).with_curve(ExpressionCurve::Exponential)`
+        pub const fn with_curve(self, curve: ExpressionCurve) -> Self {
+            Self { curve, ..self }
+        }
+
+        /// Map a trait value onto [0, 1] within this range
+        pub fn normalize(&self, value: f32) -> f32 {
+            if self.max > self.min {
+                ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        }
+    }
+
+    /// Trait ranges - the single source of truth for the `express_*` output bounds
+    pub const SPEED_BOUNDS: TraitRange = TraitRange::new("speed", 0.5, 20.0);
+    pub const SIZE_BOUNDS: TraitRange = TraitRange::new("size", 0.3, 3.0);
+    pub const METABOLISM_RATE_BOUNDS: TraitRange = TraitRange::new("metabolism_rate", 0.003, 0.03);
+    pub const MOVEMENT_COST_BOUNDS: TraitRange = TraitRange::new("movement_cost", 0.008, 0.12);
+    pub const MAX_ENERGY_BOUNDS: TraitRange = TraitRange::new("max_energy", 40.0, 220.0);
+    pub const REPRODUCTION_COOLDOWN_BOUNDS: TraitRange = TraitRange::new("reproduction_cooldown", 600.0, 3600.0);
+    pub const REPRODUCTION_THRESHOLD_BOUNDS: TraitRange = TraitRange::new("reproduction_threshold", 0.45, 0.95);
+    pub const SENSORY_RANGE_BOUNDS: TraitRange = TraitRange::new("sensory_range", 6.0, 65.0);
+    pub const AGGRESSION_BOUNDS: TraitRange = TraitRange::new("aggression", 0.0, 1.0);
+    pub const BOLDNESS_BOUNDS: TraitRange = TraitRange::new("boldness", 0.0, 1.0);
+    pub const MUTATION_RATE_BOUNDS: TraitRange = TraitRange::new("mutation_rate", 0.002, 0.06);
+    pub const FORAGING_DRIVE_BOUNDS: TraitRange = TraitRange::new("foraging_drive", 0.0, 1.0);
+    pub const RISK_TOLERANCE_BOUNDS: TraitRange = TraitRange::new("risk_tolerance", 0.05, 0.95);
+    pub const EXPLORATION_DRIVE_BOUNDS: TraitRange = TraitRange::new("exploration_drive", 0.0, 1.0);
+    pub const CLUTCH_SIZE_BOUNDS: TraitRange = TraitRange::new("clutch_size", 1.0, 6.0);
+    pub const OFFSPRING_ENERGY_SHARE_BOUNDS: TraitRange = TraitRange::new("offspring_energy_share", 0.05, 0.45);
+    pub const HUNGER_MEMORY_RATE_BOUNDS: TraitRange = TraitRange::new("hunger_memory_rate", 0.5, 3.0);
+    pub const THREAT_DECAY_RATE_BOUNDS: TraitRange = TraitRange::new("threat_decay_rate", 0.2, 2.5);
+    pub const SOCIAL_SENSITIVITY_BOUNDS: TraitRange = TraitRange::new("social_sensitivity", 0.0, 1.0);
+    pub const DIET_BIAS_BOUNDS: TraitRange = TraitRange::new("diet_bias", 0.0, 1.0);
+    pub const RESOURCE_SELECTIVITY_BOUNDS: TraitRange = TraitRange::new("resource_selectivity", 0.0, 1.0);
+    pub const PERCEPTION_ACUITY_BOUNDS: TraitRange = TraitRange::new("perception_acuity", 0.0, 1.0);
+    pub const PARENTAL_CARE_BOUNDS: TraitRange = TraitRange::new("parental_care", 0.0, 1.0);
+
+    /// Every expressed trait's range, in expression order
+    pub const TRAIT_RANGES: [TraitRange; 23] = [
+        SPEED_BOUNDS,
+        SIZE_BOUNDS,
+        METABOLISM_RATE_BOUNDS,
+        MOVEMENT_COST_BOUNDS,
+        MAX_ENERGY_BOUNDS,
+        REPRODUCTION_COOLDOWN_BOUNDS,
+        REPRODUCTION_THRESHOLD_BOUNDS,
+        SENSORY_RANGE_BOUNDS,
+        AGGRESSION_BOUNDS,
+        BOLDNESS_BOUNDS,
+        MUTATION_RATE_BOUNDS,
+        FORAGING_DRIVE_BOUNDS,
+        RISK_TOLERANCE_BOUNDS,
+        EXPLORATION_DRIVE_BOUNDS,
+        CLUTCH_SIZE_BOUNDS,
+        OFFSPRING_ENERGY_SHARE_BOUNDS,
+        HUNGER_MEMORY_RATE_BOUNDS,
+        THREAT_DECAY_RATE_BOUNDS,
+        SOCIAL_SENSITIVITY_BOUNDS,
+        DIET_BIAS_BOUNDS,
+        RESOURCE_SELECTIVITY_BOUNDS,
+        PERCEPTION_ACUITY_BOUNDS,
+        PARENTAL_CARE_BOUNDS,
+    ];
+
+    /// Range of a trait by name (e.g. "speed", "clutch_size")
+    pub fn range_of(name: &str) -> Option<TraitRange> {
+        TRAIT_RANGES.iter().find(|range| range.name == name).copied()
+    }
 
     /// Express speed trait (0.5 to 20.0 units/sec) using multiple genes.
     pub fn express_speed(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (SPEED, 1.4),
@@ -195,14 +605,13 @@ pub mod traits {
                 (STRUCTURAL_DENSITY, -0.6),
             ],
             0.1,
-            0.5,
-            20.0,
+            SPEED_BOUNDS,
         )
     }
 
     /// Express size trait (0.3 to 3.0 units) with structural modifiers.
     pub fn express_size(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (SIZE, 1.2),
@@ -211,14 +620,13 @@ pub mod traits {
                 (METABOLISM_RATE, -0.4),
             ],
             0.0,
-            0.3,
-            3.0,
+            SIZE_BOUNDS,
         )
     }
 
     /// Express metabolism rate trait (0.003 to 0.03 per second).
     pub fn express_metabolism_rate(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (METABOLISM_RATE, 1.1),
@@ -227,14 +635,13 @@ pub mod traits {
                 (STRUCTURAL_DENSITY, -0.3),
             ],
             0.0,
-            0.003,
-            0.03,
+            METABOLISM_RATE_BOUNDS,
         )
     }
 
     /// Express movement cost trait (0.008 to 0.12).
     pub fn express_movement_cost(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (MOVEMENT_COST, 1.0),
@@ -243,14 +650,13 @@ pub mod traits {
                 (METABOLIC_FLEXIBILITY, -0.5),
             ],
             0.2,
-            0.008,
-            0.12,
+            MOVEMENT_COST_BOUNDS,
         )
     }
 
     /// Express max energy trait (40.0 to 220.0).
     pub fn express_max_energy(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (MAX_ENERGY, 1.2),
@@ -259,14 +665,13 @@ pub mod traits {
                 (THERMAL_TOLERANCE, 0.3),
             ],
             0.0,
-            40.0,
-            220.0,
+            MAX_ENERGY_BOUNDS,
         )
     }
 
     /// Express reproduction cooldown trait (600 to 3600 ticks - tuned for stability).
     pub fn express_reproduction_cooldown(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (REPRODUCTION_COOLDOWN, 1.0),
@@ -275,14 +680,13 @@ pub mod traits {
                 (DEVELOPMENTAL_PLASTICITY, 0.5),
             ],
             0.0,
-            600.0, // Minimum 600 ticks (~10 seconds at 60 FPS)
-            3600.0, // Maximum 3600 ticks (~60 seconds at 60 FPS)
+            REPRODUCTION_COOLDOWN_BOUNDS,
         )
     }
 
     /// Express reproduction threshold trait (0.45 to 0.95 energy ratio).
     pub fn express_reproduction_threshold(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (REPRODUCTION_THRESHOLD, 1.0),
@@ -291,14 +695,13 @@ pub mod traits {
                 (METABOLIC_FLEXIBILITY, -0.5),
             ],
             0.2,
-            0.45,
-            0.95,
+            REPRODUCTION_THRESHOLD_BOUNDS,
         )
     }
 
     /// Express sensory range trait (6.0 to 65.0 units).
     pub fn express_sensory_range(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (SENSORY_RANGE, 1.0),
@@ -307,14 +710,13 @@ pub mod traits {
                 (THERMAL_TOLERANCE, -0.3),
             ],
             0.1,
-            6.0,
-            65.0,
+            SENSORY_RANGE_BOUNDS,
         )
     }
 
     /// Express aggression trait (0.0 to 1.0).
     pub fn express_aggression(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (AGGRESSION, 1.0),
@@ -323,14 +725,13 @@ pub mod traits {
                 (SOCIAL_SENSITIVITY, -0.6),
             ],
             0.0,
-            0.0,
-            1.0,
+            AGGRESSION_BOUNDS,
         )
     }
 
     /// Express boldness trait (0.0 to 1.0).
     pub fn express_boldness(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (BOLDNESS, 1.0),
@@ -339,14 +740,13 @@ pub mod traits {
                 (SOCIAL_SENSITIVITY, -0.4),
             ],
             0.0,
-            0.0,
-            1.0,
+            BOLDNESS_BOUNDS,
         )
     }
 
     /// Express mutation rate trait (0.002 to 0.06 probability per gene).
     pub fn express_mutation_rate(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (MUTATION_CONTROL, 1.2),
@@ -354,13 +754,12 @@ pub mod traits {
                 (METABOLIC_FLEXIBILITY, 0.3),
             ],
             -0.2,
-            0.002,
-            0.06,
+            MUTATION_RATE_BOUNDS,
         )
     }
 
     pub fn express_foraging_drive(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (FORAGING_BIAS, 1.1),
@@ -368,23 +767,21 @@ pub mod traits {
                 (RESOURCE_SELECTIVITY, -0.3),
             ],
             0.0,
-            0.0,
-            1.0,
+            FORAGING_DRIVE_BOUNDS,
         )
     }
 
     pub fn express_risk_tolerance(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[(RISK_TOLERANCE, 1.0), (BOLDNESS, 0.7), (AGGRESSION, 0.3)],
             0.0,
-            0.05,
-            0.95,
+            RISK_TOLERANCE_BOUNDS,
         )
     }
 
     pub fn express_exploration_drive(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (EXPLORATION_DRIVE, 1.0),
@@ -392,13 +789,12 @@ pub mod traits {
                 (MIGRATION_DRIVE, 0.5),
             ],
             -0.2,
-            0.0,
-            1.0,
+            EXPLORATION_DRIVE_BOUNDS,
         )
     }
 
     pub fn express_clutch_size(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (CLUTCH_SIZE, 1.0),
@@ -406,13 +802,12 @@ pub mod traits {
                 (SIZE, -0.2),
             ],
             0.3,
-            1.0,
-            6.0,
+            CLUTCH_SIZE_BOUNDS,
         )
     }
 
     pub fn express_offspring_energy_share(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (OFFSPRING_ENERGY_SHARE, 1.0),
@@ -420,13 +815,12 @@ pub mod traits {
                 (METABOLISM_RATE, -0.4),
             ],
             0.0,
-            0.05,
-            0.45,
+            OFFSPRING_ENERGY_SHARE_BOUNDS,
         )
     }
 
     pub fn express_hunger_memory_rate(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (HUNGER_MEMORY, 1.0),
@@ -434,13 +828,12 @@ pub mod traits {
                 (METABOLIC_FLEXIBILITY, 0.3),
             ],
             0.0,
-            0.5,
-            3.0,
+            HUNGER_MEMORY_RATE_BOUNDS,
         )
     }
 
     pub fn express_threat_decay_rate(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (THREAT_DECAY, 1.0),
@@ -448,13 +841,40 @@ pub mod traits {
                 (SOCIAL_SENSITIVITY, -0.3),
             ],
             0.2,
-            0.2,
-            2.5,
+            THREAT_DECAY_RATE_BOUNDS,
+        )
+    }
+
+    pub fn express_social_sensitivity(genome: &Genome) -> f32 {
+        express_in_range(
+            genome,
+            &[
+                (SOCIAL_SENSITIVITY, 1.0),
+                (SENSORY_FOCUS, 0.3),
+                (AGGRESSION, -0.4),
+            ],
+            0.0,
+            SOCIAL_SENSITIVITY_BOUNDS,
+        )
+    }
+
+    /// Express diet bias within the organism type (0 = herbivore, 1 = carnivore).
+    /// Only Consumers act on it, shifting preference between Plant and Prey.
+    pub fn express_diet_bias(genome: &Genome) -> f32 {
+        express_in_range(
+            genome,
+            &[
+                (AGGRESSION, 0.9),
+                (SIZE, 0.4),
+                (FORAGING_BIAS, -0.4),
+            ],
+            -0.45,
+            DIET_BIAS_BOUNDS,
         )
     }
 
     pub fn express_resource_selectivity(genome: &Genome) -> f32 {
-        express_with_weights(
+        express_in_range(
             genome,
             &[
                 (RESOURCE_SELECTIVITY, 1.0),
@@ -462,11 +882,133 @@ pub mod traits {
                 (SENSORY_FOCUS, 0.4),
             ],
             0.0,
+            RESOURCE_SELECTIVITY_BOUNDS,
+        )
+    }
+
+    /// Express perception acuity (0-1): how faint a resource the organism can notice.
+    /// Focused senses sharpen it; wide sensory ranges trade some of it away.
+    pub fn express_perception_acuity(genome: &Genome) -> f32 {
+        express_in_range(
+            genome,
+            &[(SENSORY_FOCUS, 1.0), (SENSORY_RANGE, -0.5)],
             0.0,
-            1.0,
+            PERCEPTION_ACUITY_BOUNDS,
+        )
+    }
+
+    /// Express parental care (0-1): how long and how generously a parent tends its brood.
+    /// Invests in quality over quantity, so it pulls against large clutches.
+    pub fn express_parental_care(genome: &Genome) -> f32 {
+        express_in_range(
+            genome,
+            &[
+                (PARENTAL_CARE, 1.0),
+                (REPRODUCTIVE_INVESTMENT, 0.4),
+                (CLUTCH_SIZE, -0.5),
+            ],
+            -0.3,
+            PARENTAL_CARE_BOUNDS,
         )
     }
 }
 
 /// Default mutation rate (probability of mutation per gene)
 pub const DEFAULT_MUTATION_RATE: f32 = 0.01; // Backwards-compatible baseline
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use traits::ExpressionCurve;
+
+    const CURVES: [ExpressionCurve; 3] = [
+        ExpressionCurve::Sigmoid,
+        ExpressionCurve::Linear,
+        ExpressionCurve::Exponential,
+    ];
+
+    #[test]
+    fn expression_curves_are_monotonic_within_unit_range() {
+        for curve in CURVES {
+            let mut previous = curve.activate(-10.0);
+            assert!((0.0..=1.0).contains(&previous), "{curve:?} below range");
+            for step in -99..=100 {
+                let value = curve.activate(step as f32 * 0.1);
+                assert!(value >= previous, "{curve:?} decreased at sum {}", step as f32 * 0.1);
+                assert!((0.0..=1.0).contains(&value), "{curve:?} left [0, 1] at {value}");
+                previous = value;
+            }
+        }
+    }
+
+    #[test]
+    fn linear_curve_is_a_clamped_line() {
+        let linear = ExpressionCurve::Linear;
+        assert_eq!(linear.activate(0.0), 0.5);
+        assert_eq!(linear.activate(1.0), 0.75);
+        assert_eq!(linear.activate(-2.0), 0.0);
+        assert_eq!(linear.activate(2.0), 1.0);
+        assert_eq!(linear.activate(5.0), 1.0);
+    }
+
+    #[test]
+    fn exponential_curve_is_flat_then_steep() {
+        let exponential = ExpressionCurve::Exponential;
+        assert!(exponential.activate(-6.0).abs() < 1e-6);
+        assert!((exponential.activate(6.0) - 1.0).abs() < 1e-6);
+        // Threshold-like: below the linear midpoint, and gaining more per step at the top
+        assert!(exponential.activate(0.0) < 0.5);
+        let low_gain = exponential.activate(-4.0) - exponential.activate(-5.0);
+        let high_gain = exponential.activate(5.0) - exponential.activate(4.0);
+        assert!(high_gain > low_gain * 4.0);
+    }
+
+    #[test]
+    fn curve_names_parse() {
+        assert_eq!(ExpressionCurve::from_name("Linear"), Some(ExpressionCurve::Linear));
+        assert_eq!(ExpressionCurve::from_name(" exponential "), Some(ExpressionCurve::Exponential));
+        assert_eq!(ExpressionCurve::from_name("sigmoid"), Some(ExpressionCurve::Sigmoid));
+        assert_eq!(ExpressionCurve::from_name("cubic"), None);
+    }
+
+    #[test]
+    fn duplication_delta_sums_the_shifted_genes() {
+        let genome = Genome::new((0..GENOME_SIZE).map(|i| i as f32 * 0.01).collect());
+        let operators = MutationOperators {
+            uniform_weight: 0.0,
+            duplication_weight: 1.0,
+            ..Default::default()
+        };
+        let mut rng = fastrand::Rng::with_seed(7);
+        let (_, spectrum) = genome.clone_with_operators(1.0, &operators, &mut rng);
+
+        // Gene 0 is copied into slot 1 and every later gene moves right by one slot
+        let first = spectrum.mutations[0];
+        assert_eq!(first.gene, 0);
+        assert_eq!(first.operator, MutationOperator::Duplication);
+        let expected = (GENOME_SIZE - 1) as f32 * 0.01;
+        assert!((first.delta - expected).abs() < 1e-4, "{} != {}", first.delta, expected);
+    }
+
+    #[test]
+    fn weighted_metric_splits_primary_and_modifier_genes_at_the_boundary() {
+        let metric = GeneticDistanceMetric::Weighted;
+        assert_eq!(metric.gene_weight(traits::SPEED), 1.0);
+        assert_eq!(metric.gene_weight(traits::BOLDNESS), 1.0);
+        assert_eq!(metric.gene_weight(traits::SPEED_FAST_TWITCH), 0.5);
+        assert_eq!(metric.gene_weight(traits::PARENTAL_CARE), 0.5);
+        assert_eq!(metric.gene_weight(traits::NEUTRAL_GENES.start), 0.0);
+    }
+
+    #[test]
+    fn weighted_distance_ignores_neutral_genes() {
+        let base = Genome::new(vec![0.5; GENOME_SIZE]);
+        let mut drifted = base.clone();
+        for gene in traits::NEUTRAL_GENES {
+            drifted.set_gene(gene, 1.0);
+        }
+        assert_eq!(base.distance_with(&drifted, GeneticDistanceMetric::Weighted), 0.0);
+        assert!(base.distance_with(&drifted, GeneticDistanceMetric::Rms) > 0.0);
+    }
+}
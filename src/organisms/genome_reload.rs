@@ -0,0 +1,197 @@
+use crate::organisms::components::{CachedTraits, TraitsDirty};
+use crate::organisms::genetics::{Genome, GENOME_SIZE};
+use crate::organisms::systems::TrackedOrganisms;
+use crate::utils::{SimError, SimTime};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Ticks between checks of the genome file's modification time
+const GENOME_POLL_INTERVAL: u64 = 30;
+
+/// `--god-genome <path>`: live-edit the genome of a designated ("god") organism
+/// The god organism is the longest-tracked one. When it's first designated and the file
+/// doesn't exist yet, its current genome is written there as a starting point; after that,
+/// every save of the file replaces its `Genome` and marks its traits for recomputation.
+#[derive(Resource, Default)]
+pub struct GenomeHotReload {
+    /// Genome file to watch (None = disabled)
+    pub path: Option<PathBuf>,
+    /// Organism the file was last applied to or exported from
+    god: Option<Entity>,
+    /// Modification time of the last version seen
+    last_modified: Option<SystemTime>,
+    /// File applied to the god organism whose traits haven't been refreshed and logged yet
+    pending_report: Option<PathBuf>,
+}
+
+/// On-disk genome, e.g. `(genes: [0.5, 0.25, ...])`
+#[derive(Serialize, Deserialize)]
+struct GenomeFile {
+    genes: Vec<f32>,
+}
+
+impl GenomeFile {
+    fn load(path: &std::path::Path) -> Result<Genome, SimError> {
+        let contents = std::fs::read_to_string(path).map_err(|err| SimError::io(path, err))?;
+        let file: GenomeFile =
+            ron::from_str(&contents).map_err(|err| SimError::serialization(path, err))?;
+        if file.genes.is_empty() || file.genes.len() > GENOME_SIZE {
+            return Err(SimError::Validation(format!(
+                "expected 1-{GENOME_SIZE} genes, found {}",
+                file.genes.len()
+            )));
+        }
+        if let Some(index) = file.genes.iter().position(|gene| !gene.is_finite()) {
+            return Err(SimError::Validation(format!("gene {index} is not a finite number")));
+        }
+        // Values are clamped to [0, 1] and missing genes default to 0.5
+        Ok(Genome::new(file.genes))
+    }
+}
+
+impl GenomeHotReload {
+    pub fn from_args() -> Self {
+        Self {
+            path: crate::utils::cli::option_value("god-genome").map(PathBuf::from),
+            ..Self::default()
+        }
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        let path = self.path.as_ref()?;
+        std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    /// Write `genome` to the file so there's something to edit
+    fn export(&mut self, genome: &Genome) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let file = GenomeFile {
+            genes: genome.genes.to_vec(),
+        };
+        let written = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+            .and_then(|contents| std::fs::write(path, contents).map_err(|err| err.to_string()));
+        match written {
+            Ok(()) => info!("[GOD] Wrote the god organism's genome to {}", path.display()),
+            Err(err) => error!("Failed to write genome file {}: {err}", path.display()),
+        }
+        self.last_modified = self.modified();
+    }
+}
+
+/// Apply edits of the genome file to the god organism
+/// Malformed files are reported and ignored; the organism keeps its current genome.
+pub fn reload_god_genome(
+    mut commands: Commands,
+    mut reload: ResMut<GenomeHotReload>,
+    tracked: Res<TrackedOrganisms>,
+    mut query: Query<(&mut Genome, &CachedTraits, Option<&TraitsDirty>)>,
+    sim_time: Res<SimTime>,
+) {
+    // Log a reload once `refresh_dirty_traits` has recomputed the god organism's traits
+    if let Some(god) = reload.god {
+        if let Ok((_, traits, None)) = query.get(god) {
+            if let Some(path) = reload.pending_report.take() {
+                info!(
+                    "[GOD] Reloaded genome from {} | Size: {:.2} | Speed: {:.2} | Aggression: {:.2}",
+                    path.display(),
+                    traits.size,
+                    traits.speed,
+                    traits.aggression
+                );
+            }
+        }
+    }
+
+    if reload.path.is_none() || !sim_time.every(GENOME_POLL_INTERVAL) {
+        return;
+    }
+    let Some(god) = tracked.entities().find(|entity| query.contains(*entity)) else {
+        return;
+    };
+    let Ok((mut genome, _, _)) = query.get_mut(god) else {
+        return;
+    };
+
+    let modified = reload.modified();
+    if reload.god != Some(god) {
+        reload.god = Some(god);
+        reload.pending_report = None;
+        info!("[GOD] Organism {:?} is the god organism", god);
+        if modified.is_none() {
+            reload.export(&genome);
+            return;
+        }
+    } else if modified.is_none() || modified == reload.last_modified {
+        return;
+    }
+    reload.last_modified = modified;
+
+    let Some(path) = reload.path.clone() else {
+        return;
+    };
+    match GenomeFile::load(&path) {
+        Ok(new_genome) => {
+            *genome = new_genome;
+            commands.entity(god).insert(TraitsDirty);
+            reload.pending_report = Some(path);
+        }
+        Err(err) => error!(
+            "[GOD] Ignoring malformed genome file {}: {err}",
+            path.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::systems::refresh_dirty_traits;
+
+    #[test]
+    fn reloaded_genome_refreshes_cached_traits() {
+        let path = std::env::temp_dir().join(format!("god_genome_test_{}.ron", std::process::id()));
+        std::fs::write(&path, "(genes: [0.9, 0.1, 0.9, 0.1, 0.9, 0.1])").unwrap();
+        let loaded = GenomeFile::load(&path);
+        std::fs::remove_file(&path).ok();
+        let loaded = loaded.unwrap();
+
+        let original = Genome::new(vec![0.5; GENOME_SIZE]);
+        let mut world = World::new();
+        let god = world
+            .spawn((loaded.clone(), CachedTraits::from_genome(&original), TraitsDirty))
+            .id();
+        // Same stale traits, but not marked: left alone
+        let bystander = world
+            .spawn((loaded.clone(), CachedTraits::from_genome(&original)))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(refresh_dirty_traits);
+        schedule.run(&mut world);
+
+        let expected = CachedTraits::from_genome(&loaded);
+        let refreshed = world.get::<CachedTraits>(god).unwrap();
+        assert_eq!(refreshed.size, expected.size);
+        assert_eq!(refreshed.speed, expected.speed);
+        assert_eq!(refreshed.aggression, expected.aggression);
+        assert!(world.get::<TraitsDirty>(god).is_none());
+
+        let stale = CachedTraits::from_genome(&original);
+        assert_ne!(stale.speed, expected.speed);
+        assert_eq!(world.get::<CachedTraits>(bystander).unwrap().speed, stale.speed);
+    }
+
+    #[test]
+    fn malformed_genome_files_are_rejected() {
+        let path = std::env::temp_dir().join(format!("bad_genome_test_{}.ron", std::process::id()));
+        std::fs::write(&path, "(genes: [])").unwrap();
+        let loaded = GenomeFile::load(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(loaded.is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::organisms::components::{Alive, OrganismUid, Position, SpeciesId};
+use crate::organisms::EcosystemTuning;
+use crate::utils::{SimTime, SpatialHashGrid};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Herd an organism currently belongs to (absent while it's alone or in a small group)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HerdId(pub u32);
+
+/// One herd as of the latest clustering pass
+#[derive(Debug, Clone, Copy)]
+pub struct HerdSummary {
+    pub species: u32,
+    pub size: usize,
+    pub centroid: Vec2,
+}
+
+/// Herd detection (enabled by `LoggingConfig::herds_enabled`, i.e. `--herds`)
+/// Every `LoggingConfig::herd_interval` ticks, same-species organisms within `herd_link_distance` of each
+/// other are chained into clusters; clusters of at least `herd_min_size` are herds. Sizes and
+/// centroids are exported as CSV.
+///
+/// Ids carry over between passes: each previous herd hands its id to the new cluster holding
+/// most of its members, and a cluster offered several ids (a merge) keeps the one that brought
+/// the most members. When a herd splits, the largest part keeps the id and the rest get new ones.
+#[derive(Resource)]
+pub struct Herds {
+    /// Herds found by the latest pass, by id
+    pub current: HashMap<u32, HerdSummary>,
+    next_id: u32,
+    csv_writer: Option<BufWriter<File>>,
+    csv_path: PathBuf,
+    /// Set after an I/O error; the export stops rather than retrying every pass
+    failed: bool,
+}
+
+impl Default for Herds {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            current: HashMap::new(),
+            next_id: 1,
+            csv_writer: None,
+            csv_path: logs_dir.join(format!("herds_{}.csv", timestamp)),
+            failed: false,
+        }
+    }
+}
+
+impl Herds {
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            if self.failed {
+                return None;
+            }
+            let file = match File::create(&self.csv_path) {
+                Ok(file) => file,
+                Err(err) => {
+                    self.disable(&err);
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+            let header = writeln!(writer, "tick,herd_id,species_id,size,centroid_x,centroid_y");
+            if let Err(err) = header {
+                self.disable(&err);
+                return None;
+            }
+
+            info!("[HERDS] Writing herds to {}", self.csv_path.display());
+            self.csv_writer = Some(writer);
+        }
+        self.csv_writer.as_mut()
+    }
+
+    fn disable(&mut self, err: &std::io::Error) {
+        error!(
+            "[HERDS] Disabling herd export to {} after an I/O error: {err}",
+            self.csv_path.display()
+        );
+        self.csv_writer = None;
+        self.failed = true;
+    }
+
+    fn export(&mut self, tick: u64) {
+        let mut ids: Vec<u32> = self.current.keys().copied().collect();
+        ids.sort_unstable();
+        let rows: String = ids
+            .iter()
+            .map(|id| {
+                let herd = self.current[id];
+                format!(
+                    "{tick},{id},{},{},{:.3},{:.3}\n",
+                    herd.species, herd.size, herd.centroid.x, herd.centroid.y
+                )
+            })
+            .collect();
+        if let Some(writer) = self.ensure_writer() {
+            if let Err(err) = writer.write_all(rows.as_bytes()).and_then(|()| writer.flush()) {
+                self.disable(&err);
+            }
+        }
+    }
+}
+
+/// Find the root of `index` in a union-find forest, halving paths on the way
+fn find(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+    index
+}
+
+/// Cluster organisms into herds and (re)assign their `HerdId`s
+pub fn update_herds(
+    mut commands: Commands,
+    mut herds: ResMut<Herds>,
+    query: Query<(Entity, &Position, &SpeciesId, &OrganismUid, Option<&HerdId>), With<Alive>>,
+    spatial_hash: Res<SpatialHashGrid>,
+    tuning: Res<EcosystemTuning>,
+    sim_time: Res<SimTime>,
+    logging: Res<crate::organisms::LoggingConfig>,
+) {
+    if !logging.herds_enabled || !sim_time.every(logging.herd_interval) {
+        return;
+    }
+
+    // Uid order keeps cluster numbering (and so id hand-over ties) the same between runs
+    let mut members: Vec<_> = query.iter().collect();
+    members.sort_by_key(|(_, _, _, uid, _)| uid.0);
+    let index_of: HashMap<Entity, usize> = members
+        .iter()
+        .enumerate()
+        .map(|(index, (entity, ..))| (*entity, index))
+        .collect();
+
+    // Chain same-species neighbors within the link distance
+    let link_distance = tuning.herd_link_distance.max(0.0);
+    let mut parents: Vec<usize> = (0..members.len()).collect();
+    for (index, (_, position, species, _, _)) in members.iter().enumerate() {
+        for neighbor in spatial_hash.organisms.query_radius(position.0, link_distance) {
+            let Some(&other) = index_of.get(&neighbor) else {
+                continue;
+            };
+            let (_, other_position, other_species, _, _) = members[other];
+            if other == index
+                || other_species != *species
+                || other_position.0.distance_squared(position.0) > link_distance * link_distance
+            {
+                continue;
+            }
+            let (root, other_root) = (find(&mut parents, index), find(&mut parents, other));
+            if root != other_root {
+                parents[root.max(other_root)] = root.min(other_root);
+            }
+        }
+    }
+
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+    for index in 0..members.len() {
+        let root = find(&mut parents, index);
+        clusters.entry(root).or_default().push(index);
+    }
+    let min_size = tuning.herd_min_size.max(2);
+    let mut clusters: Vec<Vec<usize>> = clusters
+        .into_values()
+        .filter(|cluster| cluster.len() >= min_size)
+        .collect();
+    clusters.sort_by_key(|cluster| cluster[0]);
+
+    // Each previous herd offers its id to the cluster holding most of its members
+    let mut overlaps: HashMap<u32, HashMap<usize, usize>> = HashMap::new();
+    for (cluster_index, cluster) in clusters.iter().enumerate() {
+        for &member in cluster {
+            if let Some(herd) = members[member].4 {
+                *overlaps.entry(herd.0).or_default().entry(cluster_index).or_default() += 1;
+            }
+        }
+    }
+    // Per cluster: the best (members brought, id) offered so far
+    let mut offers: Vec<Option<(usize, u32)>> = vec![None; clusters.len()];
+    for (id, counts) in overlaps {
+        let Some((&cluster_index, &count)) = counts
+            .iter()
+            .max_by_key(|(cluster_index, count)| (**count, std::cmp::Reverse(**cluster_index)))
+        else {
+            continue;
+        };
+        // Most members wins; the older (lower) id breaks ties
+        let offer = &mut offers[cluster_index];
+        let better = offer.map_or(true, |(best, best_id)| {
+            count > best || (count == best && id < best_id)
+        });
+        if better {
+            *offer = Some((count, id));
+        }
+    }
+
+    let mut current = HashMap::with_capacity(clusters.len());
+    let mut in_herd = vec![false; members.len()];
+    for (cluster, offer) in clusters.iter().zip(offers) {
+        let id = match offer {
+            Some((_, id)) => id,
+            None => {
+                herds.next_id += 1;
+                herds.next_id - 1
+            }
+        };
+        let centroid = cluster.iter().map(|&member| members[member].1 .0).sum::<Vec2>()
+            / cluster.len() as f32;
+        current.insert(
+            id,
+            HerdSummary {
+                species: members[cluster[0]].2.value(),
+                size: cluster.len(),
+                centroid,
+            },
+        );
+        for &member in cluster {
+            in_herd[member] = true;
+            let (entity, _, _, _, previous) = members[member];
+            if previous != Some(&HerdId(id)) {
+                commands.entity(entity).insert(HerdId(id));
+            }
+        }
+    }
+    for (member, (entity, _, _, _, previous)) in members.iter().enumerate() {
+        if !in_herd[member] && previous.is_some() {
+            commands.entity(*entity).remove::<HerdId>();
+        }
+    }
+
+    let herded: usize = current.values().map(|herd| herd.size).sum();
+    let largest = current.values().map(|herd| herd.size).max().unwrap_or(0);
+    info!(
+        "[HERDS] Tick {} | {} herds | {} of {} organisms herded | Largest {}",
+        sim_time.tick,
+        current.len(),
+        herded,
+        members.len(),
+        largest
+    );
+    herds.current = current;
+    herds.export(sim_time.tick);
+}
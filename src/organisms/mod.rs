@@ -1,66 +1,183 @@
 mod behavior;
+mod bottleneck;
+mod carcass;
+mod care;
+mod clutch;
 mod components;
+mod crowding;
+mod decision_trace;
+mod dendrogram;
+#[cfg(feature = "energy-audit")]
+mod energy_audit;
 mod genetics;
+mod genome_reload;
+mod herd;
+mod mutation_log;
 mod speciation;
 mod systems;
 mod tuning;
 mod ecosystem_stats;
 mod disease;
 mod coevolution;
+mod drift;
+mod extinction;
+mod predation;
+mod stats_diff;
+mod stress;
+mod territory;
+mod trophic;
 
 pub use behavior::*;
+pub use bottleneck::*;
+pub use carcass::*;
+pub use care::*;
+pub use clutch::*;
 use bevy::prelude::*;
 pub use components::*;
+pub use crowding::*;
+pub use decision_trace::*;
+pub use dendrogram::*;
 pub use genetics::*;
+pub use genome_reload::*;
+pub use herd::*;
+pub use mutation_log::*;
 pub use speciation::*;
 pub use tuning::*;
 pub use ecosystem_stats::*;
 pub use disease::*;
 pub use coevolution::*;
+pub use extinction::*;
+pub use predation::*;
+pub use stats_diff::*;
+pub use stress::*;
+pub use territory::*;
+pub use trophic::*;
 
 // Re-export specific types for visualization
 pub use disease::Infected;
+pub use systems::{DeterministicOrder, LoggingConfig, TrackedOrganisms};
+pub(crate) use systems::with_logs_directory;
 
 pub struct OrganismPlugin;
 
 impl Plugin for OrganismPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<systems::TrackedOrganism>()
+        // main may already have set logging, tracking, mutation sampling, the stats window, the
+        // god genome file, a scheduled bottleneck and the hash cell size from the command line
+        app.init_resource::<systems::LoggingConfig>()
+            .init_resource::<systems::TrackedOrganisms>()
+            .init_resource::<systems::DeterministicOrder>()
             .init_resource::<systems::AllOrganismsLogger>()
+            .init_resource::<decision_trace::DecisionTraceLog>()
+            .init_resource::<mutation_log::MutationLog>()
             .init_resource::<systems::SpatialHashTracker>()
+            .init_resource::<systems::MatingHandshakes>()
+            .init_resource::<components::OrganismUids>()
             .init_resource::<crate::utils::SpatialHashGrid>()
             .init_resource::<behavior::SensoryDataCache>() // Add sensory cache (optimization 3)
+            .init_resource::<behavior::BehaviorLod>()
             .init_resource::<speciation::SpeciesTracker>() // Step 8: Speciation system
             .init_resource::<tuning::EcosystemTuning>() // Step 8: Tuning parameters
             .init_resource::<ecosystem_stats::EcosystemStats>() // Step 8: Ecosystem statistics
-            .init_resource::<disease::DiseaseSystem>() // Step 9: Disease system
+            .init_resource::<ecosystem_stats::StatsExport>()
             .init_resource::<coevolution::CoEvolutionSystem>() // Step 9: Co-evolution system
+            .init_resource::<drift::DriftAnalysis>()
+            .init_resource::<extinction::Extinction>()
+            .init_resource::<predation::Predation>()
+            .init_resource::<carcass::Carcasses>()
+            .init_resource::<trophic::TrophicFlows>()
+            .init_resource::<dendrogram::SpeciesDendrogram>()
+            .init_resource::<genome_reload::GenomeHotReload>()
+            .init_resource::<bottleneck::Bottleneck>()
+            .init_resource::<territory::ScentMarks>()
+            .init_resource::<herd::Herds>()
+            .init_resource::<care::ParentalCare>()
             .add_systems(Startup, systems::spawn_initial_organisms)
             .add_systems(
                 Update,
                 (
+                    systems::refresh_dirty_traits,
                     systems::update_spatial_hash,
+                    crowding::update_crowding,
+                    territory::update_scent_marks,
+                    stress::update_stress,
                     systems::update_metabolism,
                     systems::update_behavior,
                     systems::update_movement,
                     systems::handle_eating,
+                    predation::handle_predation,
+                    predation::heal_wounds,
                     systems::update_age,
-                    systems::handle_reproduction,
+                    // Grouped to stay within Bevy's system tuple limit
+                    (systems::handle_reproduction, care::update_parental_care).chain(),
                     systems::handle_death,
+                    bottleneck::apply_bottleneck,
+                    carcass::age_carcasses,
                     update_speciation, // Step 8: Update species assignments
                     disease::update_disease_system, // Step 9: Update diseases (spawn and spread)
                     disease::update_infected_organisms_system, // Step 9: Update infected organisms (damage)
                     coevolution::update_coevolution_system, // Step 9: Update co-evolution
                 )
-                    .chain(),
+                    .chain()
+                    .run_if(extinction::ecosystem_active),
             )
             .add_systems(
                 Update,
                 (
                     ecosystem_stats::collect_ecosystem_stats, // Step 8: Ecosystem statistics
+                    ecosystem_stats::export_stats_json,
+                    herd::update_herds,
+                    drift::update_drift_analysis,
+                    trophic::export_trophic_flows,
+                    dendrogram::export_species_dendrogram,
                     systems::log_all_organisms,
                     systems::log_tracked_organism,
-                ).chain(),
+                ).chain()
+                    // Export what this tick simulated
+                    .after(coevolution::update_coevolution_system)
+                    .run_if(extinction::ecosystem_active),
+            )
+            .add_systems(Update, extinction::handle_extinction)
+            .add_systems(
+                Update,
+                genome_reload::reload_god_genome.after(systems::refresh_dirty_traits),
+            )
+            .add_systems(Last, systems::flush_logs_on_exit);
+
+        // Step 9: Disease system, drawing from the organism seed (SimInstance may have set it)
+        let organism_seed = app.world.resource::<tuning::EcosystemTuning>().organism_seed;
+        app.insert_resource(disease::DiseaseSystem::seeded(organism_seed));
+
+        // Accounting invariants, checked around the systems that move energy every tick
+        // World updates finish before the first snapshot and exports start after the last
+        // check, so nothing else changes the audited totals between a snapshot and its audit.
+        #[cfg(feature = "energy-audit")]
+        app.init_resource::<energy_audit::EnergyAudit>()
+            .configure_sets(
+                Update,
+                crate::world::WorldUpdateSet.before(energy_audit::FirstSnapshotSet),
+            )
+            .add_systems(
+                Update,
+                (
+                    energy_audit::snapshot_energy
+                        .in_set(energy_audit::FirstSnapshotSet)
+                        .after(stress::update_stress)
+                        .before(systems::update_metabolism),
+                    energy_audit::audit_metabolism
+                        .after(systems::update_metabolism)
+                        .before(systems::update_behavior),
+                    energy_audit::snapshot_energy
+                        .after(systems::update_movement)
+                        .before(systems::handle_eating),
+                    energy_audit::audit_eating
+                        .after(systems::handle_eating)
+                        .before(predation::handle_predation),
+                    energy_audit::audit_bounds
+                        .after(coevolution::update_coevolution_system)
+                        .before(ecosystem_stats::collect_ecosystem_stats),
+                )
+                    .run_if(extinction::ecosystem_active),
             );
     }
 }
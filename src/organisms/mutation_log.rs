@@ -0,0 +1,153 @@
+use crate::organisms::genetics::MutationSpectrum;
+use crate::organisms::LoggingConfig;
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Logged rows between flushes of the mutation CSV
+const FLUSH_INTERVAL: u64 = 100;
+
+/// Who produced one offspring genome and at what rate its genes were mutated
+#[derive(Debug, Clone, Copy)]
+pub struct MutationContext {
+    pub parent_uid: u64,
+    /// Absent for asexual offspring
+    pub mate_uid: Option<u64>,
+    /// The parent's `mutation_rate` trait
+    pub parent_mutation_rate: f32,
+    /// Rate actually applied (stress-raised, averaged over both partners for sexual offspring)
+    pub applied_mutation_rate: f32,
+}
+
+/// `--mutation-log <n>`: record the mutations behind every n-th offspring, and behind every
+/// offspring of a tracked organism, to `mutations_<ts>.csv`
+/// (enabled and sampled by `LoggingConfig::mutations_enabled` / `mutation_sample_interval`)
+/// Each row has the number of mutated genes, their mean and largest change, and every
+/// mutation as `gene:operator:delta`, next to the mutation rate that produced them. Sampling
+/// keeps the file small; with the deterministic order the sample is the same between runs.
+#[derive(Resource)]
+pub struct MutationLog {
+    offspring_seen: u64,
+    rows_written: u64,
+    csv_writer: Option<BufWriter<File>>,
+    csv_path: PathBuf,
+    /// Set after an I/O error; logging stops rather than retrying every offspring
+    failed: bool,
+}
+
+impl Default for MutationLog {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            offspring_seen: 0,
+            rows_written: 0,
+            csv_writer: None,
+            csv_path: logs_dir.join(format!("mutations_{}.csv", timestamp)),
+            failed: false,
+        }
+    }
+}
+
+impl MutationLog {
+    /// Whether the next offspring should be logged (counts it towards the sample either way)
+    pub fn sample(&mut self, config: &LoggingConfig, tracked_parent: bool) -> bool {
+        if !config.mutations_enabled || self.failed {
+            return false;
+        }
+        self.offspring_seen += 1;
+        tracked_parent || self.offspring_seen % config.mutation_sample_interval.max(1) == 0
+    }
+
+    /// Append one offspring's mutations
+    pub fn record(
+        &mut self,
+        tick: u64,
+        offspring_uid: u64,
+        context: &MutationContext,
+        spectrum: &MutationSpectrum,
+    ) {
+        let (mean, max) = spectrum.magnitudes();
+        let mutations = spectrum
+            .mutations
+            .iter()
+            .map(|mutation| {
+                format!("{}:{}:{:.6}", mutation.gene, mutation.operator.label(), mutation.delta)
+            })
+            .collect::<Vec<_>>()
+            .join(";");
+        let mate_uid = context.mate_uid.map_or(String::new(), |uid| uid.to_string());
+        let row = format!(
+            "{tick},{offspring_uid},{},{mate_uid},{:.6},{:.6},{},{:.6},{:.6},{mutations}\n",
+            context.parent_uid,
+            context.parent_mutation_rate,
+            context.applied_mutation_rate,
+            spectrum.mutations.len(),
+            mean,
+            max,
+        );
+
+        self.rows_written += 1;
+        let flush = self.rows_written % FLUSH_INTERVAL == 0;
+        if let Some(writer) = self.ensure_writer() {
+            let result = writer
+                .write_all(row.as_bytes())
+                .and_then(|()| if flush { writer.flush() } else { Ok(()) });
+            if let Err(err) = result {
+                self.disable(&err);
+            }
+        }
+    }
+
+    pub fn close(&mut self) {
+        crate::organisms::systems::close_csv_writer(&mut self.csv_writer, &self.csv_path);
+    }
+
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            if self.failed {
+                return None;
+            }
+            let file = match File::create(&self.csv_path) {
+                Ok(file) => file,
+                Err(err) => {
+                    self.disable(&err);
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+            let header = writeln!(
+                writer,
+                "tick,offspring_uid,parent_uid,mate_uid,parent_mutation_rate,applied_mutation_rate,genes_mutated,mean_magnitude,max_magnitude,mutations"
+            );
+            if let Err(err) = header {
+                self.disable(&err);
+                return None;
+            }
+
+            info!("[MUTATIONS] Writing mutations to {}", self.csv_path.display());
+            self.csv_writer = Some(writer);
+        }
+        self.csv_writer.as_mut()
+    }
+
+    fn disable(&mut self, err: &std::io::Error) {
+        error!(
+            "[MUTATIONS] Disabling mutation logging to {} after an I/O error: {err}",
+            self.csv_path.display()
+        );
+        self.csv_writer = None;
+        self.failed = true;
+    }
+}
+
+impl Drop for MutationLog {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
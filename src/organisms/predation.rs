@@ -0,0 +1,321 @@
+use crate::organisms::behavior::{Behavior, BehaviorState};
+use crate::organisms::components::*;
+use crate::organisms::{DeterministicOrder, EcosystemTuning, TrophicFlows};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Energy fraction at or above which wounds heal
+const HEALING_ENERGY_FRACTION: f32 = 0.5;
+
+/// Predation bookkeeping: strike cooldowns and capture outcomes
+/// Only active when `EcosystemTuning::predation_enabled` is set.
+#[derive(Resource, Default)]
+pub struct Predation {
+    /// Seconds until each predator can strike again (after a miss or a kill)
+    strike_cooldowns: HashMap<Entity, f32>,
+    /// Successful captures so far
+    pub captures: u64,
+    /// Failed capture attempts (prey escaped) so far
+    pub escapes: u64,
+    /// Strikes that hit but left the prey alive (it got away wounded)
+    pub wounds: u64,
+    /// Strikes where the prey fought back and injured the predator
+    pub injuries: u64,
+    /// Predator energy lost to missed strikes so far
+    pub strike_energy_lost: f64,
+}
+
+/// Chance that a single strike captures its prey
+/// Faster and bolder prey escape more often; larger, faster, aggressive predators
+/// (ambushers) capture more often. `base` is the chance for evenly matched organisms.
+pub fn capture_probability(base: f32, predator: &CachedTraits, prey: &CachedTraits) -> f32 {
+    // 0.5 when speeds match, approaching 1.0 when the predator is much faster
+    let speed_edge = predator.speed / (predator.speed + prey.speed).max(f32::EPSILON);
+    let speed_factor = speed_edge * 2.0;
+    let size_factor = (predator.size / prey.size.max(f32::EPSILON)).clamp(0.5, 2.0).sqrt();
+    let ambush_factor = 0.75 + predator.aggression * 0.5;
+    // Bold prey fight back or hold their ground
+    let defense_factor = 1.0 - prey.boldness * 0.4;
+
+    (base * speed_factor * size_factor * ambush_factor * defense_factor).clamp(0.0, 0.95)
+}
+
+/// How hard a predator commits to a strike: 0.5 for the meekest, 1.5 for the most aggressive
+/// Scales the damage dealt, the cost of a miss and the injuries taken. Aggression also raises
+/// the capture chance (the ambush factor in `capture_probability`), but by less than it raises
+/// these costs, so the most aggressive predators pay more energy and health per capture.
+fn strike_commitment(predator: &CachedTraits) -> f32 {
+    0.5 + predator.aggression.clamp(0.0, 1.0)
+}
+
+/// Chance that struck prey fights back and injures the predator
+/// Prey as large as the predator hits back half the time when bold; small or timid prey rarely do.
+pub fn counter_damage_probability(predator: &CachedTraits, prey: &CachedTraits) -> f32 {
+    let size_share = prey.size / (prey.size + predator.size).max(f32::EPSILON);
+    (size_share * (0.5 + prey.boldness)).clamp(0.0, 0.9)
+}
+
+/// Resolve strikes by predators that have closed in on their target prey
+/// A hit wounds the prey; it's captured once its health runs out, otherwise it escapes hurt.
+pub fn handle_predation(
+    mut predation: ResMut<Predation>,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+    mut predator_query: Query<
+        (Entity, &Position, &Behavior, &CachedTraits, &OrganismType, &SpeciesId, &mut RngSeed),
+        With<Alive>,
+    >,
+    prey_query: Query<(&Position, &CachedTraits, &OrganismType, &SpeciesId), With<Alive>>,
+    mut energy_query: Query<(&mut Energy, Option<&mut Stomach>, Option<&mut EnergyBudget>), With<Alive>>,
+    mut health_query: Query<&mut Health, With<Alive>>,
+    mut trophic: ResMut<TrophicFlows>,
+    order: Res<DeterministicOrder>,
+    uid_query: Query<&OrganismUid>,
+) {
+    if !tuning.predation_enabled {
+        return;
+    }
+
+    let dt = time.delta_seconds();
+    predation.strike_cooldowns.retain(|_, remaining| {
+        *remaining -= dt;
+        *remaining > 0.0
+    });
+
+    let contact_distance_sq = tuning.predation_contact_distance * tuning.predation_contact_distance;
+    let mut captures: Vec<(Entity, Entity)> = Vec::new();
+    let mut taken: HashSet<Entity> = HashSet::new();
+    // Energy each predator loses to a missed strike
+    let mut strike_costs: Vec<(Entity, f32)> = Vec::new();
+
+    // The first predator to strike claims the prey
+    let mut predators: Vec<_> = predator_query.iter_mut().collect();
+    order.sort(&mut predators, &uid_query, |predator| predator.0);
+
+    for (predator, position, behavior, predator_traits, organism_type, species, mut rng_seed) in
+        predators
+    {
+        if *organism_type != OrganismType::Consumer
+            || !matches!(behavior.state, BehaviorState::Eating | BehaviorState::Chasing)
+            || predation.strike_cooldowns.contains_key(&predator)
+        {
+            continue;
+        }
+        let Some(prey) = behavior.target_entity else {
+            continue;
+        };
+        if prey == predator || taken.contains(&prey) {
+            continue;
+        }
+        let Ok((prey_position, prey_traits, _, prey_species)) = prey_query.get(prey) else {
+            continue;
+        };
+        // A target picked before a species split or a tuning change may be kin by now
+        if !tuning.intraspecific_predation && prey_species == species {
+            continue;
+        }
+        if (position.0 - prey_position.0).length_squared() > contact_distance_sq {
+            continue;
+        }
+
+        let chance = capture_probability(tuning.base_capture_chance, predator_traits, prey_traits);
+        let commitment = strike_commitment(predator_traits);
+        let rng = rng_seed.rng();
+        if rng.f32() < chance {
+            let damage = tuning.strike_damage_per_size * predator_traits.size * commitment;
+            // Organisms without health (none spawn that way) die to any hit
+            let killed = health_query.get_mut(prey).map_or(true, |mut health| {
+                health.damage(damage);
+                health.is_dead()
+            });
+            if killed {
+                captures.push((predator, prey));
+                taken.insert(prey);
+                predation.captures += 1;
+            } else {
+                predation.wounds += 1;
+            }
+
+            if rng.f32() < counter_damage_probability(predator_traits, prey_traits) {
+                let damage = tuning.counter_damage_per_size * prey_traits.size * commitment;
+                if let Ok(mut health) = health_query.get_mut(predator) {
+                    health.damage(damage);
+                }
+                predation.injuries += 1;
+            }
+        } else {
+            predation.escapes += 1;
+            strike_costs.push((predator, tuning.failed_strike_energy_cost * commitment));
+        }
+        // Handling time after a kill, recovery after a miss
+        predation
+            .strike_cooldowns
+            .insert(predator, tuning.predation_strike_cooldown);
+    }
+
+    for (predator, prey) in captures {
+        // The prey is drained; handle_death removes it
+        let prey_energy = match energy_query.get_mut(prey) {
+            Ok((mut energy, _, _)) => std::mem::replace(&mut energy.current, 0.0).max(0.0),
+            Err(_) => continue,
+        };
+
+        let meal = prey_energy * tuning.energy_conversion_efficiency;
+        if let Ok((mut energy, stomach, budget)) = energy_query.get_mut(predator) {
+            let taken_in = if let Some(mut stomach) = stomach {
+                meal - stomach.fill(meal)
+            } else {
+                let previous = energy.current;
+                energy.current = (energy.current + meal).min(energy.max);
+                if let Some(mut budget) = budget {
+                    budget.eaten += energy.current - previous;
+                }
+                energy.current - previous
+            };
+            let animal_fraction = match prey_query.get(prey) {
+                Ok((_, _, OrganismType::Producer, _)) => 0.0,
+                _ => 1.0,
+            };
+            trophic.record_intake(OrganismType::Consumer, taken_in, animal_fraction);
+        }
+    }
+
+    // Missed strikes come out of the energy pool, so reckless predators can starve of it
+    for (predator, cost) in strike_costs {
+        if cost <= 0.0 {
+            continue;
+        }
+        if let Ok((mut energy, _, _)) = energy_query.get_mut(predator) {
+            let previous = energy.current;
+            energy.current = (energy.current - cost).max(0.0);
+            let lost = previous - energy.current;
+            predation.strike_energy_lost += lost as f64;
+            trophic.record_metabolism(OrganismType::Consumer, lost);
+        }
+    }
+}
+
+/// Regenerate health while organisms are fed
+pub fn heal_wounds(
+    mut query: Query<(&mut Health, &Energy), With<Alive>>,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+) {
+    let rate = tuning.health_regeneration_rate * time.delta_seconds();
+    if rate <= 0.0 {
+        return;
+    }
+    for (mut health, energy) in query.iter_mut() {
+        if health.current < health.max && energy.ratio() >= HEALING_ENERGY_FRACTION {
+            let amount = health.max * rate;
+            health.heal(amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::genetics::{Genome, GENOME_SIZE};
+
+    fn traits(size: f32, speed: f32, aggression: f32, boldness: f32) -> CachedTraits {
+        let mut traits = CachedTraits::from_genome(&Genome::new(vec![0.5; GENOME_SIZE]));
+        traits.size = size;
+        traits.speed = speed;
+        traits.aggression = aggression;
+        traits.boldness = boldness;
+        traits
+    }
+
+    #[test]
+    fn aggression_costs_grow_faster_than_capture_chance() {
+        let tuning = EcosystemTuning::default();
+        let prey = traits(1.0, 5.0, 0.0, 0.5);
+        let meek = traits(1.0, 5.0, 0.0, 0.5);
+        let reckless = traits(1.0, 5.0, 1.0, 0.5);
+
+        let chance = |predator: &CachedTraits| {
+            capture_probability(tuning.base_capture_chance, predator, &prey)
+        };
+        // Energy a strike is expected to cost through misses
+        let miss_cost = |predator: &CachedTraits| {
+            (1.0 - chance(predator)) * tuning.failed_strike_energy_cost * strike_commitment(predator)
+        };
+        // Health a strike is expected to cost through counter-attacks
+        let injury = |predator: &CachedTraits| {
+            chance(predator)
+                * counter_damage_probability(predator, &prey)
+                * tuning.counter_damage_per_size
+                * prey.size
+                * strike_commitment(predator)
+        };
+
+        let chance_gain = chance(&reckless) / chance(&meek);
+        assert!(chance_gain > 1.0, "aggression should still help capture");
+        assert!(miss_cost(&reckless) / miss_cost(&meek) > chance_gain);
+        assert!(injury(&reckless) / injury(&meek) > chance_gain);
+    }
+
+    #[test]
+    fn capture_chance_stays_below_certainty() {
+        let tuning = EcosystemTuning::default();
+        let predator = traits(3.0, 20.0, 1.0, 0.5);
+        let prey = traits(0.3, 0.5, 0.0, 0.0);
+        assert!(capture_probability(tuning.base_capture_chance, &predator, &prey) <= 0.95);
+    }
+
+    /// Strikes resolved when a predator of species 1 reaches prey of `prey_species`
+    fn strikes_on(prey_species: u32, intraspecific_predation: bool) -> u64 {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.insert_resource(EcosystemTuning {
+            predation_enabled: true,
+            intraspecific_predation,
+            ..EcosystemTuning::default()
+        });
+        world.init_resource::<Predation>();
+        world.init_resource::<TrophicFlows>();
+        world.init_resource::<DeterministicOrder>();
+
+        let prey = world
+            .spawn((
+                Position::new(1.0, 0.0),
+                traits(0.5, 1.0, 0.0, 0.0),
+                OrganismType::Consumer,
+                SpeciesId::new(prey_species),
+                Energy::new(50.0),
+                Health::for_size(0.5, 10.0),
+                Alive,
+            ))
+            .id();
+        let mut behavior = Behavior::new();
+        behavior.state = BehaviorState::Chasing;
+        behavior.target_entity = Some(prey);
+        world.spawn((
+            Position::new(0.0, 0.0),
+            behavior,
+            traits(2.0, 5.0, 0.5, 0.5),
+            OrganismType::Consumer,
+            SpeciesId::new(1),
+            RngSeed::new(7),
+            Energy::new(100.0),
+            Alive,
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(handle_predation);
+        schedule.run(&mut world);
+        let predation = world.resource::<Predation>();
+        predation.captures + predation.wounds + predation.escapes
+    }
+
+    #[test]
+    fn cannibalism_off_spares_kin() {
+        assert_eq!(strikes_on(1, false), 0);
+        // Other species are still hunted, and kin are when cannibalism is on
+        assert_eq!(strikes_on(2, false), 1);
+        assert_eq!(strikes_on(1, true), 1);
+    }
+}
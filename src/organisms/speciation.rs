@@ -1,7 +1,10 @@
 use crate::organisms::components::SpeciesId;
-use crate::organisms::genetics::Genome;
+use crate::organisms::genetics::{GeneticDistanceMetric, Genome, GENOME_SIZE};
 use bevy::prelude::*;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
 
 /// Speciation threshold - genetic distance below which organisms are same species
 /// Step 8: Now configurable via EcosystemTuning
@@ -14,19 +17,32 @@ pub struct SpeciesTracker {
     species_centroids: HashMap<u32, Genome>,
     /// Next available species ID
     next_species_id: u32,
-    /// Counter for speciation updates (update periodically)
-    update_counter: u32,
     /// Speciation threshold (configurable via tuning)
     threshold: f32,
+    /// How genomes are compared against centroids (configurable via tuning)
+    metric: GeneticDistanceMetric,
+    /// Members per species as of the last centroid update
+    member_counts: HashMap<u32, usize>,
+    centroid_writer: Option<BufWriter<File>>,
+    centroid_csv_path: PathBuf,
 }
 
 impl Default for SpeciesTracker {
     fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
         Self {
             species_centroids: HashMap::new(),
             next_species_id: 0,
-            update_counter: 0,
             threshold: DEFAULT_SPECIATION_THRESHOLD,
+            metric: GeneticDistanceMetric::default(),
+            member_counts: HashMap::new(),
+            centroid_writer: None,
+            centroid_csv_path: logs_dir.join(format!("species_centroids_{}.csv", timestamp)),
         }
     }
 }
@@ -41,22 +57,44 @@ impl SpeciesTracker {
     }
 
     /// Find or assign species ID for a genome
+    /// Matches the nearest centroid within the threshold (ties go to the older species),
+    /// so the result doesn't depend on map iteration order.
     pub fn find_or_create_species(&mut self, genome: &Genome) -> SpeciesId {
-        // Check if genome matches any existing species (within threshold)
-        for (species_id, centroid) in &self.species_centroids {
-            let distance = genome.distance(centroid);
-            if distance < self.threshold {
-                return SpeciesId::new(*species_id);
-            }
+        let nearest = self
+            .species_centroids
+            .iter()
+            .map(|(species_id, centroid)| (*species_id, genome.distance_with(centroid, self.metric)))
+            .filter(|(_, distance)| *distance < self.threshold)
+            .min_by(|a, b| {
+                a.1.partial_cmp(&b.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(a.0.cmp(&b.0))
+            });
+        if let Some((species_id, _)) = nearest {
+            return SpeciesId::new(species_id);
         }
 
         // No match found - create new species
         let new_id = self.next_species_id;
         self.next_species_id += 1;
         self.species_centroids.insert(new_id, genome.clone());
+        info!("[SPECIATION] New species {} ({})", new_id, species_name(new_id));
         SpeciesId::new(new_id)
     }
 
+    /// Re-derive a species assignment, keeping the current ID while the genome is still
+    /// within the threshold of that species' centroid
+    /// Only organisms that have drifted out of their species are matched again, so IDs stay
+    /// stable across relabeling and new IDs are minted only for genuinely new clusters.
+    pub fn reassign_species(&mut self, genome: &Genome, current: SpeciesId) -> SpeciesId {
+        if let Some(centroid) = self.species_centroids.get(&current.value()) {
+            if genome.distance_with(centroid, self.metric) < self.threshold {
+                return current;
+            }
+        }
+        self.find_or_create_species(genome)
+    }
+
     /// Update species centroids periodically based on average genomes
     pub fn update_centroids(
         &mut self,
@@ -72,6 +110,11 @@ impl SpeciesTracker {
                 .push(genome);
         }
 
+        self.member_counts = species_genomes
+            .iter()
+            .map(|(species_id, genomes)| (*species_id, genomes.len()))
+            .collect();
+
         // Update centroids with average genome per species
         for (species_id, genomes) in species_genomes {
             if genomes.is_empty() {
@@ -81,7 +124,7 @@ impl SpeciesTracker {
             // Calculate average genome
             let genome_size = genomes[0].genes.len();
             let mut avg_genes = Vec::with_capacity(genome_size);
-            avg_genes.resize(genome_size, 0.5);
+            avg_genes.resize(genome_size, 0.0);
             let mut avg_genome = Genome::new(avg_genes);
             
             for genome in &genomes {
@@ -115,6 +158,107 @@ impl SpeciesTracker {
     pub fn get_all_species(&self) -> Vec<u32> {
         self.species_centroids.keys().copied().collect()
     }
+
+    /// Species with members as of the last centroid update, as (id, member count, centroid),
+    /// sorted by ID
+    pub fn populated_centroids(&self) -> Vec<(u32, usize, &Genome)> {
+        let mut species: Vec<(u32, usize, &Genome)> = self
+            .member_counts
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .filter_map(|(species_id, count)| {
+                self.species_centroids
+                    .get(species_id)
+                    .map(|centroid| (*species_id, *count, centroid))
+            })
+            .collect();
+        species.sort_unstable_by_key(|(species_id, _, _)| *species_id);
+        species
+    }
+
+    /// Metric currently used to compare genomes
+    pub fn metric(&self) -> GeneticDistanceMetric {
+        self.metric
+    }
+
+    fn ensure_centroid_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.centroid_writer.is_none() {
+            let file = match File::create(&self.centroid_csv_path) {
+                Ok(file) => file,
+                Err(err) => {
+                    error!("Failed to create species centroid CSV file: {err}");
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+
+            let mut header = String::from("tick,species_id,species_name,member_count");
+            for gene in 0..GENOME_SIZE {
+                header.push_str(&format!(",gene{gene}"));
+            }
+            if let Err(err) = writeln!(writer, "{header}") {
+                error!("Failed to write species centroid CSV header: {err}");
+                return None;
+            }
+
+            info!(
+                "[SPECIATION] Writing species centroid genomes to {}",
+                self.centroid_csv_path.display()
+            );
+            self.centroid_writer = Some(writer);
+        }
+        self.centroid_writer.as_mut()
+    }
+
+    /// Append one row per populated species: its centroid genome and member count
+    fn export_centroids(&mut self, tick: u64) {
+        let mut species: Vec<(u32, usize)> = self
+            .member_counts
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(species_id, count)| (*species_id, *count))
+            .collect();
+        species.sort_unstable();
+
+        let mut rows = String::new();
+        for (species_id, count) in species {
+            let Some(centroid) = self.species_centroids.get(&species_id) else {
+                continue;
+            };
+            rows.push_str(&format!("{tick},{species_id},{},{count}", species_name(species_id)));
+            for gene in &centroid.genes {
+                rows.push_str(&format!(",{gene:.6}"));
+            }
+            rows.push('\n');
+        }
+
+        if let Some(writer) = self.ensure_centroid_writer() {
+            if let Err(err) = writer.write_all(rows.as_bytes()).and_then(|_| writer.flush()) {
+                error!("Failed to write species centroid CSV rows: {err}");
+            }
+        }
+    }
+}
+
+/// Human-readable name for a species ID (deterministic, so it's stable for the ID's lifetime)
+pub fn species_name(species_id: u32) -> String {
+    const PREFIXES: [&str; 16] = [
+        "Ae", "Bra", "Cor", "Dal", "Eri", "Fen", "Gal", "Hel", "Ix", "Jun", "Kor", "Lum",
+        "Mor", "Nyx", "Or", "Pyr",
+    ];
+    const SUFFIXES: [&str; 16] = [
+        "ax", "bia", "cus", "don", "era", "gon", "ia", "lus", "mys", "nia", "ops", "ora",
+        "rex", "sia", "tor", "us",
+    ];
+
+    let prefix = PREFIXES[species_id as usize % PREFIXES.len()];
+    let suffix = SUFFIXES[(species_id as usize / PREFIXES.len()) % SUFFIXES.len()];
+    let cycle = species_id as usize / (PREFIXES.len() * SUFFIXES.len());
+    if cycle == 0 {
+        format!("{prefix}{suffix}")
+    } else {
+        format!("{prefix}{suffix} {}", cycle + 1)
+    }
 }
 
 /// Update species assignments periodically (Step 8 - Speciation)
@@ -122,16 +266,21 @@ pub fn update_speciation(
     mut tracker: ResMut<SpeciesTracker>,
     tuning: Option<Res<crate::organisms::EcosystemTuning>>, // Step 8: Optional tuning
     mut query: Query<(Entity, &Genome, &mut SpeciesId), With<crate::organisms::components::Alive>>,
+    sim_time: Res<crate::utils::SimTime>,
+    logging: Res<crate::organisms::LoggingConfig>,
+    order: Res<crate::organisms::DeterministicOrder>,
+    uid_query: Query<&crate::organisms::components::OrganismUid>,
 ) {
     // Update threshold from tuning if available
     if let Some(tuning) = tuning {
         tracker.threshold = tuning.speciation_threshold;
+        tracker.metric = tuning.speciation_distance_metric;
     }
-    tracker.update_counter += 1;
-    
     // Update centroids every 100 ticks (not every tick for performance)
-    if tracker.update_counter % 100 == 0 {
-        let organisms: Vec<_> = query.iter().collect();
+    if sim_time.every(100) {
+        // Centroids are float averages, so the summation order must be stable too
+        let mut organisms: Vec<_> = query.iter().collect();
+        order.sort(&mut organisms, &uid_query, |organism| organism.0);
         let previous_count = tracker.species_count();
         tracker.update_centroids(&organisms);
         let new_count = tracker.species_count();
@@ -139,13 +288,21 @@ pub fn update_speciation(
         if new_count != previous_count {
             info!("[SPECIATION] Species count changed: {} -> {}", previous_count, new_count);
         }
+
+        // Centroid genomes are exported for replaying trajectories through gene space
+        if logging.centroids_enabled && sim_time.every(logging.centroid_interval) {
+            tracker.export_centroids(sim_time.tick);
+        }
     }
 
     // Reassign species IDs based on current centroids (every 500 ticks for performance)
-    if tracker.update_counter % 500 == 0 {
+    if sim_time.every(500) {
         let mut updated_count = 0;
-        for (_entity, genome, mut species_id) in query.iter_mut() {
-            let new_species = tracker.find_or_create_species(genome);
+        // Drifted organisms may found new species, and IDs are handed out in this order
+        let mut organisms: Vec<_> = query.iter_mut().collect();
+        order.sort(&mut organisms, &uid_query, |organism| organism.0);
+        for (_entity, genome, mut species_id) in organisms {
+            let new_species = tracker.reassign_species(genome, *species_id);
             if new_species != *species_id {
                 *species_id = new_species;
                 updated_count += 1;
@@ -153,7 +310,7 @@ pub fn update_speciation(
         }
         
         let species_count = tracker.species_count();
-        if updated_count > 0 || tracker.update_counter % 5000 == 0 {
+        if updated_count > 0 || sim_time.every(5000) {
             info!(
                 "[SPECIATION] Updated {} organism species assignments | Total species: {}",
                 updated_count,
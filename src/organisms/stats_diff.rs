@@ -0,0 +1,136 @@
+use crate::organisms::ecosystem_stats::StatsSnapshot;
+use crate::organisms::speciation::species_name;
+use crate::utils::SimError;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+/// What changed between two exported stats snapshots
+/// Full world saves don't exist yet, so the `stats.json` dumps written by `export_stats_json`
+/// serve as the checkpoints being compared.
+pub struct WorldDiff {
+    pub tick_a: u64,
+    pub tick_b: u64,
+    pub population_a: u32,
+    pub population_b: u32,
+    pub species_gained: Vec<u32>,
+    pub species_lost: Vec<u32>,
+    /// Change in each normalized trait mean present in both snapshots
+    pub trait_shifts: BTreeMap<String, f32>,
+    /// Change in world total per resource (a resource missing from a snapshot counts as 0)
+    pub resource_changes: BTreeMap<String, f64>,
+    /// Reasons the two snapshots may not be directly comparable
+    pub warnings: Vec<String>,
+}
+
+impl WorldDiff {
+    pub fn population_change(&self) -> i64 {
+        self.population_b as i64 - self.population_a as i64
+    }
+}
+
+/// Compare snapshot `a` (earlier) with snapshot `b` (later)
+pub fn diff_worlds(a: &StatsSnapshot, b: &StatsSnapshot) -> WorldDiff {
+    let mut warnings = Vec::new();
+    match (a.world_seed, b.world_seed) {
+        (Some(seed_a), Some(seed_b)) if seed_a != seed_b => warnings.push(format!(
+            "world seeds differ ({seed_a} vs {seed_b}); these are different worlds"
+        )),
+        (None, _) | (_, None) => {
+            warnings.push("world seed missing from a snapshot; can't confirm it's the same world".to_string())
+        }
+        _ => {}
+    }
+    if a.chunk_count != b.chunk_count {
+        warnings.push(format!(
+            "explored area differs ({} vs {} chunks); resource totals cover different regions",
+            a.chunk_count, b.chunk_count
+        ));
+    }
+    if b.tick < a.tick {
+        warnings.push(format!(
+            "second snapshot is older (tick {} < {}); deltas run backwards in time",
+            b.tick, a.tick
+        ));
+    }
+
+    let species_a: BTreeSet<u32> = a.population_by_species.keys().copied().collect();
+    let species_b: BTreeSet<u32> = b.population_by_species.keys().copied().collect();
+
+    let trait_shifts = a
+        .normalized_trait_means
+        .iter()
+        .filter_map(|(name, mean_a)| {
+            b.normalized_trait_means
+                .get(name)
+                .map(|mean_b| (name.clone(), mean_b - mean_a))
+        })
+        .collect();
+
+    let resource_names: BTreeSet<&String> = a.resource_totals.keys().chain(b.resource_totals.keys()).collect();
+    let resource_changes = resource_names
+        .into_iter()
+        .map(|name| {
+            let before = a.resource_totals.get(name).copied().unwrap_or(0.0);
+            let after = b.resource_totals.get(name).copied().unwrap_or(0.0);
+            (name.clone(), after - before)
+        })
+        .collect();
+
+    WorldDiff {
+        tick_a: a.tick,
+        tick_b: b.tick,
+        population_a: a.total_population,
+        population_b: b.total_population,
+        species_gained: species_b.difference(&species_a).copied().collect(),
+        species_lost: species_a.difference(&species_b).copied().collect(),
+        trait_shifts,
+        resource_changes,
+        warnings,
+    }
+}
+
+/// Load two snapshot files and diff them (the `--diff a b` mode)
+pub fn diff_world_files(path_a: &Path, path_b: &Path) -> Result<WorldDiff, SimError> {
+    let a = StatsSnapshot::load(path_a)?;
+    let b = StatsSnapshot::load(path_b)?;
+    Ok(diff_worlds(&a, &b))
+}
+
+fn species_list(species: &[u32]) -> String {
+    if species.is_empty() {
+        return "none".to_string();
+    }
+    species
+        .iter()
+        .map(|id| format!("{} ({})", id, species_name(*id)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for WorldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "World diff: tick {} -> {}", self.tick_a, self.tick_b)?;
+        for warning in &self.warnings {
+            writeln!(f, "  warning: {warning}")?;
+        }
+        writeln!(
+            f,
+            "Population: {} -> {} ({:+})",
+            self.population_a,
+            self.population_b,
+            self.population_change()
+        )?;
+        writeln!(f, "Species gained: {}", species_list(&self.species_gained))?;
+        writeln!(f, "Species lost: {}", species_list(&self.species_lost))?;
+        writeln!(f, "Mean trait shifts (normalized):")?;
+        for (name, shift) in &self.trait_shifts {
+            writeln!(f, "  {:<24} {:+.4}", name, shift)?;
+        }
+        writeln!(f, "Resource totals:")?;
+        for (name, change) in &self.resource_changes {
+            writeln!(f, "  {:<24} {:+.1}", name, change)?;
+        }
+        Ok(())
+    }
+}
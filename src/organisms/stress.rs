@@ -0,0 +1,59 @@
+use crate::organisms::components::{Alive, Energy, Position, SpeciesId, Stress};
+use crate::organisms::{EcosystemTuning, ScentMarks};
+use crate::world::WorldGrid;
+use bevy::prelude::*;
+
+/// Cell temperature organisms are most comfortable at
+pub const IDEAL_TEMPERATURE: f32 = 0.5;
+
+/// Instantaneous stress from energy and cell temperature (0-1)
+/// Starvation stress grows as energy falls below the threshold; thermal stress grows as the
+/// cell's temperature leaves the comfort range. The worse of the two counts.
+pub fn instantaneous_stress(energy_ratio: f32, temperature: f32, tuning: &EcosystemTuning) -> f32 {
+    let energy_threshold = tuning.stress_energy_threshold;
+    let starvation = if energy_threshold > 0.0 {
+        ((energy_threshold - energy_ratio) / energy_threshold).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    starvation.max(thermal_stress(temperature, tuning.thermal_comfort_range))
+}
+
+/// Thermal stress at a temperature (0 inside the comfort range, 1 at freezing or boiling)
+pub fn thermal_stress(temperature: f32, comfort_range: f32) -> f32 {
+    let comfort = comfort_range.clamp(0.0, IDEAL_TEMPERATURE);
+    let tolerance = IDEAL_TEMPERATURE - comfort;
+    if tolerance > 0.0 {
+        (((temperature - IDEAL_TEMPERATURE).abs() - comfort) / tolerance).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Move each organism's stress level toward its current conditions
+/// A brief dip barely registers; only conditions that persist for around
+/// `stress_memory_seconds` bring the level (and the mutation boost) up to full.
+/// Standing in another species' territory counts too (see `ScentMarks::intrusion_stress`).
+pub fn update_stress(
+    mut query: Query<(&Energy, &Position, &SpeciesId, &mut Stress), With<Alive>>,
+    world_grid: Res<WorldGrid>,
+    scent_marks: Res<ScentMarks>,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+) {
+    if tuning.stress_mutagenesis_strength <= 0.0 {
+        return;
+    }
+    let response = (time.delta_seconds() / tuning.stress_memory_seconds.max(f32::EPSILON)).min(1.0);
+
+    for (energy, position, species, mut stress) in query.iter_mut() {
+        let temperature = world_grid
+            .get_cell(position.0.x, position.0.y)
+            .map_or(IDEAL_TEMPERATURE, |cell| cell.temperature);
+        let current = instantaneous_stress(energy.ratio(), temperature, &tuning).max(
+            scent_marks.intrusion_stress(position.0, species.value(), &tuning),
+        );
+        stress.level += (current - stress.level) * response;
+    }
+}
@@ -1,8 +1,12 @@
 use crate::organisms::behavior::*;
+use crate::organisms::care::{CaredFor, EarlyLife};
+use crate::organisms::clutch::offspring_initial_energy;
 use crate::organisms::components::*;
-use crate::organisms::genetics::{traits, Genome};
-use crate::utils::SpatialHashGrid;
-use crate::world::{ResourceType, WorldGrid};
+use crate::organisms::decision_trace::DecisionTrace;
+use crate::organisms::genetics::{traits, Genome, MutationSpectrum};
+use crate::organisms::mutation_log::MutationContext;
+use crate::utils::{SimTime, SpatialHashGrid};
+use crate::world::{ResourceType, TerrainType, WorldGrid, RESOURCE_TYPE_COUNT};
 use bevy::prelude::*;
 use glam::Vec2;
 
@@ -11,44 +15,234 @@ use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 
-const ALL_ORGANISMS_HEADER: &str = "tick,entity,position_x,position_y,velocity_x,velocity_y,speed,energy_current,energy_max,energy_ratio,age,size,organism_type,behavior_state,state_time,target_x,target_y,target_entity,sensory_range,aggression,boldness,mutation_rate,reproduction_threshold,reproduction_cooldown,foraging_drive,risk_tolerance,exploration_drive,clutch_size,offspring_energy_share,hunger_memory,threat_timer,resource_selectivity,migration_target_x,migration_target_y,migration_active";
+/// All-organism CSV columns after `tick,organism_uid` (and the optional `entity_index`)
+const ALL_ORGANISMS_COLUMNS: &str = "position_x,position_y,velocity_x,velocity_y,speed,energy_current,energy_max,energy_ratio,age,size,organism_type,behavior_state,state_time,target_x,target_y,target_uid,sensory_range,aggression,boldness,mutation_rate,reproduction_threshold,reproduction_cooldown,foraging_drive,risk_tolerance,exploration_drive,clutch_size,offspring_energy_share,hunger_memory,threat_timer,resource_selectivity,migration_target_x,migration_target_y,migration_active,reproductive_strategy_index,health_ratio";
 
-fn ensure_logs_directory() -> PathBuf {
-    let logs_dir = PathBuf::from("data/logs");
+thread_local! {
+    /// Log directory override for resources created on this thread (see `with_logs_directory`)
+    static LOGS_DIRECTORY: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Run `build` with `logs_dir` as the directory every logger created inside it writes to
+/// Loggers pick their paths when their resources are created, so a `SimInstance` builds its
+/// app inside this to keep its CSVs apart from other instances in the same process.
+pub(crate) fn with_logs_directory<T>(logs_dir: PathBuf, build: impl FnOnce() -> T) -> T {
+    let previous = LOGS_DIRECTORY.with(|dir| dir.replace(Some(logs_dir)));
+    let result = build();
+    LOGS_DIRECTORY.with(|dir| *dir.borrow_mut() = previous);
+    result
+}
+
+pub(crate) fn ensure_logs_directory() -> PathBuf {
+    let logs_dir = LOGS_DIRECTORY
+        .with(|dir| dir.borrow().clone())
+        .unwrap_or_else(|| PathBuf::from("data/logs"));
     if !logs_dir.exists() {
-        std::fs::create_dir_all(&logs_dir).expect("Failed to create logs directory");
+        // The loggers report (and stop) when they fail to open their files in it
+        if let Err(err) = std::fs::create_dir_all(&logs_dir) {
+            error!("Failed to create logs directory {}: {err}", logs_dir.display());
+        }
     }
     logs_dir
 }
 
-/// Resource to track which organism we're logging
-#[derive(Resource)]
-pub struct TrackedOrganism {
-    entity: Option<Entity>,
-    log_counter: u32,
+/// Logger cadence and on/off switches, read by the loggers every tick
+/// Set from the command line at startup (see `from_args`) or changed at runtime.
+#[derive(Resource, Clone, Debug)]
+pub struct LoggingConfig {
+    /// Write periodic all-organism CSV snapshots
+    pub snapshots_enabled: bool,
+    /// Ticks between all-organism snapshots
+    pub snapshot_interval: u64,
+    /// Ticks between flushes of the snapshot CSV
+    pub snapshot_flush_interval: u64,
+    /// Add the raw Bevy entity index next to `organism_uid` in the snapshot CSV (debugging)
+    /// Fixed once the header has been written.
+    pub snapshot_entity_index: bool,
+    /// Log the tracked organism (info lines and CSV)
+    pub tracking_enabled: bool,
+    /// Ticks between tracked-organism log lines
+    pub tracking_interval: u64,
+    /// Ticks between flushes of the tracked-organism CSV
+    pub tracking_flush_interval: u64,
+    /// Record every behavior decision of the tracked organisms (see `DecisionTraceLog`)
+    pub decision_trace_enabled: bool,
+    /// Export drift vs selection metrics (see `DriftAnalysis`)
+    pub drift_enabled: bool,
+    /// Ticks between drift samples
+    pub drift_interval: u64,
+    /// Export species centroid genomes (see `SpeciesTracker`)
+    pub centroids_enabled: bool,
+    /// Ticks between centroid exports (checked on the 100-tick centroid update)
+    pub centroid_interval: u64,
+    /// Export trophic flows (see `TrophicFlows`)
+    pub trophic_enabled: bool,
+    /// Ticks per trophic-flow interval
+    pub trophic_interval: u64,
+    /// Export species dendrograms (see `SpeciesDendrogram`)
+    pub dendrogram_enabled: bool,
+    /// Ticks between dendrograms (clustering is O(k³) in the species count)
+    pub dendrogram_interval: u64,
+    /// Detect and export herds (see `Herds`)
+    pub herds_enabled: bool,
+    /// Ticks between herd clustering passes
+    pub herd_interval: u64,
+    /// Log offspring mutations (see `MutationLog`)
+    pub mutations_enabled: bool,
+    /// Log every n-th offspring's mutations (plus all of the tracked organisms')
+    pub mutation_sample_interval: u64,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            snapshots_enabled: true,
+            snapshot_interval: 50,        // snapshot every 50 ticks by default
+            snapshot_flush_interval: 500, // flush every ~500 logged ticks
+            snapshot_entity_index: false,
+            tracking_enabled: true,
+            tracking_interval: 10,
+            tracking_flush_interval: 100,
+            decision_trace_enabled: false,
+            drift_enabled: true,
+            drift_interval: 500,
+            centroids_enabled: true,
+            centroid_interval: 500,
+            trophic_enabled: true,
+            trophic_interval: 500,
+            dendrogram_enabled: true,
+            dendrogram_interval: 2500,
+            herds_enabled: false,
+            herd_interval: 120, // Every 2 seconds
+            mutations_enabled: false,
+            mutation_sample_interval: 50,
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Defaults overridden by `--no-snapshots`, `--no-tracking`, `--trace-decisions`,
+    /// `--no-drift`, `--no-centroids`, `--no-trophic`, `--no-dendrogram`, `--herds`,
+    /// `--mutation-log <n>` and `--<logger>-interval <n>` for the snapshot, tracking, drift,
+    /// centroid, trophic, dendrogram and herd loggers
+    pub fn from_args() -> Self {
+        use crate::utils::cli::{has_flag, option_value};
+
+        let mut config = Self::default();
+        config.snapshots_enabled = !has_flag("no-snapshots");
+        config.tracking_enabled = !has_flag("no-tracking");
+        config.decision_trace_enabled = has_flag("trace-decisions");
+        config.drift_enabled = !has_flag("no-drift");
+        config.centroids_enabled = !has_flag("no-centroids");
+        config.trophic_enabled = !has_flag("no-trophic");
+        config.dendrogram_enabled = !has_flag("no-dendrogram");
+        config.herds_enabled = has_flag("herds");
+        let interval = |name: &str, default: u64| match option_value(name) {
+            Some(value) => value.trim().parse().unwrap_or_else(|_| {
+                warn!("Invalid --{} '{}', using {}", name, value, default);
+                default
+            }),
+            None => default,
+        };
+        config.snapshot_interval = interval("snapshot-interval", config.snapshot_interval);
+        config.tracking_interval = interval("tracking-interval", config.tracking_interval);
+        config.drift_interval = interval("drift-interval", config.drift_interval);
+        config.centroid_interval = interval("centroid-interval", config.centroid_interval);
+        config.trophic_interval = interval("trophic-interval", config.trophic_interval);
+        config.dendrogram_interval = interval("dendrogram-interval", config.dendrogram_interval);
+        config.herd_interval = interval("herd-interval", config.herd_interval);
+        if let Some(value) = option_value("mutation-log") {
+            match value.trim().parse() {
+                Ok(interval) if interval > 0 => {
+                    config.mutations_enabled = true;
+                    config.mutation_sample_interval = interval;
+                }
+                _ => warn!("Invalid --mutation-log '{}', not logging mutations", value),
+            }
+        }
+        config
+    }
+}
+
+/// One tracked organism and its own CSV stream
+struct TrackedSlot {
+    entity: Entity,
     csv_writer: Option<BufWriter<File>>,
     csv_path: PathBuf,
     header_written: bool,
+    /// Set after an I/O error; the slot stops writing rather than retrying every tick
+    failed: bool,
+}
+
+impl TrackedSlot {
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            if self.failed {
+                return None;
+            }
+            let file = match OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.csv_path)
+            {
+                Ok(file) => file,
+                Err(err) => {
+                    self.disable(&err);
+                    return None;
+                }
+            };
+            self.csv_writer = Some(BufWriter::new(file));
+            info!("[TRACKED] CSV logging to: {}", self.csv_path.display());
+        }
+        self.csv_writer.as_mut()
+    }
+
+    fn close(&mut self) {
+        close_csv_writer(&mut self.csv_writer, &self.csv_path);
+    }
+
+    /// Stop logging this organism after an I/O error (the simulation keeps running)
+    fn disable(&mut self, err: &std::io::Error) {
+        error!(
+            "[TRACKED] Disabling CSV logging to {} after an I/O error: {err}",
+            self.csv_path.display()
+        );
+        self.csv_writer = None;
+        self.failed = true;
+    }
+}
+
+/// Organisms we're logging in detail, each streamed to its own CSV
+/// Holds at most `capacity` organisms (one by default); tracking another when full drops
+/// the one tracked longest.
+#[derive(Resource)]
+pub struct TrackedOrganisms {
+    slots: Vec<TrackedSlot>,
+    /// Maximum number of organisms tracked at once
+    pub capacity: usize,
+    logs_dir: PathBuf,
+    timestamp: u64,
+    /// Tracking files handed out so far (the first keeps the single-organism file name)
+    files_created: usize,
 }
 
 // TRACKED ORGANISM LOGGING
-impl Default for TrackedOrganism {
+impl Default for TrackedOrganisms {
     fn default() -> Self {
         let logs_dir = ensure_logs_directory();
 
-        // Create CSV file with timestamp
+        // CSV files are named with a timestamp
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs();
-        let csv_path = logs_dir.join(format!("organism_tracking_{}.csv", timestamp));
 
         Self {
-            entity: None,
-            log_counter: 0,
-            csv_writer: None,
-            csv_path,
-            header_written: false,
+            slots: Vec::new(),
+            capacity: 1,
+            logs_dir,
+            timestamp,
+            files_created: 0,
         }
     }
 }
@@ -59,9 +253,10 @@ pub struct AllOrganismsLogger {
     csv_writer: Option<BufWriter<File>>,
     csv_path: PathBuf,
     header_written: bool,
-    tick_counter: u64,
-    sample_interval: u64,
-    flush_interval: u64,
+    /// Whether the written header includes `entity_index`
+    entity_index_column: bool,
+    /// Set after an I/O error; snapshots stop rather than retrying every tick
+    failed: bool,
 }
 
 impl Default for AllOrganismsLogger {
@@ -77,9 +272,8 @@ impl Default for AllOrganismsLogger {
             csv_writer: None,
             csv_path,
             header_written: false,
-            tick_counter: 0,
-            sample_interval: 50, // snapshot every 50 ticks by default
-            flush_interval: 500, // flush every ~500 logged ticks
+            entity_index_column: false,
+            failed: false,
         }
     }
 }
@@ -87,6 +281,9 @@ impl Default for AllOrganismsLogger {
 impl AllOrganismsLogger {
     fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
         if self.csv_writer.is_none() {
+            if self.failed {
+                return None;
+            }
             let file = match OpenOptions::new()
                 .create(true)
                 .append(true)
@@ -94,7 +291,7 @@ impl AllOrganismsLogger {
             {
                 Ok(file) => file,
                 Err(err) => {
-                    error!("Failed to open all-organism CSV file: {err}");
+                    self.disable(&err);
                     return None;
                 }
             };
@@ -106,34 +303,247 @@ impl AllOrganismsLogger {
         }
         self.csv_writer.as_mut()
     }
+
+    /// Flush and close the CSV writer (a later snapshot reopens it in append mode)
+    pub fn close(&mut self) {
+        close_csv_writer(&mut self.csv_writer, &self.csv_path);
+    }
+
+    /// Stop taking snapshots after an I/O error (the simulation keeps running)
+    fn disable(&mut self, err: &std::io::Error) {
+        error!(
+            "[LOGGER] Disabling all-organism snapshots to {} after an I/O error: {err}",
+            self.csv_path.display()
+        );
+        self.csv_writer = None;
+        self.failed = true;
+    }
+}
+
+impl Drop for AllOrganismsLogger {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// `--deterministic`: process organisms in `OrganismUid` order wherever the outcome depends
+/// on iteration order (shared grazing cells, prey claimed by the first striker, offspring
+/// uid and species allocation, alarm spreading)
+/// Bevy's query order isn't stable between runs, so without this two runs with the same
+/// seeds can diverge. Sorting costs time every tick, so it's meant for replays and
+/// reproducibility checks; `main` also fixes the timestep and founder placement with it.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct DeterministicOrder(pub bool);
+
+impl DeterministicOrder {
+    pub fn from_args() -> Self {
+        Self(crate::utils::cli::has_flag("deterministic"))
+    }
+
+    /// Frame-time source: a fixed 60 Hz step when enabled, since wall-clock frame times would
+    /// make every dt-scaled update differ between runs
+    pub fn time_update_strategy(&self) -> bevy::time::TimeUpdateStrategy {
+        if self.0 {
+            bevy::time::TimeUpdateStrategy::ManualDuration(std::time::Duration::from_secs_f64(1.0 / 60.0))
+        } else {
+            bevy::time::TimeUpdateStrategy::Automatic
+        }
+    }
+
+    /// Sort `items` by the uid of the organism each belongs to (no-op unless enabled)
+    pub fn sort<T>(&self, items: &mut [T], uids: &Query<&OrganismUid>, entity: impl Fn(&T) -> Entity) {
+        if self.0 {
+            items.sort_by_cached_key(|item| uids.get(entity(item)).map(|uid| uid.0).ok());
+        }
+    }
+}
+
+impl TrackedOrganisms {
+    /// Default tracker with `--tracked-organisms <n>` slots (1 if not given)
+    pub fn from_args() -> Self {
+        let mut tracked = Self::default();
+        if let Some(value) = crate::utils::cli::option_value("tracked-organisms") {
+            match value.trim().parse() {
+                Ok(capacity) => tracked.capacity = capacity,
+                Err(_) => warn!("Invalid --tracked-organisms '{}', tracking 1", value),
+            }
+        }
+        tracked
+    }
+
+    /// Organisms currently being tracked, oldest first
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.slots.iter().map(|slot| slot.entity)
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.slots.iter().any(|slot| slot.entity == entity)
+    }
+
+    /// Start tracking an organism in a fresh CSV, dropping the longest-tracked one if full
+    /// Returns false if it was already tracked or there are no slots.
+    pub fn track(&mut self, entity: Entity) -> bool {
+        if self.capacity == 0 || self.contains(entity) {
+            return false;
+        }
+        while self.slots.len() >= self.capacity {
+            let mut oldest = self.slots.remove(0);
+            oldest.close();
+            info!("[TRACKED] Stopped tracking organism entity: {:?}", oldest.entity);
+        }
+
+        let csv_path = if self.files_created == 0 {
+            self.logs_dir
+                .join(format!("organism_tracking_{}.csv", self.timestamp))
+        } else {
+            self.logs_dir.join(format!(
+                "organism_tracking_{}_{}.csv",
+                self.timestamp, self.files_created
+            ))
+        };
+        self.files_created += 1;
+
+        // The CSV is opened on the first logged row, so disabled tracking leaves no file
+        self.slots.push(TrackedSlot {
+            entity,
+            csv_writer: None,
+            csv_path,
+            header_written: false,
+            failed: false,
+        });
+        info!("[TRACKED] Started tracking organism entity: {:?}", entity);
+        true
+    }
+
+    /// Stop tracking an organism, closing its CSV; returns whether it was tracked
+    pub fn untrack(&mut self, entity: Entity) -> bool {
+        let Some(index) = self.slots.iter().position(|slot| slot.entity == entity) else {
+            return false;
+        };
+        let mut slot = self.slots.remove(index);
+        slot.close();
+        true
+    }
+
+    /// Track an untracked organism or stop tracking a tracked one; returns whether it's now tracked
+    pub fn toggle(&mut self, entity: Entity) -> bool {
+        if self.untrack(entity) {
+            info!("[TRACKED] Stopped tracking organism entity: {:?}", entity);
+            false
+        } else {
+            self.track(entity)
+        }
+    }
+
+    /// Flush and close every CSV writer (later rows reopen them in append mode)
+    pub fn close(&mut self) {
+        for slot in &mut self.slots {
+            slot.close();
+        }
+    }
+}
+
+impl Drop for TrackedOrganisms {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Flush and drop a buffered CSV writer, reporting (not panicking on) failures
+pub(crate) fn close_csv_writer(writer: &mut Option<BufWriter<File>>, path: &std::path::Path) {
+    if let Some(mut writer) = writer.take() {
+        match writer.flush() {
+            Ok(()) => info!("[LOGGER] Flushed and closed {}", path.display()),
+            Err(err) => error!("Failed to flush CSV file {}: {err}", path.display()),
+        }
+    }
+}
+
+/// Flush all CSV loggers when the app is exiting
+/// The window can close without resources being dropped, which would lose buffered rows
+pub fn flush_logs_on_exit(
+    mut exit_events: EventReader<bevy::app::AppExit>,
+    tracked: Option<ResMut<TrackedOrganisms>>,
+    all_organisms: Option<ResMut<AllOrganismsLogger>>,
+    decision_trace: Option<ResMut<crate::organisms::DecisionTraceLog>>,
+    mutation_log: Option<ResMut<crate::organisms::MutationLog>>,
+) {
+    if exit_events.read().next().is_none() {
+        return;
+    }
+
+    if let Some(mut decision_trace) = decision_trace {
+        decision_trace.close();
+    }
+
+    if let Some(mut tracked) = tracked {
+        tracked.close();
+    }
+    if let Some(mut all_organisms) = all_organisms {
+        all_organisms.close();
+    }
+    if let Some(mut mutation_log) = mutation_log {
+        mutation_log.close();
+    }
 }
 
 /// Spawn initial organisms in the world (Step 8: Uses tuning parameters)
 pub fn spawn_initial_organisms(
     mut commands: Commands,
-    mut tracked: ResMut<TrackedOrganism>,
+    mut tracked: ResMut<TrackedOrganisms>,
     mut species_tracker: ResMut<crate::organisms::speciation::SpeciesTracker>, // Step 8: Speciation
+    mut uids: ResMut<OrganismUids>,
     tuning: Res<crate::organisms::EcosystemTuning>, // Step 8: Tuning parameters
     _world_grid: Res<WorldGrid>,
+    order: Res<DeterministicOrder>,
 ) {
     info!("Spawning initial organisms...");
+    spawn_founders(
+        &mut commands,
+        &mut tracked,
+        &mut species_tracker,
+        &mut uids,
+        &tuning,
+        tuning.organism_seed,
+        *order,
+    );
+}
+
+/// Spawn a founder population and start tracking some of its organisms
+/// `seed` replaces `EcosystemTuning::organism_seed` so restarts get fresh genomes; under
+/// `DeterministicOrder` it also fixes where founders land and what type they are.
+pub(crate) fn spawn_founders(
+    commands: &mut Commands,
+    tracked: &mut TrackedOrganisms,
+    species_tracker: &mut crate::organisms::speciation::SpeciesTracker,
+    uids: &mut OrganismUids,
+    tuning: &crate::organisms::EcosystemTuning,
+    seed: u64,
+    order: DeterministicOrder,
+) {
 
-    let mut rng = fastrand::Rng::new();
+    let mut rng = if order.0 {
+        fastrand::Rng::with_seed(seed)
+    } else {
+        fastrand::Rng::new()
+    };
     let spawn_count = tuning.initial_spawn_count;
 
-    // Spawn organisms randomly within initialized chunks
-    // Chunks are from -1 to 1, each chunk is 64x64 cells
-    let world_size = 3 * 64; // 3 chunks * 64 cells
-    let spawn_range = world_size as f32 / 2.0; // -range to +range
+    // Spawn organisms randomly within the chunks generated at startup
+    let (spawn_min, spawn_max) = crate::world::initial_world_bounds(tuning.initial_world_radius);
 
-    let mut first_entity = None;
+    let mut founders: Vec<(Entity, OrganismType)> = Vec::with_capacity(spawn_count);
 
     for i in 0..spawn_count {
-        let x = rng.f32() * spawn_range * 2.0 - spawn_range;
-        let y = rng.f32() * spawn_range * 2.0 - spawn_range;
+        let x = spawn_min + rng.f32() * (spawn_max - spawn_min);
+        let y = spawn_min + rng.f32() * (spawn_max - spawn_min);
+
+        // Per-organism stream seeded from the world seed and spawn index
+        let mut rng_seed = RngSeed::founder(seed, i as u64);
 
         // Create random genome for this organism
-        let genome = Genome::random();
+        let genome = Genome::random_with_rng(rng_seed.rng());
+        let sex = tuning.sexes_enabled.then(|| Sex::random(rng_seed.rng()));
 
         // Express traits from genome
         let size = traits::express_size(&genome);
@@ -161,7 +571,7 @@ pub fn spawn_initial_organisms(
             .spawn((
                 Position::new(x, y),
                 Velocity::new(vel_x, vel_y),
-                Energy::new(max_energy),
+                Energy::with_fraction(max_energy, tuning.initial_organism_energy_fraction),
                 Age::new(),
                 Size::new(size),
                 Metabolism::new(metabolism_rate, movement_cost),
@@ -171,32 +581,43 @@ pub fn spawn_initial_organisms(
                 species_id, // Step 8: Use speciation-assigned species ID
                 organism_type,
                 Behavior::new(),
+                rng_seed,
+                EnergyBudget::default(),
                 Alive,
             ))
             .id();
-
-        // Track the first organism spawned
-        if i == 0 {
-            first_entity = Some(entity);
+        commands.entity(entity).insert((
+            Stomach::for_size(size, tuning.stomach_capacity_per_size),
+            Health::for_size(size, tuning.health_per_size),
+            Crowding::default(),
+            Stress::default(),
+            uids.allocate(),
+        ));
+        if let Some(sex) = sex {
+            commands.entity(entity).insert(sex);
         }
+
+        founders.push((entity, organism_type));
     }
 
     // TRACKED ORGANISM LOGGING
-    // Set the first organism as the tracked one
-    if let Some(entity) = first_entity {
-        tracked.entity = Some(entity);
-
-        // Initialize CSV writer
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&tracked.csv_path)
-            .expect("Failed to open CSV file for writing");
-        tracked.csv_writer = Some(BufWriter::new(file));
-
-        info!("[TRACKED] Started tracking organism entity: {:?}", entity);
-        info!("[TRACKED] CSV logging to: {}", tracked.csv_path.display());
-        info!("[TRACKED] Logging will begin after 10 ticks...");
+    // The first founder of each organism type comes first, then the earliest of the rest,
+    // so a single slot tracks the first organism spawned
+    let mut picks: Vec<Entity> = Vec::new();
+    let mut seen_types: Vec<OrganismType> = Vec::new();
+    for (entity, organism_type) in &founders {
+        if !seen_types.contains(organism_type) {
+            seen_types.push(*organism_type);
+            picks.push(*entity);
+        }
+    }
+    for (entity, _) in &founders {
+        if !picks.contains(entity) {
+            picks.push(*entity);
+        }
+    }
+    for entity in picks.into_iter().take(tracked.capacity) {
+        tracked.track(entity);
     }
 
     info!("Spawned {} organisms", spawn_count);
@@ -218,12 +639,14 @@ pub struct SpatialHashTracker {
 pub fn update_spatial_hash(
     mut spatial_hash: ResMut<SpatialHashGrid>,
     mut tracker: ResMut<SpatialHashTracker>,
+    mut world_grid: ResMut<WorldGrid>,
     query: Query<(Entity, &Position), With<Alive>>,
     mut removed: RemovedComponents<Alive>, // Entites that lost alive component
 ) {
     for entity in removed.read() {
         spatial_hash.organisms.remove(entity);
         tracker.previous_positions.remove(&entity);
+        world_grid.remove_organism(entity);
     }
     // Update only the entities that have moved or are new
     for (entity, position) in query.iter(){
@@ -234,11 +657,13 @@ pub fn update_spatial_hash(
             if (current_pos - *old_pos).length_squared() > 0.01 {
                 spatial_hash.organisms.insert(entity, current_pos);
                 tracker.previous_positions.insert(entity, current_pos);
+                world_grid.update_organism_chunk(entity, current_pos);
             }
         } else {
             // New entity - insert
             spatial_hash.organisms.insert(entity, current_pos);
             tracker.previous_positions.insert(entity, current_pos);
+            world_grid.update_organism_chunk(entity, current_pos);
         }
     }
 
@@ -246,6 +671,7 @@ pub fn update_spatial_hash(
     tracker.previous_positions.retain(|entity, _| {
         query.get(*entity).is_ok()
     });
+    world_grid.retain_organisms(|entity| query.get(entity).is_ok());
 }
 
 /// Update metabolism - organisms consume energy over time
@@ -258,18 +684,40 @@ pub fn update_metabolism(
         &Velocity,
         &Metabolism,
         &Size,
+        &OrganismType,
+        Option<&Behavior>,
         Option<&CachedTraits>,
+        Option<&mut EnergyBudget>,
+        Option<&Crowding>,
+        &Position,
+        Option<&Age>,
     )>,
     time: Res<Time>,
     tuning: Res<crate::organisms::EcosystemTuning>, // Step 8: Tuning parameters
+    mut trophic: ResMut<crate::organisms::TrophicFlows>,
+    mut world_grid: ResMut<WorldGrid>,
 ) {
     let dt = time.delta_seconds();
     let base_metabolism_mult = tuning.base_metabolism_multiplier;
     let movement_cost_mult = tuning.movement_cost_multiplier;
+    let sensing_cost_mult = tuning.sensing_cost_per_range;
 
     // Step 10: Bevy automatically parallelizes systems, so regular iteration is fine
     // Chunk processing is parallelized separately for better performance
-    for (mut energy, velocity, metabolism, size, traits_opt) in query.iter_mut() {
+    for (
+        mut energy,
+        velocity,
+        metabolism,
+        size,
+        organism_type,
+        behavior,
+        traits_opt,
+        budget,
+        crowding,
+        position,
+        age,
+    ) in query.iter_mut()
+    {
         // Use cached traits if available, otherwise use Metabolism component
         let (base_rate, organism_movement_cost) = if let Some(traits) = traits_opt {
             (traits.metabolism_rate, traits.movement_cost)
@@ -278,7 +726,13 @@ pub fn update_metabolism(
         };
 
         // Step 8: Apply tuning multipliers
-        let effective_base_rate = base_rate * base_metabolism_mult;
+        let mut effective_base_rate = base_rate * base_metabolism_mult;
+        if *organism_type == OrganismType::Decomposer {
+            effective_base_rate *= tuning.decomposer_metabolism_multiplier;
+        }
+        if behavior.is_some_and(|behavior| behavior.state == BehaviorState::Resting) {
+            effective_base_rate *= tuning.resting_metabolism_multiplier;
+        }
         let effective_movement_cost = organism_movement_cost * movement_cost_mult;
 
         // Base metabolic cost (proportional to size)
@@ -288,15 +742,60 @@ pub fn update_metabolism(
         let speed = velocity.0.length();
         let movement_cost = speed * effective_movement_cost * dt;
 
+        // Sensing cost (proportional to sensory range)
+        let sensing_cost = traits_opt.map_or(0.0, |traits| traits.sensory_range)
+            * sensing_cost_mult
+            * dt;
+
+        // Crowding stress (proportional to how far past its threshold the organism is)
+        let crowding_cost =
+            crowding.map_or(0.0, |crowding| crowding.level) * tuning.crowding_energy_penalty * dt;
+
+        // Storage upkeep (fat organisms pay to carry large reserves, lean ones don't)
+        let storage_cost = energy.max * tuning.energy_storage_maintenance * dt;
+
+        // Newborn grace: only movement costs until the organism is old enough to have foraged
+        let upkeep = if age.is_some_and(|age| age.0 < tuning.newborn_grace_ticks) {
+            0.0
+        } else {
+            1.0
+        };
+        let (base_cost, sensing_cost, crowding_cost, storage_cost) = (
+            base_cost * upkeep,
+            sensing_cost * upkeep,
+            crowding_cost * upkeep,
+            storage_cost * upkeep,
+        );
+
         // Total energy consumed
-        let total_cost = base_cost + movement_cost;
+        let total_cost = base_cost + movement_cost + sensing_cost + crowding_cost + storage_cost;
+
+        if let Some(mut budget) = budget {
+            budget.metabolism += base_cost + crowding_cost + storage_cost;
+            budget.movement += movement_cost;
+            budget.sensing += sensing_cost;
+        }
 
         // Deduct energy
+        let previous = energy.current;
         energy.current -= total_cost;
         energy.current = energy.current.max(0.0);
+        let spent = previous - energy.current;
+        trophic.record_metabolism(*organism_type, spent);
+
+        // Part of the throughput leaves as waste, feeding decomposers during life
+        let excreted = crate::organisms::carcass::deposit_detritus(
+            &mut world_grid,
+            position.0,
+            spent * tuning.excretion_fraction,
+        );
+        trophic.record_detritus(excreted);
     }
 }
 
+/// Ticks between `[BEHAVIOR LOD]` reports of skipped behavior updates and time spent deciding
+const BEHAVIOR_LOD_REPORT_INTERVAL: u64 = 600;
+
 /// Update behavior decisions based on sensory input and organism state
 pub fn update_behavior(
     mut query: Query<
@@ -315,19 +814,36 @@ pub fn update_behavior(
     world_grid: Res<WorldGrid>,
     spatial_hash: Res<SpatialHashGrid>,
     organism_query: Query<
-        (Entity, &Position, &SpeciesId, &OrganismType, &Size, &Energy),
+        (Entity, &Position, &SpeciesId, &OrganismType, &Size, &Energy, Option<&Sex>),
         With<Alive>,
     >,
     mut sensory_cache: ResMut<crate::organisms::behavior::SensoryDataCache>, // Add cache
+    carcasses: Res<crate::organisms::Carcasses>,
+    tuning: Res<crate::organisms::EcosystemTuning>,
     time: Res<Time>,
+    logging: Res<LoggingConfig>,
+    tracked: Res<TrackedOrganisms>,
+    mut decision_log: ResMut<crate::organisms::DecisionTraceLog>,
+    uid_query: Query<&OrganismUid>,
+    sim_time: Res<SimTime>,
+    order: Res<DeterministicOrder>,
+    mut lod: ResMut<BehaviorLod>,
 ) {
+    let started = std::time::Instant::now();
     let dt = time.delta_seconds();
+    let mobbing = MobbingRule::from_tuning(&tuning);
+    let occlusion = SensoryOcclusion::from_tuning(&tuning);
+
+    // Organisms that started fleeing this tick: (entity, position, species, threat position)
+    let mut alarms: Vec<(Entity, Vec2, SpeciesId, Vec2)> = Vec::new();
 
     for (entity, position, mut behavior, energy, cached_traits, species_id, organism_type, size) in
         query.iter_mut()
     {
-        // Update state time
+        // Update state time and progress
         behavior.state_time += dt;
+        behavior.track_progress(energy.current, position.0, dt);
+        behavior.age_abandoned_target(dt);
 
         // Settle migration target if already reached
         if let Some(target) = behavior.migration_target {
@@ -343,11 +859,38 @@ pub fn update_behavior(
             .min(2.0);
         behavior.hunger_memory *= (1.0 - dt * 0.25).max(0.65);
 
+        // Far from the camera, keep the last decision between staggered updates
+        let uid = uid_query.get(entity).map_or(entity.index() as u64, |uid| uid.0);
+        let updates =
+            tracked.contains(entity) || lod.updates(&tuning, position.0, uid, sim_time.tick);
+        lod.record(updates);
+        if !updates {
+            // No new sighting without sensing, but the old one still fades
+            behavior.decay_threat(cached_traits.threat_decay_rate, dt);
+            continue;
+        }
+
         // Get sensory range from cached traits
         let sensory_range = cached_traits.sensory_range;
+        let sex = organism_query
+            .get(entity)
+            .ok()
+            .and_then(|(_, _, _, _, _, _, sex)| sex.copied());
+
+        // A food cell grazed down since it was sensed isn't worth the trip: drop the stale
+        // sensory data so the decision picks from what's actually there now
+        let depleted_target = depleted_food_target(
+            &behavior,
+            *organism_type,
+            &world_grid,
+            tuning.chase_retarget_threshold,
+        );
+        if depleted_target.is_some() {
+            sensory_cache.invalidate(entity);
+        }
 
         // Collect sensory data using cache (optimization 3)
-        let sensory = sensory_cache.get_or_compute(
+        let mut sensory = sensory_cache.get_or_compute(
             entity,
             position.0,
             sensory_range,
@@ -358,24 +901,52 @@ pub fn update_behavior(
                 *species_id,
                 *organism_type,
                 size.value(),
+                sex,
                 &world_grid,
                 &spatial_hash.organisms,
                 &organism_query,
+                occlusion,
+                tuning.intraspecific_predation,
+                ResourceDetection::for_organism(&tuning, cached_traits.perception_acuity),
             )
         );
 
+        if let Some(target) = depleted_target {
+            sensory.forget_resource(target);
+            if tracked.contains(entity) {
+                info!(
+                    "[TRACKED] Food target ({:.1}, {:.1}) depleted, re-targeting",
+                    target.x, target.y
+                );
+            }
+        }
+
+        // Carcasses change every tick, so they're sensed outside the cache
+        if *organism_type == OrganismType::Decomposer {
+            sensory.nearest_carcass = carcasses.freshest_near(
+                position.0,
+                sensory_range,
+                tuning.carcass_freshness_window,
+            );
+        }
+
         if let Some((_, threat_pos, _)) = sensory.nearest_predator {
             behavior.threat_timer =
                 (behavior.threat_timer + cached_traits.threat_decay_rate).min(10.0);
             behavior.recent_threat = Some(threat_pos);
         } else {
-            behavior.threat_timer =
-                (behavior.threat_timer - dt * cached_traits.threat_decay_rate).max(0.0);
-            if behavior.threat_timer <= 0.0 {
-                behavior.recent_threat = None;
-            }
+            behavior.decay_threat(cached_traits.threat_decay_rate, dt);
         }
 
+        // Tracked organisms can record why they decided what they did
+        let mut trace = (logging.decision_trace_enabled && tracked.contains(entity))
+            .then(DecisionTrace::default);
+
+        // A stalled state that gets given up on leaves its target off-limits for a while
+        let stalled_target = behavior
+            .is_stalled()
+            .then(|| behavior.current_target(position.0));
+
         // Make behavior decision using cached traits
         let decision = decide_behavior_with_memory(
             energy,
@@ -384,16 +955,46 @@ pub fn update_behavior(
             &sensory,
             behavior.state,
             behavior.state_time,
+            behavior.stalled_time,
             behavior.hunger_memory,
             behavior.threat_timer,
             behavior.recent_threat,
             behavior.migration_target.is_some(),
+            position.0,
+            behavior.abandoned_target(),
+            mobbing,
+            trace.as_mut(),
         );
 
+        if let Some(trace) = &trace {
+            decision_log.record(
+                sim_time.tick,
+                uid_query.get(entity).ok().copied(),
+                behavior.state,
+                decision.state,
+                energy.ratio(),
+                behavior.threat_timer,
+                behavior.stalled_time,
+                trace,
+            );
+        }
+
         // Update behavior state and targets
+        let was_fleeing = behavior.state == BehaviorState::Fleeing;
         behavior.set_state(decision.state);
         behavior.target_entity = decision.target_entity;
         behavior.target_position = decision.target_position;
+        if let Some(target) = stalled_target {
+            if behavior.state == BehaviorState::Wandering {
+                behavior.abandon_target(target);
+            }
+        }
+
+        if !was_fleeing && behavior.state == BehaviorState::Fleeing {
+            if let Some(threat_pos) = behavior.target_position.or(behavior.recent_threat) {
+                alarms.push((entity, position.0, *species_id, threat_pos));
+            }
+        }
 
         if matches!(behavior.state, BehaviorState::Migrating) {
             if let Some(target) = decision
@@ -405,6 +1006,83 @@ pub fn update_behavior(
             }
         }
     }
+
+    // Earlier alarms claim shared neighbors first
+    order.sort(&mut alarms, &uid_query, |(entity, _, _, _)| *entity);
+    propagate_alarms(&mut query, &spatial_hash, &tuning, &alarms);
+
+    lod.record_time(started.elapsed());
+    // Reported with LOD off too, as the baseline to compare skipped-update timings against
+    if sim_time.every(BEHAVIOR_LOD_REPORT_INTERVAL) {
+        let (skipped_share, mean_ms) = lod.take_report();
+        info!(
+            "[BEHAVIOR LOD] Skipped {:.1}% of behavior updates over the last {} ticks | {:.3} ms per tick in update_behavior",
+            skipped_share * 100.0,
+            BEHAVIOR_LOD_REPORT_INTERVAL,
+            mean_ms
+        );
+    }
+}
+
+/// Share danger with same-species neighbors of organisms that just started fleeing.
+/// Each organism is alarmed at most once per tick; the seeded threat memory then decays
+/// normally, so a stampede only spreads as far as neighbors keep choosing to flee.
+fn propagate_alarms(
+    query: &mut Query<
+        (
+            Entity,
+            &Position,
+            &mut Behavior,
+            &Energy,
+            &CachedTraits,
+            &SpeciesId,
+            &OrganismType,
+            &Size,
+        ),
+        With<Alive>,
+    >,
+    spatial_hash: &SpatialHashGrid,
+    tuning: &crate::organisms::EcosystemTuning,
+    alarms: &[(Entity, Vec2, SpeciesId, Vec2)],
+) {
+    if alarms.is_empty() {
+        return;
+    }
+
+    let alarm_radius_sq = tuning.alarm_radius * tuning.alarm_radius;
+    let mut alarmed: std::collections::HashSet<Entity> =
+        alarms.iter().map(|(entity, _, _, _)| *entity).collect();
+
+    for (source, source_pos, source_species, threat_pos) in alarms {
+        for neighbor in spatial_hash
+            .organisms
+            .query_radius(*source_pos, tuning.alarm_radius)
+        {
+            if neighbor == *source || alarmed.contains(&neighbor) {
+                continue;
+            }
+
+            if let Ok((_, position, mut behavior, _, cached_traits, species_id, _, _)) =
+                query.get_mut(neighbor)
+            {
+                if *species_id != *source_species {
+                    continue;
+                }
+                if (position.0 - *source_pos).length_squared() > alarm_radius_sq {
+                    continue;
+                }
+
+                let boost = tuning.alarm_threat_boost * cached_traits.social_sensitivity;
+                if boost <= 0.0 {
+                    continue;
+                }
+
+                behavior.threat_timer = behavior.threat_timer.max(boost).min(10.0);
+                behavior.recent_threat = Some(*threat_pos);
+                alarmed.insert(neighbor);
+            }
+        }
+    }
 }
 
 /// Update organism movement based on behavior state
@@ -413,22 +1091,40 @@ pub fn update_movement(
         (
             &mut Position,
             &mut Velocity,
-            &Behavior,
+            &mut Behavior,
             &Energy,
             &CachedTraits,
             &OrganismType,
+            &mut RngSeed,
             Entity,
+            Option<&Health>,
+            &SpeciesId,
         ),
         With<Alive>,
     >,
     time: Res<Time>,
-    tracked: ResMut<TrackedOrganism>,
+    tracked: Res<TrackedOrganisms>,
+    tuning: Res<crate::organisms::EcosystemTuning>,
+    world_grid: Res<WorldGrid>,
+    scent_marks: Res<crate::organisms::ScentMarks>,
+    parental_care: Res<crate::organisms::ParentalCare>,
+    edges: Res<crate::world::WorldEdges>,
 ) {
     let dt = time.delta_seconds();
-    let time_elapsed = time.elapsed_seconds();
-
-    for (mut position, mut velocity, behavior, energy, cached_traits, organism_type, entity) in
-        query.iter_mut()
+    let thermotaxis = Thermotaxis::from_tuning(&tuning);
+
+    for (
+        mut position,
+        mut velocity,
+        mut behavior,
+        energy,
+        cached_traits,
+        organism_type,
+        mut rng_seed,
+        entity,
+        health,
+        species,
+    ) in query.iter_mut()
     {
         // Skip if dead
         if energy.is_dead() {
@@ -436,15 +1132,35 @@ pub fn update_movement(
             continue;
         }
 
+        // Per-organism heading drift, so wanderers don't all turn in unison
+        behavior.advance_wander_heading(rng_seed.rng(), tuning.wander_jitter, dt);
+
         // Calculate velocity based on behavior state using cached traits
-        let desired_velocity = calculate_behavior_velocity(
-            behavior,
+        let impassable = tuning.impassable_terrain(*organism_type);
+        let mut desired_velocity = calculate_behavior_velocity(
+            &behavior,
             position.0,
             cached_traits,
             *organism_type,
             energy,
-            time_elapsed,
+            &world_grid,
+            impassable,
+            thermotaxis,
+            scent_marks.avoidance(position.0, species.value(), &tuning),
         );
+        if *organism_type == OrganismType::Decomposer {
+            desired_velocity *= tuning.decomposer_speed_multiplier;
+        }
+        // Wounds slow organisms down until they heal
+        if let Some(health) = health {
+            let penalty = tuning.wound_speed_penalty.clamp(0.0, 1.0) * (1.0 - health.ratio());
+            desired_velocity *= 1.0 - penalty;
+        }
+        // Tending parents keep close to their brood unless they're running for their lives
+        if behavior.state != BehaviorState::Fleeing {
+            desired_velocity =
+                parental_care.tether(entity, position.0, desired_velocity, cached_traits, &tuning);
+        }
 
         // Smooth velocity transitions (lerp for smoother movement)
         let lerp_factor = 0.3; // How quickly velocity changes
@@ -455,15 +1171,28 @@ pub fn update_movement(
             velocity.0 *= 0.98;
         }
 
-        // Update position
-        position.0 += velocity.0 * dt;
+        // Update position, never stepping into impassable terrain (slide along it if possible)
+        let step = velocity.0 * dt;
+        let current_cell = position.0.floor();
+        let blocked = |target: Vec2| {
+            target.floor() != current_cell && is_impassable(&world_grid, target, impassable)
+        };
+        if !blocked(position.0 + step) {
+            position.0 += step;
+        } else if !blocked(position.0 + Vec2::new(step.x, 0.0)) {
+            position.0.x += step.x;
+            velocity.0.y = 0.0;
+        } else if !blocked(position.0 + Vec2::new(0.0, step.y)) {
+            position.0.y += step.y;
+            velocity.0.x = 0.0;
+        } else {
+            velocity.0 = Vec2::ZERO;
+        }
 
-        // Simple boundary checking (keep organisms within reasonable bounds)
-        let max_pos = 200.0;
-        position.0.x = position.0.x.clamp(-max_pos, max_pos);
-        position.0.y = position.0.y.clamp(-max_pos, max_pos);
+        // Keep organisms within the world square (stopping at or wrapping around its edges)
+        position.0 = edges.confine(position.0);
 
-        if tracked.entity == Some(entity) && behavior.state_time < dt * 2.0 {
+        if tracked.contains(entity) && behavior.state_time < dt * 2.0 {
             // Log behavior changes
             info!(
                 "[TRACKED] Behavior: {:?}, Velocity: ({:.2}, {:.2}), Speed: {:.2}",
@@ -486,6 +1215,8 @@ pub fn handle_eating(
             &Behavior,
             &OrganismType,
             &Size,
+            Option<&mut EnergyBudget>,
+            Option<&mut Stomach>,
         ),
         With<Alive>,
     >,
@@ -493,95 +1224,381 @@ pub fn handle_eating(
     tuning: Res<crate::organisms::EcosystemTuning>, // Step 8: Tuning parameters
     _organism_query: Query<(&Position, &mut Energy, &Size), (With<Alive>, Without<Behavior>)>,
     time: Res<Time>,
+    mut trophic: ResMut<crate::organisms::TrophicFlows>,
+    order: Res<DeterministicOrder>,
+    uid_query: Query<&OrganismUid>,
 ) {
     let dt = time.delta_seconds();
     let consumption_rate = tuning.consumption_rate_base;
     let energy_conversion_efficiency = tuning.energy_conversion_efficiency;
 
-    for (_entity, position, mut energy, behavior, organism_type, _size) in query.iter_mut() {
+    // Plan every meal before anything is eaten, so eaters contesting a cell can share it
+    let mut eaters: Vec<_> = query.iter_mut().collect();
+    order.sort(&mut eaters, &uid_query, |eater| eater.0);
+
+    let mut meals = Vec::with_capacity(eaters.len());
+    for (_entity, position, energy, behavior, organism_type, size, budget, stomach) in
+        eaters.iter_mut()
+    {
+        // Digestion runs every tick, whatever the organism is doing
+        if let Some(stomach) = stomach.as_deref_mut() {
+            let room = (energy.max - energy.current).max(0.0);
+            let digested = stomach.digest((stomach.capacity * tuning.digestion_rate * dt).min(room));
+            energy.current += digested;
+            if let Some(budget) = budget.as_deref_mut() {
+                budget.eaten += digested;
+            }
+        }
+
         if behavior.state != BehaviorState::Eating {
+            meals.push(None);
             continue;
         }
 
-        // Get current cell
-        if let Some(cell) = world_grid.get_cell_mut(position.x(), position.y()) {
-            let consumed = match organism_type {
-                OrganismType::Producer => {
-                    // Producers consume sunlight, water, minerals
-                    let sunlight = cell
-                        .get_resource(ResourceType::Sunlight)
-                        .min(consumption_rate * dt);
-                    let water = cell
-                        .get_resource(ResourceType::Water)
-                        .min(consumption_rate * dt * 0.5);
-                    let mineral = cell
-                        .get_resource(ResourceType::Mineral)
-                        .min(consumption_rate * dt * 0.2);
-
-                    cell.set_resource(
-                        ResourceType::Sunlight,
-                        cell.get_resource(ResourceType::Sunlight) - sunlight,
-                    );
-                    cell.set_resource(
-                        ResourceType::Water,
-                        cell.get_resource(ResourceType::Water) - water,
-                    );
-                    cell.set_resource(
-                        ResourceType::Mineral,
-                        cell.get_resource(ResourceType::Mineral) - mineral,
-                    );
-                    cell.add_pressure(ResourceType::Sunlight, sunlight);
-                    cell.add_pressure(ResourceType::Water, water);
-                    cell.add_pressure(ResourceType::Mineral, mineral);
-
-                    (sunlight + water + mineral) * energy_conversion_efficiency
+        // Intake is throttled so a tick's meal fits in the stomach (full stomachs can't eat)
+        let intake = match stomach.as_deref() {
+            Some(stomach) if stomach.is_full() => {
+                meals.push(None);
+                continue;
+            }
+            Some(stomach) => {
+                let peak_energy_per_unit = match **organism_type {
+                    OrganismType::Producer => tuning.photosynthesis_sunlight_efficiency,
+                    // Plant (1x) and prey (2x) can both be eaten in one tick
+                    OrganismType::Consumer => 3.0 * energy_conversion_efficiency,
+                    OrganismType::Decomposer => {
+                        energy_conversion_efficiency * tuning.decomposer_efficiency_multiplier
+                    }
+                };
+                let peak_meal = consumption_rate * dt * peak_energy_per_unit;
+                if peak_meal > 0.0 {
+                    consumption_rate * (stomach.free_space() / peak_meal).min(1.0)
+                } else {
+                    consumption_rate
                 }
-                OrganismType::Consumer => {
-                    // Consumers consume plants or prey resources
-                    let plant = cell
-                        .get_resource(ResourceType::Plant)
-                        .min(consumption_rate * dt);
-                    let prey_resource = cell
-                        .get_resource(ResourceType::Prey)
-                        .min(consumption_rate * dt);
-
-                    cell.set_resource(
-                        ResourceType::Plant,
-                        cell.get_resource(ResourceType::Plant) - plant,
-                    );
-                    cell.set_resource(
-                        ResourceType::Prey,
-                        cell.get_resource(ResourceType::Prey) - prey_resource,
-                    );
-                    cell.add_pressure(ResourceType::Plant, plant);
-                    cell.add_pressure(ResourceType::Prey, prey_resource);
+            }
+            None => consumption_rate,
+        };
 
-                    (plant + prey_resource * 2.0) * energy_conversion_efficiency
-                    // Prey is more nutritious
-                }
-                OrganismType::Decomposer => {
-                    // Decomposers consume detritus
-                    let detritus = cell
-                        .get_resource(ResourceType::Detritus)
-                        .min(consumption_rate * dt);
-
-                    cell.set_resource(
-                        ResourceType::Detritus,
-                        cell.get_resource(ResourceType::Detritus) - detritus,
-                    );
-                    cell.add_pressure(ResourceType::Detritus, detritus);
+        // Graze every cell within reach (bigger organisms cover more ground)
+        let patch = GrazingPatch::around(position.0, size.value() * tuning.grazing_radius_per_size);
+        let max_take = intake * dt;
+        let demand = match **organism_type {
+            OrganismType::Producer => {
+                // Photosynthesis: sunlight provides the energy, water and minerals gate it
+                let sunlight_available = patch
+                    .available(&world_grid, ResourceType::Sunlight)
+                    .min(max_take);
+                let water_needed = sunlight_available * tuning.photosynthesis_water_per_sunlight;
+                let mineral_needed = sunlight_available * tuning.photosynthesis_mineral_per_sunlight;
+
+                // Fraction of the available sunlight the cofactors can support
+                let cofactor_limit = |available: f32, needed: f32| {
+                    if needed > 0.0 {
+                        (available / needed).min(1.0)
+                    } else {
+                        1.0
+                    }
+                };
+                let gate = cofactor_limit(patch.available(&world_grid, ResourceType::Water), water_needed)
+                    .min(cofactor_limit(
+                        patch.available(&world_grid, ResourceType::Mineral),
+                        mineral_needed,
+                    ));
+
+                vec![
+                    (ResourceType::Sunlight, sunlight_available * gate),
+                    (ResourceType::Water, water_needed * gate),
+                    (ResourceType::Mineral, mineral_needed * gate),
+                ]
+            }
+            // Consumers consume plants or prey resources
+            OrganismType::Consumer => vec![
+                (ResourceType::Plant, patch.available(&world_grid, ResourceType::Plant).min(max_take)),
+                (ResourceType::Prey, patch.available(&world_grid, ResourceType::Prey).min(max_take)),
+            ],
+            // Decomposers consume detritus
+            OrganismType::Decomposer => vec![(
+                ResourceType::Detritus,
+                patch.available(&world_grid, ResourceType::Detritus).min(max_take),
+            )],
+        };
+        meals.push(Some(Meal { patch, demand }));
+    }
 
-                    // Step 8: Use tuning parameter for decomposer efficiency
-                    detritus * energy_conversion_efficiency * tuning.decomposer_efficiency_multiplier
-                }
+    // Without fair sharing, organisms sharing a cell graze it down in turn
+    let shares = tuning
+        .fair_cell_sharing
+        .then(|| CellShares::new(&world_grid, meals.iter().flatten()));
+
+    for ((_entity, _position, energy, _behavior, organism_type, _size, budget, stomach), meal) in
+        eaters.iter_mut().zip(meals)
+    {
+        let Some(Meal { patch, mut demand }) = meal else {
+            continue;
+        };
+
+        if let (OrganismType::Producer, Some(shares)) = (**organism_type, shares.as_ref()) {
+            // Sunlight is only useful with its cofactors, so scale the whole reaction down to
+            // the scarcest share
+            let granted = demand
+                .iter()
+                .filter(|(_, amount)| *amount > 0.0)
+                .map(|&(resource_type, amount)| patch.share(shares, resource_type, amount) / amount)
+                .fold(1.0_f32, f32::min);
+            for (_, amount) in demand.iter_mut() {
+                *amount *= granted;
+            }
+        }
+
+        let mut eaten = [0.0; RESOURCE_TYPE_COUNT];
+        for &(resource_type, amount) in &demand {
+            eaten[resource_type as usize] = match shares.as_ref() {
+                Some(shares) => patch.take_share(&mut world_grid, shares, resource_type, amount),
+                None => patch.take(&mut world_grid, resource_type, amount),
             };
+        }
 
+        // Share of a consumer's meal that was animal (Prey) rather than Plant
+        let mut animal_fraction = 0.0;
+        let consumed = match **organism_type {
+            OrganismType::Producer => {
+                eaten[ResourceType::Sunlight as usize] * tuning.photosynthesis_sunlight_efficiency
+            }
+            OrganismType::Consumer => {
+                let plant = eaten[ResourceType::Plant as usize];
+                let prey_resource = eaten[ResourceType::Prey as usize];
+                if plant + prey_resource > 0.0 {
+                    animal_fraction = prey_resource * 2.0 / (plant + prey_resource * 2.0);
+                }
+
+                (plant + prey_resource * 2.0) * energy_conversion_efficiency
+                // Prey is more nutritious
+            }
+            OrganismType::Decomposer => {
+                // Step 8: Use tuning parameter for decomposer efficiency
+                eaten[ResourceType::Detritus as usize]
+                    * energy_conversion_efficiency
+                    * tuning.decomposer_efficiency_multiplier
+            }
+        };
+
+        let taken_in = if let Some(stomach) = stomach.as_deref_mut() {
+            // Food is digested into energy over the following ticks
+            consumed - stomach.fill(consumed)
+        } else {
             // Add energy (clamped to max)
+            let previous = energy.current;
             energy.current = (energy.current + consumed).min(energy.max);
+            if let Some(budget) = budget.as_deref_mut() {
+                budget.eaten += energy.current - previous;
+            }
+            energy.current - previous
+        };
+        trophic.record_intake(**organism_type, taken_in, animal_fraction);
+    }
+}
+
+/// One organism's planned meal: where it grazes and how much of each resource it wants
+struct Meal {
+    patch: GrazingPatch,
+    demand: Vec<(ResourceType, f32)>,
+}
+
+/// Cell key for a resource: (cell x, cell y, resource index)
+type CellResource = (i32, i32, usize);
+
+fn cell_resource(position: Vec2, resource_type: ResourceType) -> CellResource {
+    (position.x.floor() as i32, position.y.floor() as i32, resource_type as usize)
+}
+
+/// Total demand on every contested cell this tick, for fair sharing
+/// Each eater asks a cell for a fraction of its stock (its patch-wide demand over the
+/// patch-wide supply). When the fractions on a cell add up to more than the whole stock,
+/// everyone gets the same proportion of what they asked for, so what a cell gives up never
+/// exceeds what it had and doesn't depend on who eats first.
+struct CellShares {
+    /// (stock at the start of the tick, sum of the fractions of it requested)
+    demand: HashMap<CellResource, (f32, f32)>,
+}
+
+impl CellShares {
+    fn new<'a>(world_grid: &WorldGrid, meals: impl Iterator<Item = &'a Meal>) -> Self {
+        let mut demand: HashMap<CellResource, (f32, f32)> = HashMap::new();
+        for meal in meals {
+            for &(resource_type, amount) in &meal.demand {
+                let fraction = meal.patch.fraction(world_grid, resource_type, amount);
+                if fraction <= 0.0 {
+                    continue;
+                }
+                for position in &meal.patch.cells {
+                    if let Some(cell) = world_grid.get_cell(position.x, position.y) {
+                        let entry = demand
+                            .entry(cell_resource(*position, resource_type))
+                            .or_insert((cell.get_resource(resource_type), 0.0));
+                        entry.1 += fraction;
+                    }
+                }
+            }
         }
+        Self { demand }
+    }
+
+    /// Amount of a cell's resource granted to an eater asking for `fraction` of it
+    fn grant(&self, position: Vec2, resource_type: ResourceType, fraction: f32) -> f32 {
+        self.demand
+            .get(&cell_resource(position, resource_type))
+            .map_or(0.0, |&(stock, requested)| stock * fraction / requested.max(1.0))
     }
 }
 
+/// Cells an organism eats from in one tick: every cell whose center is within the grazing
+/// radius (the cell under the organism is always included)
+struct GrazingPatch {
+    cells: Vec<Vec2>,
+}
+
+impl GrazingPatch {
+    fn around(center: Vec2, radius: f32) -> Self {
+        let reach = radius.max(0.0).floor() as i32;
+        let radius_sq = radius * radius;
+        let mut cells = Vec::with_capacity(((2 * reach + 1) * (2 * reach + 1)) as usize);
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                if (dx * dx + dy * dy) as f32 <= radius_sq || (dx == 0 && dy == 0) {
+                    cells.push(center + Vec2::new(dx as f32, dy as f32));
+                }
+            }
+        }
+        Self { cells }
+    }
+
+    /// Total amount of a resource across the patch
+    fn available(&self, world_grid: &WorldGrid, resource_type: ResourceType) -> f32 {
+        self.cells
+            .iter()
+            .filter_map(|cell| world_grid.get_cell(cell.x, cell.y))
+            .map(|cell| cell.get_resource(resource_type))
+            .sum()
+    }
+
+    /// Fraction of every cell's stock that `amount` of a resource takes from the patch
+    fn fraction(&self, world_grid: &WorldGrid, resource_type: ResourceType, amount: f32) -> f32 {
+        let available = self.available(world_grid, resource_type);
+        if amount <= 0.0 || available <= 0.0 {
+            return 0.0;
+        }
+        (amount / available).min(1.0)
+    }
+
+    /// Amount of a resource the patch would yield for `amount` under fair sharing
+    fn share(&self, shares: &CellShares, resource_type: ResourceType, amount: f32) -> f32 {
+        let fraction = self.fraction_of_start(shares, resource_type, amount);
+        self.cells
+            .iter()
+            .map(|position| shares.grant(*position, resource_type, fraction))
+            .sum()
+    }
+
+    /// Remove `amount` of a resource, drawn from each cell in proportion to its stock,
+    /// adding consumption pressure to every touched cell. Returns the amount removed.
+    fn take(&self, world_grid: &mut WorldGrid, resource_type: ResourceType, amount: f32) -> f32 {
+        let fraction = self.fraction(world_grid, resource_type, amount);
+        self.remove(world_grid, resource_type, |_, stock| stock * fraction)
+    }
+
+    /// Like `take`, but each cell only gives up this eater's share of its contested stock
+    fn take_share(
+        &self,
+        world_grid: &mut WorldGrid,
+        shares: &CellShares,
+        resource_type: ResourceType,
+        amount: f32,
+    ) -> f32 {
+        // Fractions are measured against the start-of-tick stock the shares were computed from
+        let fraction = self.fraction_of_start(shares, resource_type, amount);
+        self.remove(world_grid, resource_type, |position, _| {
+            shares.grant(position, resource_type, fraction)
+        })
+    }
+
+    /// `fraction`, measured against the stock recorded in `shares`
+    fn fraction_of_start(&self, shares: &CellShares, resource_type: ResourceType, amount: f32) -> f32 {
+        let available: f32 = self
+            .cells
+            .iter()
+            .filter_map(|position| shares.demand.get(&cell_resource(*position, resource_type)))
+            .map(|&(stock, _)| stock)
+            .sum();
+        if amount <= 0.0 || available <= 0.0 {
+            return 0.0;
+        }
+        (amount / available).min(1.0)
+    }
+
+    /// Remove `amount(position, stock)` of a resource from every cell (never more than the
+    /// cell holds), adding consumption pressure. Returns the amount removed.
+    fn remove(
+        &self,
+        world_grid: &mut WorldGrid,
+        resource_type: ResourceType,
+        amount: impl Fn(Vec2, f32) -> f32,
+    ) -> f32 {
+        let mut taken = 0.0;
+        for position in &self.cells {
+            // Only existing cells hold resources - don't create chunks just to look
+            if world_grid.get_cell(position.x, position.y).is_none() {
+                continue;
+            }
+            if let Some(cell) = world_grid.get_cell_mut(position.x, position.y) {
+                let stock = cell.get_resource(resource_type);
+                let take = amount(*position, stock).min(stock);
+                if take > 0.0 {
+                    cell.set_resource(resource_type, stock - take);
+                    cell.add_pressure(resource_type, take);
+                    taken += take;
+                }
+            }
+        }
+        taken
+    }
+}
+
+/// Recompute cached traits for organisms whose genome changed (see `TraitsDirty`)
+pub fn refresh_dirty_traits(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Genome, &mut CachedTraits), With<TraitsDirty>>,
+) {
+    for (entity, genome, mut cached_traits) in query.iter_mut() {
+        *cached_traits = CachedTraits::from_genome(genome);
+        commands.entity(entity).remove::<TraitsDirty>();
+    }
+}
+
+/// Confirm mating pairs from candidate `(organism, partner, distance²)` links, closest first
+/// Each organism joins at most one pair, stored as (lower entity, higher entity). Ties go to
+/// the lower entities, so the pairing doesn't depend on query order.
+fn pair_closest_mates(mut candidates: Vec<(Entity, Entity, f32)>) -> Vec<(Entity, Entity)> {
+    for (a, b, _) in candidates.iter_mut() {
+        if *a > *b {
+            std::mem::swap(a, b);
+        }
+    }
+    candidates.sort_by(|x, y| x.2.total_cmp(&y.2).then(x.0.cmp(&y.0)).then(x.1.cmp(&y.1)));
+
+    let mut paired = std::collections::HashSet::new();
+    let mut pairs = Vec::new();
+    for (a, b, _) in candidates {
+        if paired.contains(&a) || paired.contains(&b) {
+            continue;
+        }
+        paired.insert(a);
+        paired.insert(b);
+        pairs.push((a, b));
+    }
+    pairs
+}
+
 /// Update organism age and reproduction cooldown
 /// Step 10: Bevy automatically parallelizes systems at the scheduler level
 pub fn update_age(mut query: Query<(&mut Age, &mut ReproductionCooldown)>) {
@@ -592,7 +1609,18 @@ pub fn update_age(mut query: Query<(&mut Age, &mut ReproductionCooldown)>) {
     }
 }
 
-/// Handle reproduction - both asexual and sexual (Step 8: Uses speciation system)
+/// In-progress mating handshakes, keyed by (lower entity, higher entity)
+/// A pair must be confirmed on consecutive ticks; pairs that aren't (a partner died,
+/// fled, or wandered off) are dropped.
+#[derive(Resource, Default)]
+pub struct MatingHandshakes {
+    pending: HashMap<(Entity, Entity), u32>,
+}
+
+/// Ticks both partners must spend mating in contact before a clutch is produced
+const MATING_HANDSHAKE_TICKS: u32 = 2;
+
+/// Handle reproduction - asexual, or sexual through a mating handshake (Step 8: Uses speciation system)
 pub fn handle_reproduction(
     mut commands: Commands,
     mut query: Query<
@@ -605,193 +1633,499 @@ pub fn handle_reproduction(
             &CachedTraits,
             &SpeciesId,
             &OrganismType,
+            &mut RngSeed,
+            &Behavior,
         ),
         With<Alive>,
     >,
     mut species_tracker: ResMut<crate::organisms::speciation::SpeciesTracker>, // Step 8: Speciation
     tuning: Res<crate::organisms::EcosystemTuning>, // Step 8: Tuning parameters
-    spatial_hash: Res<SpatialHashGrid>,
-    organism_query: Query<(Entity, &Position, &Genome, &SpeciesId, &CachedTraits), With<Alive>>,
+    mut handshakes: ResMut<MatingHandshakes>,
+    mut uids: ResMut<OrganismUids>,
+    mut budget_query: Query<&mut EnergyBudget>,
+    sex_query: Query<&Sex>,
+    crowding_query: Query<&Crowding>,
+    world_grid: Res<WorldGrid>,
+    order: Res<DeterministicOrder>,
+    uid_query: Query<&OrganismUid>,
+    stress_query: Query<&Stress>,
+    (mut mutation_log, tracked, logging): (
+        ResMut<crate::organisms::MutationLog>,
+        Res<TrackedOrganisms>,
+        Res<LoggingConfig>,
+    ),
+    sim_time: Res<SimTime>,
 ) {
     struct PendingSpawn {
-        parent: Entity,
+        /// Parents paying the clutch energy and cooldown
+        parents: Vec<Entity>,
+        sexual: bool,
         position: Vec2,
-        genomes: Vec<Genome>,
+        genomes: Vec<(Genome, RngSeed, MutationSpectrum)>,
         species_id: SpeciesId,
         organism_type: OrganismType,
-        energy_share: f32,
+        /// Where the genomes came from, for the mutation log
+        mutation: MutationContext,
+        /// Whether either genetic parent is tracked (its offspring are always mutation-logged)
+        tracked_parent: bool,
     }
+    let uid_of = |entity: Entity| uid_query.get(entity).map_or(0, |uid| uid.0);
 
-    let mut rng = fastrand::Rng::new();
     let mut reproduction_events: Vec<PendingSpawn> = Vec::new();
-
-    for (entity, position, energy, cooldown, genome, cached_traits, species_id, org_type) in
+    let mutation_operators = tuning.mutation_operators();
+    let contact_distance_sq = tuning.mating_contact_distance * tuning.mating_contact_distance;
+
+    let is_ready = |energy: &Energy, cooldown: &ReproductionCooldown, traits: &CachedTraits| {
+        cooldown.is_ready() && energy.ratio() >= traits.reproduction_threshold
+    };
+    // Crowded organisms reproduce less often (1.0 = no suppression)
+    let crowding_factor = |entity: Entity| {
+        crowding_query.get(entity).map_or(1.0, |crowding| {
+            crowding.reproduction_factor(tuning.crowding_reproduction_suppression)
+        })
+    };
+
+    // Stressed parents pass on an elevated mutation rate (logged so its effects can be traced)
+    let stressed_mutation_rate = |entity: Entity, base_rate: f32| {
+        let Ok(stress) = stress_query.get(entity) else {
+            return base_rate;
+        };
+        let rate = stress.mutation_rate(base_rate, tuning.stress_mutagenesis_strength);
+        if rate > base_rate {
+            info!(
+                "[MUTAGENESIS] Organism {} (stress {:.2}) passes on mutation rate {:.4} (genome: {:.4})",
+                uid_query.get(entity).map_or(entity.index() as u64, |uid| uid.0),
+                stress.level,
+                rate,
+                base_rate
+            );
+        }
+        rate
+    };
+
+    // Candidate pairs: an organism and the partner it targets, both Mating, in contact and ready
+    // (targets come from `update_behavior`, which picks the nearest mate; several organisms
+    // may target the same partner, so the closest pairs are confirmed first)
+    let mut candidates: Vec<(Entity, Entity, f32)> = Vec::new();
+    for (entity, position, energy, cooldown, _, cached_traits, species_id, _, _, behavior) in
         query.iter()
     {
-        if !cooldown.is_ready() {
+        if behavior.state != BehaviorState::Mating {
             continue;
         }
-
-        if energy.ratio() < cached_traits.reproduction_threshold {
+        let Some(partner) = behavior.target_entity else {
+            continue;
+        };
+        if entity == partner || !is_ready(energy, cooldown, cached_traits) {
             continue;
         }
 
-        // Use tuning parameter for reproduction chance
-        if rng.f32() >= tuning.reproduction_chance_multiplier {
+        let Ok((_, partner_pos, partner_energy, partner_cooldown, _, partner_traits, partner_species, _, _, partner_behavior)) =
+            query.get(partner)
+        else {
+            continue;
+        };
+
+        let distance_sq = (position.0 - partner_pos.0).length_squared();
+        if partner_behavior.state != BehaviorState::Mating
+            || *partner_species != *species_id
+            || !is_ready(partner_energy, partner_cooldown, partner_traits)
+            || distance_sq > contact_distance_sq
+            || !Sex::compatible(
+                sex_query.get(entity).ok().copied(),
+                sex_query.get(partner).ok().copied(),
+            )
+        {
             continue;
         }
+        candidates.push((entity, partner, distance_sq));
+    }
+    let confirmed_pairs = pair_closest_mates(candidates);
+    let paired: std::collections::HashSet<Entity> =
+        confirmed_pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+
+    // Pairs that weren't re-confirmed this tick are abandoned
+    handshakes
+        .pending
+        .retain(|pair, _| confirmed_pairs.contains(pair));
+
+    let mut completed_pairs = Vec::new();
+    for pair in confirmed_pairs {
+        let ticks = handshakes.pending.entry(pair).or_insert(0);
+        *ticks += 1;
+        if *ticks >= MATING_HANDSHAKE_TICKS {
+            handshakes.pending.remove(&pair);
+            completed_pairs.push(pair);
+        }
+    }
 
-        let clutch_size = cached_traits.clutch_size.max(1.0).round().clamp(1.0, 6.0) as usize;
-        if clutch_size == 0 {
+    for (parent_a, parent_b) in completed_pairs {
+        let Ok((_, _, _, _, mate_genome, mate_traits, _, _, _, _)) = query.get(parent_b) else {
+            continue;
+        };
+        let mate_genome = mate_genome.clone();
+        let mate_mut_rate = mate_traits.mutation_rate.clamp(0.001, 0.08);
+        let mate_clutch = mate_traits.clutch_size;
+
+        let Ok((_, position, _, _, genome, cached_traits, species_id, org_type, mut rng_seed, _)) =
+            query.get_mut(parent_a)
+        else {
+            continue;
+        };
+
+        // The more crowded partner decides whether the clutch is laid
+        let pair_crowding_factor = crowding_factor(parent_a).min(crowding_factor(parent_b));
+        if pair_crowding_factor < 1.0 && rng_seed.rng().f32() >= pair_crowding_factor {
             continue;
         }
 
-        let parent_mutation_rate = cached_traits.mutation_rate.clamp(0.001, 0.08);
-        let use_sexual = rng.f32() < 0.35;
+        // Clutch size is the average of both partners' clutch traits
+        let clutch_size = ((cached_traits.clutch_size + mate_clutch) * 0.5)
+            .max(1.0)
+            .round()
+            .clamp(1.0, 6.0) as usize;
+        let parent_mutation_rate =
+            stressed_mutation_rate(parent_a, cached_traits.mutation_rate.clamp(0.001, 0.08));
+        let mate_mut_rate = stressed_mutation_rate(parent_b, mate_mut_rate);
+        let crossover_rate = ((parent_mutation_rate + mate_mut_rate) * 0.5)
+            .clamp(0.001, MAX_STRESSED_MUTATION_RATE);
 
-        let mut mate_data: Option<(Genome, f32)> = None;
+        let mut offspring_genomes = Vec::with_capacity(clutch_size);
+        for _ in 0..clutch_size {
+            let mut child_seed = rng_seed.derive_child();
+            let (child_genome, spectrum) = Genome::crossover_with_operators(
+                genome,
+                &mate_genome,
+                crossover_rate,
+                &mutation_operators,
+                child_seed.rng(),
+            );
+            offspring_genomes.push((child_genome, child_seed, spectrum));
+        }
 
-        if use_sexual {
-            let sensory_range = cached_traits.sensory_range;
-            let nearby_entities = spatial_hash
-                .organisms
-                .query_radius(position.0, sensory_range);
+        // With sexes enabled the female carries the clutch; hermaphrodites share the cost
+        let parents = match (sex_query.get(parent_a), sex_query.get(parent_b)) {
+            (Ok(Sex::Female), _) => vec![parent_a],
+            (_, Ok(Sex::Female)) => vec![parent_b],
+            _ => vec![parent_a, parent_b],
+        };
 
-            for other_entity in nearby_entities {
-                if other_entity == entity {
-                    continue;
-                }
+        reproduction_events.push(PendingSpawn {
+            parents,
+            sexual: true,
+            position: position.0,
+            genomes: offspring_genomes,
+            species_id: *species_id,
+            organism_type: *org_type,
+            mutation: MutationContext {
+                parent_uid: uid_of(parent_a),
+                mate_uid: Some(uid_of(parent_b)),
+                parent_mutation_rate: cached_traits.mutation_rate,
+                applied_mutation_rate: crossover_rate,
+            },
+            tracked_parent: tracked.contains(parent_a) || tracked.contains(parent_b),
+        });
+    }
 
-                if let Ok((_, other_pos, other_genome, other_species, other_traits)) =
-                    organism_query.get(other_entity)
-                {
-                    if *other_species != *species_id {
-                        continue;
-                    }
+    // Asexual reproduction for organisms that aren't seeking a mate
+    // With sexes enabled only females (and hermaphrodites) lay clutches; males need a mate
+    for (
+        entity,
+        position,
+        energy,
+        cooldown,
+        genome,
+        cached_traits,
+        species_id,
+        org_type,
+        mut rng_seed,
+        behavior,
+    ) in query.iter_mut()
+    {
+        if behavior.state == BehaviorState::Mating || paired.contains(&entity) {
+            continue;
+        }
+        if tuning.sexes_enabled && sex_query.get(entity).is_ok_and(|sex| *sex == Sex::Male) {
+            continue;
+        }
 
-                    let distance = (position.0 - other_pos.0).length();
-                    if distance <= sensory_range {
-                        mate_data = Some((
-                            other_genome.clone(),
-                            other_traits.mutation_rate.clamp(0.001, 0.08),
-                        ));
-                        break;
-                    }
-                }
-            }
+        if !is_ready(&energy, &cooldown, cached_traits) {
+            continue;
+        }
+
+        // Draw from the parent's own stream so results don't depend on iteration order
+        let rng = rng_seed.rng();
+
+        // Use tuning parameter for reproduction chance
+        if rng.f32() >= tuning.reproduction_chance_multiplier * crowding_factor(entity) {
+            continue;
         }
 
+        let clutch_size = cached_traits.clutch_size.max(1.0).round().clamp(1.0, 6.0) as usize;
+        if clutch_size == 0 {
+            continue;
+        }
+
+        let parent_mutation_rate =
+            stressed_mutation_rate(entity, cached_traits.mutation_rate.clamp(0.001, 0.08));
+
         let mut offspring_genomes = Vec::with_capacity(clutch_size);
-        if let Some((mate_genome, mate_mut_rate)) = mate_data.as_ref() {
-            let crossover_rate = ((parent_mutation_rate + mate_mut_rate) * 0.5).clamp(0.001, 0.08);
-            for _ in 0..clutch_size {
-                offspring_genomes.push(Genome::crossover(genome, mate_genome, crossover_rate));
-            }
-        } else {
-            for _ in 0..clutch_size {
-                offspring_genomes.push(genome.clone_with_mutation(parent_mutation_rate));
-            }
+        for _ in 0..clutch_size {
+            let mut child_seed = rng_seed.derive_child();
+            let (child_genome, spectrum) = genome.clone_with_operators(
+                parent_mutation_rate,
+                &mutation_operators,
+                child_seed.rng(),
+            );
+            offspring_genomes.push((child_genome, child_seed, spectrum));
         }
 
         reproduction_events.push(PendingSpawn {
-            parent: entity,
+            parents: vec![entity],
+            sexual: false,
             position: position.0,
             genomes: offspring_genomes,
             species_id: *species_id,
             organism_type: *org_type,
-            energy_share: cached_traits.offspring_energy_share,
+            mutation: MutationContext {
+                parent_uid: uid_of(entity),
+                mate_uid: None,
+                parent_mutation_rate: cached_traits.mutation_rate,
+                applied_mutation_rate: parent_mutation_rate,
+            },
+            tracked_parent: tracked.contains(entity),
         });
     }
 
+    // Offspring uids and new species ids are handed out in event order
+    order.sort(&mut reproduction_events, &uid_query, |event| event.parents[0]);
+
     for event in reproduction_events {
-        if let Ok((_, _, mut parent_energy, mut parent_cooldown, _, parent_traits, _, _)) =
-            query.get_mut(event.parent)
+        let count = event.genomes.len() as f32;
+        if count == 0.0 {
+            continue;
+        }
+
+        // Every parent offers its share; with two parents each covers half of each child
+        let parent_count = event.parents.len() as f32;
+        let offers: Vec<(Entity, f32)> = event
+            .parents
+            .iter()
+            .filter_map(|parent| {
+                let (_, _, parent_energy, _, _, parent_traits, ..) = query.get(*parent).ok()?;
+                let available_energy = parent_energy.current.max(0.0);
+                let contribution = ((available_energy * parent_traits.offspring_energy_share)
+                    .min(available_energy / count)
+                    / parent_count)
+                    .max(0.0);
+                Some((*parent, contribution * count))
+            })
+            .collect();
+        let clutch_budget: f32 = offers.iter().map(|(_, offer)| offer).sum();
+
+        // The strategy decides how the budget is split; parents pay only what the offspring receive
+        let offspring: Vec<(Genome, RngSeed, CachedTraits, MutationSpectrum)> = event
+            .genomes
+            .into_iter()
+            .map(|(genome, seed, spectrum)| {
+                let cached = CachedTraits::from_genome(&genome);
+                (genome, seed, cached, spectrum)
+            })
+            .collect();
+        let offspring_max_energy: Vec<f32> =
+            offspring.iter().map(|(_, _, cached, _)| cached.max_energy).collect();
+        let shares = tuning.clutch_energy_strategy.split(
+            clutch_budget,
+            &offspring_max_energy,
+            tuning.clutch_exponential_ratio,
+            tuning.clutch_fixed_cost_fraction,
+        );
+        let spent: f32 = shares.iter().sum();
+        let paid_fraction = if clutch_budget > 0.0 {
+            (spent / clutch_budget).min(1.0)
+        } else {
+            0.0
+        };
+
+        for (parent, offer) in &offers {
+            if let Ok((_, _, mut parent_energy, mut parent_cooldown, _, parent_traits, ..)) =
+                query.get_mut(*parent)
+            {
+                let available_energy = parent_energy.current.max(0.0);
+                parent_energy.current = (available_energy - offer * paid_fraction).max(0.0);
+                if let Ok(mut budget) = budget_query.get_mut(*parent) {
+                    budget.reproduction += available_energy - parent_energy.current;
+                }
+                parent_cooldown.reset(parent_traits.reproduction_cooldown.max(1.0) as u32);
+            }
+        }
+
+        // The most caring parent tends the brood (if it cares enough to tend at all)
+        let caretaker = event
+            .parents
+            .iter()
+            .filter_map(|parent| {
+                let (_, _, _, _, _, parent_traits, ..) = query.get(*parent).ok()?;
+                Some((*parent, parent_traits.parental_care))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .and_then(|(parent, care)| CaredFor::from_parent(parent, care, &tuning));
+
+        let spawned_count = shares.len() as u32;
+        let mut spawned_species = None;
+        for ((offspring_genome, mut offspring_seed, cached, spectrum), share) in
+            offspring.into_iter().zip(shares)
         {
-            let count = event.genomes.len() as f32;
-            if count == 0.0 {
-                continue;
+            let size = cached.size;
+            let max_energy = cached.max_energy;
+            let metabolism_rate = cached.metabolism_rate;
+            let movement_cost = cached.movement_cost;
+            let reproduction_cooldown = cached.reproduction_cooldown.max(1.0) as u32;
+
+            let rng = offspring_seed.rng();
+            let dispersal_radius = offspring_dispersal_radius(
+                tuning.offspring_dispersal_radius,
+                tuning.dispersal_exploration_weight,
+                cached.exploration_drive,
+            );
+            let spawn_position = disperse_offspring(
+                event.position,
+                dispersal_radius,
+                &world_grid,
+                tuning.impassable_terrain(event.organism_type),
+                rng,
+            );
+            let sex = tuning.sexes_enabled.then(|| Sex::random(rng));
+            let initial_energy = offspring_initial_energy(
+                share,
+                max_energy,
+                tuning.offspring_min_energy_fraction,
+            );
+
+            // Step 8: Assign species ID using speciation system
+            let offspring_species = species_tracker.find_or_create_species(&offspring_genome);
+            if spawned_species.is_none() {
+                spawned_species = Some(offspring_species);
             }
 
-            let available_energy = parent_energy.current.max(0.0);
-            let per_child_energy = (available_energy * event.energy_share)
-                .min(available_energy / count)
-                .max(0.0);
-            let total_energy_cost = per_child_energy * count;
-            parent_energy.current = (available_energy - total_energy_cost).max(0.0);
-
-            let mut spawned_species = None;
-            for offspring_genome in event.genomes {
-                let cached = CachedTraits::from_genome(&offspring_genome);
-                let size = cached.size;
-                let max_energy = cached.max_energy;
-                let metabolism_rate = cached.metabolism_rate;
-                let movement_cost = cached.movement_cost;
-                let reproduction_cooldown = cached.reproduction_cooldown.max(1.0) as u32;
-
-                let offset = Vec2::new(rng.f32() * 10.0 - 5.0, rng.f32() * 10.0 - 5.0);
-                let initial_energy = (per_child_energy * 0.9)
-                    .min(max_energy)
-                    .max(max_energy * 0.15);
-
-                // Step 8: Assign species ID using speciation system
-                let offspring_species = species_tracker.find_or_create_species(&offspring_genome);
-                if spawned_species.is_none() {
-                    spawned_species = Some(offspring_species);
-                }
-                
-                commands.spawn((
-                    Position::new(event.position.x + offset.x, event.position.y + offset.y),
-                    Velocity::new(0.0, 0.0),
-                    Energy::with_energy(max_energy, initial_energy),
-                    Age::new(),
-                    Size::new(size),
-                    Metabolism::new(metabolism_rate, movement_cost),
-                    ReproductionCooldown::new(reproduction_cooldown),
-                    offspring_genome,
-                    cached,
-                    offspring_species, // Step 8: Use speciation-assigned species ID
-                    event.organism_type,
-                    Behavior::new(),
-                    Alive,
-                ));
+            let uid = uids.allocate();
+            if mutation_log.sample(&logging, event.tracked_parent) {
+                mutation_log.record(sim_time.tick, uid.0, &event.mutation, &spectrum);
             }
 
-            parent_cooldown.reset(parent_traits.reproduction_cooldown.max(1.0) as u32);
-            
-            // Step 8: Log species information on reproduction
-            if let Some(species) = spawned_species {
-                let species_count = species_tracker.species_count();
-                if count as u32 % 10 == 0 || species_count <= 5 {
-                    // Log every 10th reproduction or when few species exist
-                    info!(
-                        "[REPRODUCTION] Spawned {} offspring | Species: {} (parent: {})",
-                        count as u32,
-                        species_count,
-                        species.value()
-                    );
-                }
+            let mut offspring = commands.spawn((
+                Position::new(spawn_position.x, spawn_position.y),
+                Velocity::new(0.0, 0.0),
+                Energy::with_energy(max_energy, initial_energy),
+                Age::new(),
+                Size::new(size),
+                Metabolism::new(metabolism_rate, movement_cost),
+                ReproductionCooldown::new(reproduction_cooldown),
+                offspring_genome,
+                cached,
+                offspring_species, // Step 8: Use speciation-assigned species ID
+                event.organism_type,
+                Behavior::new(),
+                offspring_seed,
+                EnergyBudget::default(),
+                Alive,
+            ));
+            offspring.insert((
+                Stomach::for_size(size, tuning.stomach_capacity_per_size),
+                Health::for_size(size, tuning.health_per_size),
+                Crowding::default(),
+                Stress::default(),
+                uid,
+            ));
+            if let Some(sex) = sex {
+                offspring.insert(sex);
+            }
+            if let Some(cared) = caretaker {
+                offspring.insert(cared);
+            }
+            if let Some(early) = EarlyLife::at_birth(caretaker.is_some(), &tuning) {
+                offspring.insert(early);
+            }
+        }
+
+        // Step 8: Log species information on reproduction
+        if let Some(species) = spawned_species {
+            let species_count = species_tracker.species_count();
+            if spawned_count % 10 == 0 || species_count <= 5 {
+                // Log every 10th reproduction or when few species exist
+                info!(
+                    "[REPRODUCTION] Spawned {} offspring ({}) | Species: {} (parent: {} {})",
+                    spawned_count,
+                    if event.sexual { "sexual" } else { "asexual" },
+                    species_count,
+                    species.value(),
+                    crate::organisms::species_name(species.value())
+                );
             }
         }
     }
 }
 
-/// Handle organism death (remove entities with zero energy)
+/// Offsets tried when placing a newborn before it falls back to the parent's position
+const DISPERSAL_ATTEMPTS: usize = 8;
+
+/// How far from its parent a newborn may land (half-width of the placement box)
+/// Exploration drive in [0, 1] scales the tuned radius up or down by `exploration_weight`
+/// around its midpoint, so wide-ranging lineages spread faster and homebodies cluster.
+fn offspring_dispersal_radius(base_radius: f32, exploration_weight: f32, exploration_drive: f32) -> f32 {
+    (base_radius * (1.0 + exploration_weight * (exploration_drive - 0.5) * 2.0)).max(0.0)
+}
+
+/// Pick a spawn position within `radius` of `origin` that isn't impassable for the newborn
+/// Tries a few random offsets, then settles for the origin (where the parent stands).
+fn disperse_offspring(
+    origin: Vec2,
+    radius: f32,
+    world_grid: &WorldGrid,
+    impassable: &[TerrainType],
+    rng: &mut fastrand::Rng,
+) -> Vec2 {
+    for _ in 0..DISPERSAL_ATTEMPTS {
+        let offset = Vec2::new(rng.f32() * 2.0 - 1.0, rng.f32() * 2.0 - 1.0) * radius;
+        let candidate = origin + offset;
+        if !is_impassable(world_grid, candidate, impassable) {
+            return candidate;
+        }
+    }
+    origin
+}
+
+/// Handle organism death (remove entities at or below the tuned death energy threshold)
 pub fn handle_death(
     mut commands: Commands,
-    mut tracked: ResMut<TrackedOrganism>,
+    mut tracked: ResMut<TrackedOrganisms>,
     mut spatial_hash: ResMut<SpatialHashGrid>,
-    query: Query<(Entity, &Energy), With<Alive>>,
+    mut world_grid: ResMut<WorldGrid>,
+    mut carcasses: ResMut<crate::organisms::Carcasses>,
+    mut trophic: ResMut<crate::organisms::TrophicFlows>,
+    query: Query<(Entity, &Energy, &Position, &Size, Option<&Health>), With<Alive>>,
+    tuning: Res<crate::organisms::EcosystemTuning>,
 ) {
-    for (entity, energy) in query.iter() {
-        if energy.is_dead() {
-            if tracked.entity == Some(entity) {
+    for (entity, energy, position, size, health) in query.iter() {
+        let wounded_to_death = health.is_some_and(|health| health.is_dead());
+        if energy.is_depleted(tuning.death_energy_fraction) || wounded_to_death {
+            let cause = if wounded_to_death { "wounds" } else { "starvation" };
+            if tracked.untrack(entity) {
                 info!(
-                    "[TRACKED] Organism died! Final energy: {:.2}",
-                    energy.current
+                    "[TRACKED] Organism died of {}! Final energy: {:.2}",
+                    cause, energy.current
                 );
-                tracked.entity = None; // Clear tracking
             }
-            info!("Organism died at energy level: {:.2}", energy.current);
+            info!("Organism died of {} at energy level: {:.2}", cause, energy.current);
+            let detritus = crate::organisms::carcass::deposit_carcass(
+                &mut world_grid,
+                &mut carcasses,
+                &tuning,
+                position.0,
+                size.value(),
+                energy.current,
+            );
+            trophic.record_detritus(detritus);
             // Remove from spatial hash before despawning
             spatial_hash.organisms.remove(entity);
             commands.entity(entity).despawn();
@@ -812,19 +2146,26 @@ pub fn log_all_organisms(
             &OrganismType,
             &Behavior,
             &CachedTraits,
+            &OrganismUid,
         ),
         With<Alive>,
     >,
+    uid_query: Query<&OrganismUid>,
+    health_query: Query<&Health>,
+    config: Res<LoggingConfig>,
+    sim_time: Res<SimTime>,
 ) {
-    state.tick_counter += 1;
-
-    if state.sample_interval > 1 && state.tick_counter % state.sample_interval != 0 {
+    if !config.snapshots_enabled || !sim_time.every(config.snapshot_interval) {
         return;
     }
 
-    let tick = state.tick_counter;
+    let tick = sim_time.tick;
     let header_needed = !state.header_written;
-    let flush_interval = state.flush_interval;
+    let flush_interval = config.snapshot_flush_interval;
+    if header_needed {
+        state.entity_index_column = config.snapshot_entity_index;
+    }
+    let entity_index_column = state.entity_index_column;
 
     {
         let writer = match state.ensure_writer() {
@@ -833,25 +2174,37 @@ pub fn log_all_organisms(
         };
 
         if header_needed {
-            writeln!(writer, "{}", ALL_ORGANISMS_HEADER)
-                .expect("Failed to write all-organisms header");
+            let entity_index = if entity_index_column { ",entity_index" } else { "" };
+            let header =
+                writeln!(writer, "tick,organism_uid{entity_index},{ALL_ORGANISMS_COLUMNS}");
+            if let Err(err) = header {
+                state.disable(&err);
+                return;
+            }
         }
 
-        for (entity, position, velocity, energy, age, size, org_type, behavior, cached_traits) in
+        for (entity, position, velocity, energy, age, size, org_type, behavior, cached_traits, uid) in
             query.iter()
         {
             let speed = velocity.0.length();
+            let entity_index = if entity_index_column {
+                format!(",{}", entity.index())
+            } else {
+                String::new()
+            };
 
             let energy_ratio = energy.ratio();
+            let health_ratio = health_query.get(entity).map_or(1.0, |health| health.ratio());
             let behavior_state = format!("{:?}", behavior.state);
             let organism_type = format!("{:?}", org_type);
             let (target_x, target_y) = behavior
                 .target_position
                 .map(|pos| (pos.x, pos.y))
                 .unwrap_or((f32::NAN, f32::NAN));
-            let target_entity = behavior
+            let target_uid = behavior
                 .target_entity
-                .map(|entity| entity.index().to_string())
+                .and_then(|entity| uid_query.get(entity).ok())
+                .map(|uid| uid.0.to_string())
                 .unwrap_or_else(|| "None".to_string());
             let migration = behavior.migration_target.or(behavior.target_position);
             let (migration_x, migration_y) = migration
@@ -865,11 +2218,12 @@ pub fn log_all_organisms(
                 0u8
             };
 
-            writeln!(
+            let row = writeln!(
                 writer,
-                "{tick},{entity},{pos_x:.6},{pos_y:.6},{vel_x:.6},{vel_y:.6},{speed:.6},{energy_current:.6},{energy_max:.6},{energy_ratio:.6},{age},{size:.6},{organism_type},{behavior_state},{state_time:.6},{target_x:.6},{target_y:.6},{target_entity},{sensory_range:.6},{aggression:.6},{boldness:.6},{mutation_rate:.6},{reproduction_threshold:.6},{reproduction_cooldown:.6},{foraging_drive:.6},{risk_tolerance:.6},{exploration_drive:.6},{clutch_size:.6},{offspring_share:.6},{hunger_memory:.6},{threat_timer:.6},{resource_selectivity:.6},{migration_x:.6},{migration_y:.6},{migration_active}",
+                "{tick},{uid}{entity_index},{pos_x:.6},{pos_y:.6},{vel_x:.6},{vel_y:.6},{speed:.6},{energy_current:.6},{energy_max:.6},{energy_ratio:.6},{age},{size:.6},{organism_type},{behavior_state},{state_time:.6},{target_x:.6},{target_y:.6},{target_uid},{sensory_range:.6},{aggression:.6},{boldness:.6},{mutation_rate:.6},{reproduction_threshold:.6},{reproduction_cooldown:.6},{foraging_drive:.6},{risk_tolerance:.6},{exploration_drive:.6},{clutch_size:.6},{offspring_share:.6},{hunger_memory:.6},{threat_timer:.6},{resource_selectivity:.6},{migration_x:.6},{migration_y:.6},{migration_active},{strategy_index:.6},{health_ratio:.6}",
                 tick = tick,
-                entity = entity.index(),
+                uid = uid.0,
+                entity_index = entity_index,
                 pos_x = position.0.x,
                 pos_y = position.0.y,
                 vel_x = velocity.0.x,
@@ -885,7 +2239,7 @@ pub fn log_all_organisms(
                 state_time = behavior.state_time,
                 target_x = target_x,
                 target_y = target_y,
-                target_entity = target_entity,
+                target_uid = target_uid,
                 sensory_range = cached_traits.sensory_range,
                 aggression = cached_traits.aggression,
                 boldness = cached_traits.boldness,
@@ -902,15 +2256,21 @@ pub fn log_all_organisms(
                 resource_selectivity = cached_traits.resource_selectivity,
                 migration_x = migration_x,
                 migration_y = migration_y,
-                migration_active = migration_active
-            )
-            .expect("Failed to write all-organism CSV row");
+                migration_active = migration_active,
+                strategy_index = cached_traits.reproductive_strategy_index(),
+                health_ratio = health_ratio
+            );
+            if let Err(err) = row {
+                state.disable(&err);
+                return;
+            }
         }
 
         if flush_interval > 0 && tick % flush_interval == 0 {
-            writer
-                .flush()
-                .expect("Failed to flush all-organism CSV writer");
+            if let Err(err) = writer.flush() {
+                state.disable(&err);
+                return;
+            }
         }
     }
 
@@ -921,7 +2281,7 @@ pub fn log_all_organisms(
 
 /// Log tracked organism information periodically
 pub fn log_tracked_organism(
-    tracked: ResMut<TrackedOrganism>,
+    tracked: ResMut<TrackedOrganisms>,
     query: Query<
         (
             Entity,
@@ -933,19 +2293,36 @@ pub fn log_tracked_organism(
             &OrganismType,
             &Behavior,
             &CachedTraits,
+            &OrganismUid,
         ),
         With<Alive>,
     >,
+    uid_query: Query<&OrganismUid>,
+    mut budget_query: Query<&mut EnergyBudget>,
+    stomach_query: Query<&Stomach>,
+    crowding_query: Query<&Crowding>,
+    health_query: Query<&Health>,
+    config: Res<LoggingConfig>,
+    sim_time: Res<SimTime>,
 ) {
     let mut tracked_mut = tracked;
-    tracked_mut.log_counter += 1;
 
-    // default cadence: every 10 ticks
-    if tracked_mut.log_counter % 10 != 0 {
+    if !config.tracking_enabled || !sim_time.every(config.tracking_interval) {
         return;
     }
 
-    if let Some(entity) = tracked_mut.entity {
+    let tracked_budgets: Vec<EnergyBudget> = tracked_mut
+        .entities()
+        .map(|entity| budget_query.get(entity).ok().copied().unwrap_or_default())
+        .collect();
+    // Budgets cover one logging interval
+    for mut budget in budget_query.iter_mut() {
+        budget.reset();
+    }
+
+    let mut gone = Vec::new();
+    for (slot, tracked_budget) in tracked_mut.slots.iter_mut().zip(tracked_budgets) {
+        let entity = slot.entity;
         if let Ok((
             _entity,
             position,
@@ -956,6 +2333,7 @@ pub fn log_tracked_organism(
             org_type,
             behavior,
             cached_traits,
+            uid,
         )) = query.get(entity)
         {
             let speed = velocity.0.length();
@@ -964,6 +2342,14 @@ pub fn log_tracked_organism(
             let aggression = cached_traits.aggression;
             let boldness = cached_traits.boldness;
             let mutation_rate = cached_traits.mutation_rate;
+            let stomach_fullness = stomach_query
+                .get(entity)
+                .map(|stomach| stomach.fullness())
+                .unwrap_or(0.0);
+            let crowding = crowding_query.get(entity).copied().unwrap_or_default();
+            let (health_current, health_max) = health_query
+                .get(entity)
+                .map_or((f32::NAN, f32::NAN), |health| (health.current, health.max));
 
             let target_info = if let Some(target_pos) = behavior.target_position {
                 format!("({:.1}, {:.1})", target_pos.x, target_pos.y)
@@ -972,8 +2358,9 @@ pub fn log_tracked_organism(
             };
 
             info!(
-                "[TRACKED ORGANISM] Tick: {} | Pos: ({:.2}, {:.2}) | Vel: ({:.2}, {:.2}) | Speed: {:.2} | Energy: {:.2}/{:.2} ({:.1}%) | Age: {} | Size: {:.2} | Type: {:?} | Behavior: {} | StateTime: {:.1}s | Target: {} | SensoryRange: {:.1} | Aggression: {:.2} | Boldness: {:.2} | MutationRate: {:.4}",
-                tracked_mut.log_counter,
+                "[TRACKED ORGANISM] Tick: {} | UID: {} | Pos: ({:.2}, {:.2}) | Vel: ({:.2}, {:.2}) | Speed: {:.2} | Energy: {:.2}/{:.2} ({:.1}%) | Age: {} | Size: {:.2} | Type: {:?} | Behavior: {} | StateTime: {:.1}s | Target: {} | SensoryRange: {:.1} | Aggression: {:.2} | Boldness: {:.2} | MutationRate: {:.4} | Stomach: {:.0}% | Crowding: {} ({:.2}) | Health: {:.1}/{:.1}",
+                sim_time.tick,
+                uid.0,
                 position.0.x,
                 position.0.y,
                 velocity.0.x,
@@ -992,18 +2379,27 @@ pub fn log_tracked_organism(
                 aggression,
                 boldness,
                 mutation_rate,
+                stomach_fullness * 100.0,
+                crowding.neighbors,
+                crowding.level,
+                health_current,
+                health_max,
             );
 
-            let needs_header = !tracked_mut.header_written;
-            let tick = tracked_mut.log_counter;
+            let needs_header = !slot.header_written;
+            let tick = sim_time.tick;
 
-            if let Some(ref mut writer) = tracked_mut.csv_writer {
+            let flush_interval = config.tracking_flush_interval;
+            if let Some(writer) = slot.ensure_writer() {
                 if needs_header {
-                    writeln!(
+                    let header = writeln!(
                         writer,
-                        "tick,position_x,position_y,velocity_x,velocity_y,speed,energy_current,energy_max,energy_ratio,age,size,organism_type,behavior_state,state_time,target_x,target_y,target_entity,sensory_range,aggression,boldness,mutation_rate,foraging_drive,risk_tolerance,exploration_drive,clutch_size,offspring_energy_share,hunger_memory,threat_timer,resource_selectivity,migration_target_x,migration_target_y,migration_active"
-                    )
-                    .expect("Failed to write CSV header");
+                        "tick,organism_uid,position_x,position_y,velocity_x,velocity_y,speed,energy_current,energy_max,energy_ratio,age,size,organism_type,behavior_state,state_time,target_x,target_y,target_uid,sensory_range,aggression,boldness,mutation_rate,foraging_drive,risk_tolerance,exploration_drive,clutch_size,offspring_energy_share,hunger_memory,threat_timer,resource_selectivity,diet_bias,migration_target_x,migration_target_y,migration_active,budget_metabolism,budget_movement,budget_sensing,budget_eaten,budget_reproduction,budget_net,stomach_fullness,crowding_neighbors,crowding_level,health_current,health_max"
+                    );
+                    if let Err(err) = header {
+                        slot.disable(&err);
+                        continue;
+                    }
                 }
 
                 let (target_x, target_y) = if let Some(target_pos) = behavior.target_position {
@@ -1011,9 +2407,10 @@ pub fn log_tracked_organism(
                 } else {
                     (f32::NAN, f32::NAN)
                 };
-                let target_entity = behavior
+                let target_uid = behavior
                     .target_entity
-                    .map(|entity| entity.index().to_string())
+                    .and_then(|entity| uid_query.get(entity).ok())
+                    .map(|uid| uid.0.to_string())
                     .unwrap_or_else(|| "None".to_string());
                 let (migration_x, migration_y) = behavior
                     .migration_target
@@ -1028,10 +2425,11 @@ pub fn log_tracked_organism(
                     0u8
                 };
 
-                writeln!(
+                let row = writeln!(
                     writer,
-                    "{tick},{pos_x:.6},{pos_y:.6},{vel_x:.6},{vel_y:.6},{speed:.6},{energy_current:.6},{energy_max:.6},{energy_ratio:.6},{age},{size:.6},{organism_type:?},{behavior_state},{state_time:.6},{target_x:.6},{target_y:.6},{target_entity},{sensory_range:.6},{aggression:.6},{boldness:.6},{mutation_rate:.6},{foraging_drive:.6},{risk_tolerance:.6},{exploration_drive:.6},{clutch_size:.6},{offspring_share:.6},{hunger_memory:.6},{threat_timer:.6},{resource_selectivity:.6},{migration_x:.6},{migration_y:.6},{migration_active}",
+                    "{tick},{uid},{pos_x:.6},{pos_y:.6},{vel_x:.6},{vel_y:.6},{speed:.6},{energy_current:.6},{energy_max:.6},{energy_ratio:.6},{age},{size:.6},{organism_type:?},{behavior_state},{state_time:.6},{target_x:.6},{target_y:.6},{target_uid},{sensory_range:.6},{aggression:.6},{boldness:.6},{mutation_rate:.6},{foraging_drive:.6},{risk_tolerance:.6},{exploration_drive:.6},{clutch_size:.6},{offspring_share:.6},{hunger_memory:.6},{threat_timer:.6},{resource_selectivity:.6},{diet_bias:.6},{migration_x:.6},{migration_y:.6},{migration_active},{budget_metabolism:.6},{budget_movement:.6},{budget_sensing:.6},{budget_eaten:.6},{budget_reproduction:.6},{budget_net:.6},{stomach_fullness:.6},{crowding_neighbors},{crowding_level:.6},{health_current:.6},{health_max:.6}",
                     tick = tick,
+                    uid = uid.0,
                     pos_x = position.0.x,
                     pos_y = position.0.y,
                     vel_x = velocity.0.x,
@@ -1047,7 +2445,7 @@ pub fn log_tracked_organism(
                     state_time = behavior.state_time,
                     target_x = target_x,
                     target_y = target_y,
-                    target_entity = target_entity,
+                    target_uid = target_uid,
                     sensory_range = sensory_range,
                     aggression = aggression,
                     boldness = boldness,
@@ -1060,27 +2458,411 @@ pub fn log_tracked_organism(
                     hunger_memory = behavior.hunger_memory,
                     threat_timer = behavior.threat_timer,
                     resource_selectivity = cached_traits.resource_selectivity,
+                    diet_bias = cached_traits.diet_bias,
                     migration_x = migration_x,
                     migration_y = migration_y,
-                    migration_active = migration_active
-                )
-                .expect("Failed to write CSV row");
-
-                if tick % 100 == 0 {
-                    writer.flush().expect("Failed to flush CSV writer");
+                    migration_active = migration_active,
+                    budget_metabolism = tracked_budget.metabolism,
+                    budget_movement = tracked_budget.movement,
+                    budget_sensing = tracked_budget.sensing,
+                    budget_eaten = tracked_budget.eaten,
+                    budget_reproduction = tracked_budget.reproduction,
+                    budget_net = tracked_budget.net(),
+                    stomach_fullness = stomach_fullness,
+                    crowding_neighbors = crowding.neighbors,
+                    crowding_level = crowding.level,
+                    health_current = health_current,
+                    health_max = health_max
+                );
+                let flushed = if flush_interval > 0 && tick % flush_interval == 0 {
+                    row.and_then(|()| writer.flush())
+                } else {
+                    row
+                };
+                if let Err(err) = flushed {
+                    slot.disable(&err);
+                    continue;
                 }
             }
 
             if needs_header {
-                tracked_mut.header_written = true;
+                slot.header_written = true;
             }
         } else {
             info!("[TRACKED] Organism entity {:?} no longer exists", entity);
-            tracked_mut.entity = None;
+            gone.push(entity);
+        }
+    }
+    for entity in gone {
+        tracked_mut.untrack(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::AppExit;
+
+    #[test]
+    fn closest_mates_pair_first() {
+        let [a, b, c, d] = [1, 2, 3, 4].map(Entity::from_raw);
+        // b, c and d all court a; c is closest, so a pairs with c and b falls back to d
+        let pairs = pair_closest_mates(vec![
+            (b, a, 4.0),
+            (d, a, 9.0),
+            (c, a, 1.0),
+            (a, c, 1.0),
+            (b, d, 6.0),
+        ]);
+        assert_eq!(pairs, vec![(a, c), (b, d)]);
+    }
+
+    #[test]
+    fn mate_pairing_ignores_candidate_order() {
+        let [a, b, c] = [1, 2, 3].map(Entity::from_raw);
+        let candidates = vec![(c, a, 2.0), (b, a, 2.0), (c, b, 5.0)];
+        let mut reversed = candidates.clone();
+        reversed.reverse();
+        // Equal distances: the lower entity wins
+        assert_eq!(pair_closest_mates(candidates), vec![(a, b)]);
+        assert_eq!(pair_closest_mates(reversed), vec![(a, b)]);
+    }
+
+    #[test]
+    fn interrupt_flushes_buffered_snapshot_rows() {
+        let csv_path =
+            std::env::temp_dir().join(format!("snapshot_flush_test_{}.csv", std::process::id()));
+        let mut logger = AllOrganismsLogger::default();
+        logger.csv_path = csv_path.clone();
+        let writer = logger.ensure_writer().unwrap();
+        for row in 0..100 {
+            writeln!(writer, "{row},row").unwrap();
+        }
+        // Still buffered: nothing has reached the file yet
+        assert_eq!(std::fs::read_to_string(&csv_path).unwrap(), "");
+
+        let mut world = World::new();
+        world.init_resource::<Events<AppExit>>();
+        world.insert_resource(logger);
+        crate::utils::shutdown::request_interrupt();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((crate::utils::shutdown::exit_on_interrupt, flush_logs_on_exit).chain());
+        schedule.run(&mut world);
+
+        // Written out completely while the app (and logger) is still alive
+        let contents = std::fs::read_to_string(&csv_path).unwrap();
+        std::fs::remove_file(&csv_path).ok();
+        let lines: Vec<_> = contents.lines().collect();
+        assert_eq!(lines.len(), 100);
+        assert_eq!(lines.last(), Some(&"99,row"));
+    }
+
+    /// An empty world running at 60 ticks/s under `tuning`
+    fn metabolism_world(tuning: crate::organisms::EcosystemTuning) -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.insert_resource(tuning);
+        world.init_resource::<crate::organisms::TrophicFlows>();
+        world.init_resource::<WorldGrid>();
+        world
+    }
 
-            if let Some(mut writer) = tracked_mut.csv_writer.take() {
-                writer.flush().expect("Failed to flush CSV writer on close");
+    /// A motionless newborn consumer of size 1 burning `base_rate` energy per second
+    fn spawn_idle(world: &mut World, base_rate: f32, max_energy: f32, energy: f32) -> Entity {
+        world
+            .spawn((
+                Energy::with_energy(max_energy, energy),
+                Velocity::zero(),
+                Metabolism::new(base_rate, 0.0),
+                Size::new(1.0),
+                OrganismType::Consumer,
+                Position::new(0.0, 0.0),
+                Age::new(),
+            ))
+            .id()
+    }
+
+    /// Run `update_metabolism` for `ticks` ticks, ageing every organism as `update_age` would
+    fn run_metabolism(world: &mut World, ticks: u32) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_metabolism);
+        for _ in 0..ticks {
+            schedule.run(world);
+            let mut ages = world.query::<&mut Age>();
+            for mut age in ages.iter_mut(world) {
+                age.increment();
             }
         }
     }
+
+    #[test]
+    fn newborn_grace_lets_a_starving_newborn_reach_its_first_meal() {
+        let tuning = |newborn_grace_ticks| crate::organisms::EcosystemTuning {
+            newborn_grace_ticks,
+            base_metabolism_multiplier: 1.0,
+            ..Default::default()
+        };
+        let mut graced = metabolism_world(tuning(60));
+        let mut ungraced = metabolism_world(tuning(0));
+        let newborn = spawn_idle(&mut graced, 100.0, 100.0, 15.0);
+        let unprotected = spawn_idle(&mut ungraced, 100.0, 100.0, 15.0);
+
+        // Half a second without food: upkeep alone starves a newborn at the energy floor
+        run_metabolism(&mut graced, 30);
+        run_metabolism(&mut ungraced, 30);
+        assert_eq!(graced.get::<Energy>(newborn).unwrap().current, 15.0);
+        assert!(ungraced.get::<Energy>(unprotected).unwrap().is_dead());
+
+        // Upkeep is charged again once the grace period is over
+        run_metabolism(&mut graced, 40);
+        assert!(graced.get::<Energy>(newborn).unwrap().current < 15.0);
+    }
+
+    /// Insert a generated chunk at the origin with every cell set up by `fill`
+    fn insert_origin_chunk(world: &mut World, fill: impl Fn(&mut crate::world::Cell)) {
+        let mut chunk = crate::world::Chunk::new(0, 0);
+        for cell in chunk.cells_mut().iter_mut() {
+            fill(cell);
+        }
+        world.resource_mut::<WorldGrid>().insert_chunk(chunk);
+    }
+
+    /// A motionless organism of size 1 that is trying to eat at `position`
+    fn spawn_eater(world: &mut World, organism_type: OrganismType, position: Vec2, energy: f32) -> Entity {
+        let mut behavior = Behavior::new();
+        behavior.state = BehaviorState::Eating;
+        world
+            .spawn((
+                Position(position),
+                Velocity::zero(),
+                Energy::with_energy(100.0, energy),
+                Metabolism::new(5.0, 0.0),
+                Size::new(1.0),
+                organism_type,
+                behavior,
+                Alive,
+            ))
+            .id()
+    }
+
+    /// World with a single producer eating at the center of a chunk lit by `sunlight`
+    fn photosynthesis_world(sunlight: f32) -> (World, Entity) {
+        let mut world = metabolism_world(crate::organisms::EcosystemTuning::default());
+        world.init_resource::<DeterministicOrder>();
+        insert_origin_chunk(&mut world, |cell| {
+            cell.set_resource(ResourceType::Sunlight, sunlight);
+            cell.set_resource(ResourceType::Water, 1.0);
+            cell.set_resource(ResourceType::Mineral, 1.0);
+        });
+        let producer = spawn_eater(&mut world, OrganismType::Producer, Vec2::new(32.5, 32.5), 5.0);
+        (world, producer)
+    }
+
+    #[test]
+    fn shaded_producer_starves_despite_abundant_water() {
+        let mut schedule = Schedule::default();
+        schedule.add_systems((handle_eating, update_metabolism).chain());
+
+        let (mut lit, lit_producer) = photosynthesis_world(1.0);
+        let (mut shaded, shaded_producer) = photosynthesis_world(0.0);
+        schedule.run(&mut lit);
+        schedule.run(&mut shaded);
+        assert!(
+            lit.get::<Energy>(lit_producer).unwrap().current
+                > shaded.get::<Energy>(shaded_producer).unwrap().current
+        );
+
+        for _ in 0..600 {
+            schedule.run(&mut shaded);
+        }
+        assert!(shaded.get::<Energy>(shaded_producer).unwrap().is_dead());
+        // Without sunlight the cofactors aren't drawn on either
+        let cell = shaded.resource::<WorldGrid>().get_cell_or_default(32.5, 32.5);
+        assert_eq!(cell.get_resource(ResourceType::Water), 1.0);
+        assert_eq!(cell.get_resource(ResourceType::Mineral), 1.0);
+    }
+
+    #[test]
+    fn resting_organisms_burn_energy_more_slowly() {
+        let mut world = metabolism_world(crate::organisms::EcosystemTuning {
+            newborn_grace_ticks: 0,
+            ..Default::default()
+        });
+        let spawn_in = |world: &mut World, state| {
+            let organism = spawn_idle(world, 10.0, 100.0, 50.0);
+            let mut behavior = Behavior::new();
+            behavior.state = state;
+            world.entity_mut(organism).insert(behavior);
+            organism
+        };
+        let resting = spawn_in(&mut world, BehaviorState::Resting);
+        let wandering = spawn_in(&mut world, BehaviorState::Wandering);
+
+        run_metabolism(&mut world, 60);
+        let spent = |organism| 50.0 - world.get::<Energy>(organism).unwrap().current;
+        assert!(spent(resting) > 0.0);
+        assert!(spent(resting) < spent(wandering));
+    }
+
+    #[test]
+    fn offspring_avoid_impassable_cells_beside_the_parent() {
+        // Ocean west of x = 32, plains east of it; the parent stands on the shore
+        let mut chunk = crate::world::Chunk::new(0, 0);
+        for (index, cell) in chunk.cells_mut().iter_mut().enumerate() {
+            cell.terrain = if index % crate::world::CHUNK_SIZE < 32 {
+                TerrainType::Ocean
+            } else {
+                TerrainType::Plains
+            };
+        }
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+        let parent = Vec2::new(32.5, 32.5);
+
+        let mut moved_away = false;
+        for seed in 0..200 {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let position = disperse_offspring(parent, 5.0, &grid, &[TerrainType::Ocean], &mut rng);
+            let terrain = grid.get_cell(position.x, position.y).unwrap().terrain;
+            assert_ne!(terrain, TerrainType::Ocean, "seed {seed} placed offspring at {position}");
+            moved_away |= position != parent;
+        }
+        assert!(moved_away);
+    }
+
+    #[test]
+    fn contested_cells_never_give_up_more_than_they_hold() {
+        let tuning = crate::organisms::EcosystemTuning {
+            consumption_rate_base: 100.0,
+            fair_cell_sharing: true,
+            ..Default::default()
+        };
+        let efficiency = tuning.energy_conversion_efficiency;
+        let mut world = metabolism_world(tuning);
+        world.init_resource::<DeterministicOrder>();
+        insert_origin_chunk(&mut world, |cell| cell.set_resource(ResourceType::Plant, 0.02));
+        let plants = |world: &World| -> f32 {
+            let chunk = world.resource::<WorldGrid>().get_chunk(0, 0).unwrap();
+            chunk.cells().iter().map(|cell| cell.get_resource(ResourceType::Plant)).sum()
+        };
+        let before = plants(&world);
+
+        // Twenty hungry consumers want far more than the handful of cells they share
+        let eaters: Vec<_> = (0..20)
+            .map(|_| spawn_eater(&mut world, OrganismType::Consumer, Vec2::new(32.5, 32.5), 10.0))
+            .collect();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(handle_eating);
+        schedule.run(&mut world);
+
+        let grid = world.resource::<WorldGrid>();
+        for (dx, dy) in [(0.0, 0.0), (1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)] {
+            let cell = grid.get_cell_or_default(32.5 + dx, 32.5 + dy);
+            assert!(cell.get_resource(ResourceType::Plant) >= 0.0);
+        }
+        let consumed = before - plants(&world);
+        // The five cells in reach held 0.1 between them
+        assert!(consumed > 0.0 && consumed <= 0.1 + 1e-4, "consumed {consumed}");
+        let gained: f32 = eaters
+            .iter()
+            .map(|&eater| world.get::<Energy>(eater).unwrap().current - 10.0)
+            .sum();
+        assert!((gained - consumed * efficiency).abs() < 1e-3, "{gained} from {consumed}");
+    }
+
+    #[test]
+    fn fat_reserves_outlast_famine_but_cost_upkeep() {
+        let tuning = |energy_storage_maintenance| crate::organisms::EcosystemTuning {
+            newborn_grace_ticks: 0,
+            base_metabolism_multiplier: 1.0,
+            energy_storage_maintenance,
+            ..Default::default()
+        };
+
+        // Famine: nothing to eat, so the larger reserve lasts longer
+        let mut famine = metabolism_world(tuning(0.0002));
+        let lean = spawn_idle(&mut famine, 10.0, 50.0, 50.0);
+        let fat = spawn_idle(&mut famine, 10.0, 200.0, 200.0);
+        run_metabolism(&mut famine, 400);
+        assert!(famine.get::<Energy>(lean).unwrap().is_dead());
+        assert!(!famine.get::<Energy>(fat).unwrap().is_dead());
+
+        // Abundance: both stay fed, but the fat one pays more to carry its reserve
+        let spent_per_second = |maintenance| {
+            let mut world = metabolism_world(tuning(maintenance));
+            let lean = spawn_idle(&mut world, 10.0, 50.0, 50.0);
+            let fat = spawn_idle(&mut world, 10.0, 200.0, 50.0);
+            run_metabolism(&mut world, 60);
+            let spent = |organism| 50.0 - world.get::<Energy>(organism).unwrap().current;
+            (spent(lean), spent(fat))
+        };
+        let (lean_spent, fat_spent) = spent_per_second(0.0002);
+        assert!(fat_spent > lean_spent);
+        // Without storage upkeep the reserve would be free
+        let (lean_spent, fat_spent) = spent_per_second(0.0);
+        assert_eq!(fat_spent, lean_spent);
+    }
+
+    /// Energy a wandering, ready organism of each sex has left after one reproduction tick
+    fn energy_after_asexual_tick(sexes_enabled: bool) -> (f32, f32) {
+        let mut world = World::new();
+        world.insert_resource(crate::organisms::EcosystemTuning {
+            sexes_enabled,
+            reproduction_chance_multiplier: 1.0,
+            ..Default::default()
+        });
+        let mut logging = LoggingConfig::default();
+        logging.mutations_enabled = false;
+        world.insert_resource(logging);
+        world.init_resource::<crate::organisms::SpeciesTracker>();
+        world.init_resource::<crate::organisms::MutationLog>();
+        world.init_resource::<MatingHandshakes>();
+        world.init_resource::<OrganismUids>();
+        world.init_resource::<WorldGrid>();
+        world.init_resource::<DeterministicOrder>();
+        world.init_resource::<TrackedOrganisms>();
+        world.init_resource::<SimTime>();
+
+        let genome = Genome::new(vec![0.5; crate::organisms::genetics::GENOME_SIZE]);
+        let mut spawn = |sex, seed| {
+            world
+                .spawn((
+                    Position::new(0.0, 0.0),
+                    Energy::new(100.0),
+                    ReproductionCooldown::new(0),
+                    genome.clone(),
+                    CachedTraits::from_genome(&genome),
+                    SpeciesId::new(1),
+                    OrganismType::Consumer,
+                    RngSeed::new(seed),
+                    Behavior::new(),
+                    Alive,
+                    sex,
+                ))
+                .id()
+        };
+        let female = spawn(Sex::Female, 1);
+        let male = spawn(Sex::Male, 2);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(handle_reproduction);
+        schedule.run(&mut world);
+        let energy = |organism| world.get::<Energy>(organism).unwrap().current;
+        (energy(female), energy(male))
+    }
+
+    #[test]
+    fn males_only_reproduce_with_a_mate() {
+        // Females lay (and pay for) their own clutches; males don't clone themselves
+        let (female, male) = energy_after_asexual_tick(true);
+        assert!(female < 100.0);
+        assert_eq!(male, 100.0);
+
+        // Without sexes, leftover Sex components don't stop anyone
+        let (female, male) = energy_after_asexual_tick(false);
+        assert!(female < 100.0);
+        assert!(male < 100.0);
+    }
 }
@@ -0,0 +1,148 @@
+use crate::organisms::components::{Alive, OrganismType, OrganismUid, Position, SpeciesId};
+use crate::organisms::{DeterministicOrder, EcosystemTuning};
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Marks weaker than this are dropped, so faded territory stops costing memory
+const MIN_SCENT: f32 = 0.01;
+
+/// How far (in cells) organisms sniff around when steering out of foreign territory
+const AVOIDANCE_SAMPLE_DISTANCE: f32 = 2.0;
+
+/// One cell's scent: the species that marked it and how strongly (0-1)
+#[derive(Debug, Clone, Copy)]
+pub struct ScentMark {
+    pub species: u32,
+    pub strength: f32,
+}
+
+/// Territory scent marks left by consumers, stored only for marked cells
+/// Consumers mark their current cell every `scent_mark_interval` ticks; marks fade with
+/// `scent_half_life_seconds`. Organisms on strongly marked foreign cells are stressed and
+/// steer back out, so each species ends up with a home range.
+#[derive(Resource, Default)]
+pub struct ScentMarks {
+    marks: HashMap<(i32, i32), ScentMark>,
+}
+
+impl ScentMarks {
+    fn key(position: Vec2) -> (i32, i32) {
+        (position.x.floor() as i32, position.y.floor() as i32)
+    }
+
+    pub fn get(&self, position: Vec2) -> Option<ScentMark> {
+        self.marks.get(&Self::key(position)).copied()
+    }
+
+    /// Strength of another species' mark at `position` (0 if unmarked or marked by `species`)
+    pub fn foreign_strength(&self, position: Vec2, species: u32) -> f32 {
+        self.get(position)
+            .filter(|mark| mark.species != species)
+            .map_or(0.0, |mark| mark.strength)
+    }
+
+    /// Leave `amount` of `species` scent at `position`
+    /// Reinforces the species' own mark; a rival's mark is worn down first, and the cell
+    /// changes hands once it's gone.
+    pub fn mark(&mut self, position: Vec2, species: u32, amount: f32) {
+        let mark = self.marks.entry(Self::key(position)).or_insert(ScentMark {
+            species,
+            strength: 0.0,
+        });
+        if mark.species == species {
+            mark.strength = (mark.strength + amount).min(1.0);
+        } else if mark.strength > amount {
+            mark.strength -= amount;
+        } else {
+            *mark = ScentMark {
+                species,
+                strength: (amount - mark.strength).min(1.0),
+            };
+        }
+    }
+
+    /// Scale every mark by `factor`, forgetting the ones that have faded out
+    pub fn decay(&mut self, factor: f32) {
+        self.marks.retain(|_, mark| {
+            mark.strength *= factor;
+            mark.strength >= MIN_SCENT
+        });
+    }
+
+    /// Marked cells as (cell x, cell y, mark)
+    pub fn iter(&self) -> impl Iterator<Item = (i32, i32, ScentMark)> + '_ {
+        self.marks.iter().map(|(&(x, y), mark)| (x, y, *mark))
+    }
+
+    pub fn len(&self) -> usize {
+        self.marks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// Stress (0-1) from standing in foreign territory: none up to `threshold`, rising to
+    /// `territorial_stress` on a fully marked cell
+    pub fn intrusion_stress(&self, position: Vec2, species: u32, tuning: &EcosystemTuning) -> f32 {
+        let threshold = tuning.territory_intrusion_threshold.clamp(0.0, 0.99);
+        let foreign = self.foreign_strength(position, species);
+        ((foreign - threshold) / (1.0 - threshold)).clamp(0.0, 1.0) * tuning.territorial_stress
+    }
+
+    /// Steering term (as a fraction of current speed) out of foreign territory
+    /// Neighboring cells with weaker foreign scent pull in proportion to the difference; the
+    /// pull only applies past the intrusion threshold.
+    pub fn avoidance(&self, position: Vec2, species: u32, tuning: &EcosystemTuning) -> Vec2 {
+        let here = self.foreign_strength(position, species);
+        if tuning.territory_avoidance_strength <= 0.0
+            || here <= tuning.territory_intrusion_threshold
+        {
+            return Vec2::ZERO;
+        }
+
+        let mut pull = Vec2::ZERO;
+        for step in 0..8 {
+            let direction = Vec2::from_angle(step as f32 * std::f32::consts::FRAC_PI_4);
+            let sample = position + direction * AVOIDANCE_SAMPLE_DISTANCE;
+            let there = self.foreign_strength(sample, species);
+            pull += direction * (here - there).max(0.0);
+        }
+        pull.normalize_or_zero() * tuning.territory_avoidance_strength * here
+    }
+}
+
+/// Fade existing marks and let consumers mark their current cells
+pub fn update_scent_marks(
+    mut marks: ResMut<ScentMarks>,
+    query: Query<(Entity, &Position, &SpeciesId, &OrganismType), With<Alive>>,
+    tuning: Res<EcosystemTuning>,
+    time: Res<Time>,
+    sim_time: Res<SimTime>,
+    order: Res<DeterministicOrder>,
+    uid_query: Query<&OrganismUid>,
+) {
+    if tuning.scent_mark_strength <= 0.0 {
+        if !marks.is_empty() {
+            marks.marks.clear();
+        }
+        return;
+    }
+
+    let half_life = tuning.scent_half_life_seconds.max(f32::EPSILON);
+    marks.decay(0.5f32.powf(time.delta_seconds() / half_life));
+
+    if !sim_time.every(tuning.scent_mark_interval) {
+        return;
+    }
+    // Rivals marking the same cell wear each other down, so the outcome depends on order
+    let mut markers: Vec<_> = query
+        .iter()
+        .filter(|(_, _, _, organism_type)| **organism_type == OrganismType::Consumer)
+        .collect();
+    order.sort(&mut markers, &uid_query, |(entity, _, _, _)| *entity);
+    for (_, position, species, _) in markers {
+        marks.mark(position.0, species.value(), tuning.scent_mark_strength);
+    }
+}
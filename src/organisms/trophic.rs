@@ -0,0 +1,162 @@
+use crate::organisms::components::OrganismType;
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+/// Energy moved between trophic levels over one export interval
+/// Intake is counted as the energy an organism actually takes in (what fits in its stomach
+/// or energy store), so the totals can be checked against metabolic losses.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TrophicTotals {
+    /// Photosynthesis
+    pub environment_to_producers: f64,
+    /// Consumers grazing Plant, or catching producers
+    pub producers_to_consumers: f64,
+    /// Consumers eating Prey, or catching any other organism
+    pub consumers_to_consumers: f64,
+    /// Decomposers eating detritus
+    pub into_decomposers: f64,
+    /// Detritus left by carcasses and excretion (resource units, not energy)
+    pub to_detritus: f64,
+    pub producer_metabolism: f64,
+    pub consumer_metabolism: f64,
+    pub decomposer_metabolism: f64,
+}
+
+/// Trophic-flow accounting (energy pyramid), exported as CSV every
+/// `LoggingConfig::trophic_interval` ticks
+#[derive(Resource)]
+pub struct TrophicFlows {
+    /// Flows accumulated since the last export
+    pub current: TrophicTotals,
+    csv_writer: Option<BufWriter<File>>,
+    csv_path: PathBuf,
+}
+
+impl Default for TrophicFlows {
+    fn default() -> Self {
+        let logs_dir = crate::organisms::systems::ensure_logs_directory();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Self {
+            current: TrophicTotals::default(),
+            csv_writer: None,
+            csv_path: logs_dir.join(format!("trophic_flows_{}.csv", timestamp)),
+        }
+    }
+}
+
+impl TrophicFlows {
+    /// Record energy taken in by an organism
+    /// `animal_fraction` is the share of a consumer's meal that came from animals rather than
+    /// producers (ignored for other types).
+    pub fn record_intake(&mut self, organism_type: OrganismType, energy: f32, animal_fraction: f32) {
+        let energy = energy.max(0.0) as f64;
+        match organism_type {
+            OrganismType::Producer => self.current.environment_to_producers += energy,
+            OrganismType::Consumer => {
+                let animal_fraction = animal_fraction.clamp(0.0, 1.0) as f64;
+                self.current.consumers_to_consumers += energy * animal_fraction;
+                self.current.producers_to_consumers += energy * (1.0 - animal_fraction);
+            }
+            OrganismType::Decomposer => self.current.into_decomposers += energy,
+        }
+    }
+
+    /// Record energy spent on upkeep (metabolism, movement, sensing)
+    pub fn record_metabolism(&mut self, organism_type: OrganismType, energy: f32) {
+        let energy = energy.max(0.0) as f64;
+        match organism_type {
+            OrganismType::Producer => self.current.producer_metabolism += energy,
+            OrganismType::Consumer => self.current.consumer_metabolism += energy,
+            OrganismType::Decomposer => self.current.decomposer_metabolism += energy,
+        }
+    }
+
+    pub fn record_detritus(&mut self, amount: f32) {
+        self.current.to_detritus += amount.max(0.0) as f64;
+    }
+
+    fn ensure_writer(&mut self) -> Option<&mut BufWriter<File>> {
+        if self.csv_writer.is_none() {
+            let file = match File::create(&self.csv_path) {
+                Ok(file) => file,
+                Err(err) => {
+                    error!("Failed to create trophic flow CSV file: {err}");
+                    return None;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+            let header = "tick,interval_ticks,environment_to_producers,producers_to_consumers,\
+                          consumers_to_consumers,into_decomposers,to_detritus,producer_metabolism,\
+                          consumer_metabolism,decomposer_metabolism";
+            if let Err(err) = writeln!(writer, "{header}") {
+                error!("Failed to write trophic flow CSV header: {err}");
+                return None;
+            }
+
+            info!("[TROPHIC] Writing trophic flows to {}", self.csv_path.display());
+            self.csv_writer = Some(writer);
+        }
+        self.csv_writer.as_mut()
+    }
+}
+
+/// Write the interval's trophic flows and start a new interval
+pub fn export_trophic_flows(
+    mut flows: ResMut<TrophicFlows>,
+    sim_time: Res<SimTime>,
+    logging: Res<LoggingConfig>,
+) {
+    if !sim_time.every(logging.trophic_interval) {
+        return;
+    }
+    // Disabled: keep the accumulators from growing without bound, but write nothing
+    if !logging.trophic_enabled {
+        flows.current = TrophicTotals::default();
+        return;
+    }
+
+    let totals = std::mem::take(&mut flows.current);
+    let consumer_intake = totals.producers_to_consumers + totals.consumers_to_consumers;
+    let producer_to_consumer_efficiency = if totals.environment_to_producers > 0.0 {
+        consumer_intake / totals.environment_to_producers
+    } else {
+        0.0
+    };
+    info!(
+        "[TROPHIC] Tick {} | Producers in {:.1} | Consumers in {:.1} ({:.1}% of producer intake) | Decomposers in {:.1} | Metabolism {:.1}/{:.1}/{:.1}",
+        sim_time.tick,
+        totals.environment_to_producers,
+        consumer_intake,
+        producer_to_consumer_efficiency * 100.0,
+        totals.into_decomposers,
+        totals.producer_metabolism,
+        totals.consumer_metabolism,
+        totals.decomposer_metabolism
+    );
+
+    let row = format!(
+        "{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4}",
+        sim_time.tick,
+        logging.trophic_interval,
+        totals.environment_to_producers,
+        totals.producers_to_consumers,
+        totals.consumers_to_consumers,
+        totals.into_decomposers,
+        totals.to_detritus,
+        totals.producer_metabolism,
+        totals.consumer_metabolism,
+        totals.decomposer_metabolism
+    );
+    if let Some(writer) = flows.ensure_writer() {
+        if let Err(err) = writeln!(writer, "{row}").and_then(|_| writer.flush()) {
+            error!("Failed to write trophic flow CSV row: {err}");
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use crate::organisms::clutch::ClutchEnergyStrategy;
+use crate::organisms::genetics::GeneticDistanceMetric;
+use crate::organisms::OrganismType;
+use crate::world::{FrontierPolicy, TerrainType, RESOURCE_TYPE_COUNT};
 use bevy::prelude::*;
 
 /// Ecosystem tuning parameters for Step 8 - Easy balance adjustment
@@ -10,6 +14,16 @@ pub struct EcosystemTuning {
     pub mineral_regeneration_rate: f32,
     pub detritus_regeneration_rate: f32,
     pub prey_regeneration_rate: f32,
+    /// Chunks generated at startup in each direction from the origin chunk (1 = 3x3); founders spawn within them
+    pub initial_world_radius: u32,
+    /// Fraction of capacity new chunks are seeded with, scaled by each terrain's regeneration profile
+    pub initial_resource_seed_fraction: f32,
+    /// New chunks whose generation may start per tick; the rest wait their turn
+    pub max_chunk_creations_per_tick: usize,
+    /// How much higher or forested neighbors cut a cell's sunlight regeneration (0 = no shading)
+    pub terrain_shading_strength: f32,
+    /// When chunks organisms wander into get created (frontier cells read empty until then)
+    pub frontier_policy: FrontierPolicy,
 
     // Resource decay rates
     pub plant_decay_rate: f32,
@@ -19,25 +33,234 @@ pub struct EcosystemTuning {
     pub detritus_decay_rate: f32,
     pub prey_decay_rate: f32,
 
+    // Diffusion between neighboring cells, indexed by `ResourceType::index()`
+    /// How quickly each resource evens out with its neighbors (0 = doesn't spread)
+    pub resource_diffusion_rates: [f32; RESOURCE_TYPE_COUNT],
+    /// How strongly each resource is carried downwind (0 = isotropic diffusion)
+    pub resource_wind_response: [f32; RESOURCE_TYPE_COUNT],
+    /// Fraction of a cell's water that runs to lower neighbors per second (0 = no hydrology)
+    pub water_flow_strength: f32,
+
     // Consumption rates
     pub consumption_rate_base: f32,
     pub energy_conversion_efficiency: f32,
     pub decomposer_efficiency_multiplier: f32,
+    /// Grazing radius (in cells) per unit of body size; organisms eat from every cell in reach
+    pub grazing_radius_per_size: f32,
+    /// Eaters contesting a cell split it in proportion to demand (false = first come, first served)
+    pub fair_cell_sharing: bool,
+    /// Producer energy per unit of sunlight absorbed
+    pub photosynthesis_sunlight_efficiency: f32,
+    /// Water consumed per unit of sunlight (photosynthesis stalls without it)
+    pub photosynthesis_water_per_sunlight: f32,
+    /// Mineral consumed per unit of sunlight (photosynthesis stalls without it)
+    pub photosynthesis_mineral_per_sunlight: f32,
+    /// Stomach capacity (energy value of undigested food) per unit of body size
+    pub stomach_capacity_per_size: f32,
+    /// Fraction of stomach capacity digested into energy per second
+    pub digestion_rate: f32,
+    /// Food density below which a chased food cell is abandoned for another source
+    pub chase_retarget_threshold: f32,
+
+    // Movement
+    /// Wander heading random-walk strength (radians per sqrt-second)
+    pub wander_jitter: f32,
+    /// Share of speed a fully heat- or cold-stressed organism puts into heading for comfort (0 = off)
+    pub thermotaxis_strength: f32,
+    /// How far away (in cells) organisms sample temperatures when seeking comfort
+    pub thermotaxis_sample_distance: f32,
+
+    // Sensory occlusion (line of sight through terrain; costs a ray march per detection)
+    pub sensory_occlusion: bool,
+    /// Height above the sight line (fraction of the elevation range) at which a cell blocks sight
+    pub occlusion_ridge_height: f32,
+    /// Share of sensory range lost per Forest cell between an organism and its target
+    pub occlusion_forest_density: f32,
+
+    // Territory scent marking (see `ScentMarks`)
+    /// Scent a consumer leaves on its cell each time it marks (0 = no territories)
+    pub scent_mark_strength: f32,
+    /// Ticks between marks
+    pub scent_mark_interval: u64,
+    /// Seconds for an unrefreshed mark to fade to half strength
+    pub scent_half_life_seconds: f32,
+    /// Foreign scent strength above which organisms feel like intruders
+    pub territory_intrusion_threshold: f32,
+    /// Steering out of foreign territory, as a fraction of speed on a fully marked cell
+    pub territory_avoidance_strength: f32,
+    /// Stress from standing on a fully marked foreign cell (feeds `Stress`)
+    pub territorial_stress: f32,
+
+    // Herd detection (see `Herds`; switched on by `LoggingConfig::herds_enabled`)
+    /// Same-species organisms this close are linked into the same herd
+    pub herd_link_distance: f32,
+    /// Smallest linked group counted as a herd
+    pub herd_min_size: usize,
+
+    // Behavior level of detail (see `BehaviorLod`)
+    /// Ticks between behavior updates for organisms far from the camera (1 = every tick)
+    pub behavior_lod_interval: u64,
+    /// Distance from the camera within which organisms always update every tick
+    pub behavior_lod_distance: f32,
+
+    // Resource perception
+    /// Density at which each resource type (by `ResourceType` index) is noticed in a cell;
+    /// food is only targeted at twice its threshold
+    pub resource_detection_thresholds: [f32; RESOURCE_TYPE_COUNT],
+    /// How far perception acuity moves the thresholds: the sharpest organisms see down to
+    /// `1 - strength` of them, the dullest need `1 + strength` (0 = acuity ignored)
+    pub perception_acuity_strength: f32,
+    /// Radius cap (in cells) of the per-organism resource scan (0 = no cap); organisms are still
+    /// sensed, and sensing is still paid for, over the full sensory range
+    pub resource_scan_range_cap: f32,
+
+    // Decomposers and carcasses
+    /// Decomposer movement speed relative to their genetic speed
+    pub decomposer_speed_multiplier: f32,
+    /// Decomposer base metabolism relative to other organisms
+    pub decomposer_metabolism_multiplier: f32,
+    /// Detritus left where an organism dies, per unit of body size
+    pub carcass_detritus_per_size: f32,
+    /// Detritus left per unit of energy the organism still had stored when it died
+    pub carcass_detritus_per_energy: f32,
+    /// Seconds a carcass stays fresh enough to draw decomposers
+    pub carcass_freshness_window: f32,
+    /// Seconds over which a carcass releases its detritus (0 = all at once on death)
+    pub carcass_decay_seconds: f32,
+    /// Detritus excreted into the current cell per unit of energy metabolized (0 = none)
+    pub excretion_fraction: f32,
 
     // Metabolism tuning
     pub base_metabolism_multiplier: f32,
     pub movement_cost_multiplier: f32,
+    /// Base metabolic cost while Resting, relative to the normal cost
+    pub resting_metabolism_multiplier: f32,
+    /// Energy per second per unit of sensory range (0 = sensing is free)
+    pub sensing_cost_per_range: f32,
+    /// Energy per second per unit of max energy (large reserves cost upkeep; 0 = storage is free)
+    pub energy_storage_maintenance: f32,
+
+    // Crowding stress (density dependence beyond resource competition)
+    /// Distance within which neighbors count toward crowding (0 = no crowding stress)
+    pub crowding_radius: f32,
+    /// Neighbors a size-1 organism tolerates; the threshold shrinks as size grows
+    pub crowding_neighbor_threshold: f32,
+    /// Energy per second lost per unit of crowding level
+    pub crowding_energy_penalty: f32,
+    /// Reproduction chance lost per unit of crowding level
+    pub crowding_reproduction_suppression: f32,
 
     // Reproduction tuning
     pub reproduction_chance_multiplier: f32,
     pub min_reproduction_cooldown: f32,
     pub max_reproduction_cooldown: f32,
+    /// Maximum distance between partners for a mating handshake to progress
+    pub mating_contact_distance: f32,
+    /// Assign Male/Female at spawn and require opposite sexes to mate (females pay for the clutch)
+    pub sexes_enabled: bool,
+    /// How a clutch's energy is divided between the offspring
+    pub clutch_energy_strategy: ClutchEnergyStrategy,
+    /// Exponential strategy: each offspring's share relative to its older sibling's
+    pub clutch_exponential_ratio: f32,
+    /// Fixed-cost strategy: energy each offspring costs, as a fraction of its max energy
+    pub clutch_fixed_cost_fraction: f32,
+    /// How far from the parent newborns land, for a middling exploration drive
+    pub offspring_dispersal_radius: f32,
+    /// How strongly exploration drive widens or narrows dispersal (0 = same radius for everyone)
+    pub dispersal_exploration_weight: f32,
+
+    // Predation (consumers killing the organisms they hunt)
+    pub predation_enabled: bool,
+    /// Let consumers hunt members of their own species (cannibalism); off isolates
+    /// interspecific predator-prey dynamics
+    pub intraspecific_predation: bool,
+    /// Capture chance of a strike between evenly matched predator and prey
+    pub base_capture_chance: f32,
+    /// Maximum predator-prey distance for a strike
+    pub predation_contact_distance: f32,
+    /// Seconds a predator waits between strikes
+    pub predation_strike_cooldown: f32,
+    /// Energy a missed strike costs a predator of middling aggression
+    pub failed_strike_energy_cost: f32,
+    /// Health lost per unit of prey size when struck prey fights back (middling aggression)
+    pub counter_damage_per_size: f32,
+    /// Health per unit of size
+    pub health_per_size: f32,
+    /// Health a strike takes from prey per unit of predator size (middling aggression)
+    pub strike_damage_per_size: f32,
+    /// Fraction of max health regained per second while fed (energy at least half full)
+    pub health_regeneration_rate: f32,
+    /// Share of speed lost at zero health (wounds slow organisms in proportion to damage)
+    pub wound_speed_penalty: f32,
+
+    // Death
+    /// Organisms die at or below this fraction of max energy (0 = only at exactly zero)
+    pub death_energy_fraction: f32,
 
     // Spawn parameters
     pub initial_spawn_count: usize,
+    /// Founders start with this fraction of their max energy
+    pub initial_organism_energy_fraction: f32,
+    /// Newborns start with at least this fraction of their max energy, whatever the parents gave
+    pub offspring_min_energy_fraction: f32,
+    /// Ticks after birth during which basal, sensing, crowding and storage upkeep are waived
+    /// (movement is still paid), so newborns get a chance to reach food (0 = no grace)
+    pub newborn_grace_ticks: u32,
+    /// Base seed for per-organism random streams (see `RngSeed`)
+    pub organism_seed: u64,
+    /// Respawn a fresh founder population when everything dies (otherwise the sim pauses)
+    pub restart_on_extinction: bool,
+
+    // Parental care (see `ParentalCare`)
+    /// Ticks a parent with full parental care tends its newborns; scaled by the parent's trait
+    /// (0 = offspring are abandoned at birth)
+    pub parental_care_ticks: u32,
+    /// Parents below this parental care trait don't tend their brood at all
+    pub parental_care_min_trait: f32,
+    /// Energy per second a parent with full care passes to each tended offspring
+    pub parental_care_energy_rate: f32,
+    /// Parents stop sharing energy below this fraction of their own max energy
+    pub parental_care_reserve: f32,
+    /// Distance from its brood beyond which a tending parent is pulled back toward it
+    pub parental_care_radius: f32,
     
     // Speciation
     pub speciation_threshold: f32,
+    /// Gene weighting used when comparing genomes to species centroids
+    pub speciation_distance_metric: GeneticDistanceMetric,
+
+    // Mutation operator mix (relative weights, uniform-only by default)
+    pub mutation_uniform_weight: f32,
+    pub mutation_gaussian_weight: f32,
+    pub mutation_duplication_weight: f32,
+    pub mutation_macro_weight: f32,
+    pub mutation_uniform_range: f32,
+    pub mutation_gaussian_sigma: f32,
+
+    // Stress-induced mutagenesis (sustained hunger or heat/cold raises offspring mutation rates)
+    /// Extra mutation rate at full stress, as a multiple of the genome's rate (0 = off)
+    pub stress_mutagenesis_strength: f32,
+    /// Seconds of stress it takes for the stress level to catch up with conditions
+    pub stress_memory_seconds: f32,
+    /// Energy fraction below which an organism counts as starving
+    pub stress_energy_threshold: f32,
+    /// Distance from the ideal cell temperature (0.5) that causes no thermal stress
+    pub thermal_comfort_range: f32,
+
+    // Alarm propagation (herd danger sharing)
+    pub alarm_radius: f32,
+    pub alarm_threat_boost: f32,
+
+    // Mobbing (group defense instead of flight)
+    /// Same-species allies in sensory range needed before a consumer mobs (at least 1)
+    pub mobbing_ally_threshold: u32,
+    /// Mean of aggression and boldness needed to mob rather than flee
+    pub mobbing_trait_threshold: f32,
+
+    // Impassable terrain per organism type (steered around, never entered)
+    pub producer_impassable_terrain: Vec<TerrainType>,
+    pub consumer_impassable_terrain: Vec<TerrainType>,
+    pub decomposer_impassable_terrain: Vec<TerrainType>,
 }
 
 impl Default for EcosystemTuning {
@@ -51,6 +274,11 @@ impl Default for EcosystemTuning {
             mineral_regeneration_rate: 0.06,    // Increased from 0.05
             detritus_regeneration_rate: 0.04,   // Increased from 0.03 (more detritus = more decomposers)
             prey_regeneration_rate: 0.03,       // Increased from 0.02 (prey should regenerate from death)
+            initial_world_radius: 1,
+            initial_resource_seed_fraction: 0.5, // New chunks start half-stocked instead of barren
+            max_chunk_creations_per_tick: 2,     // Bounds frame time while organisms spread out
+            terrain_shading_strength: 0.5, // A cell ringed by peaks or canopy keeps half its sunlight
+            frontier_policy: FrontierPolicy::Queued,
 
             // Decay rates (resources naturally decay over time)
             // Balanced to prevent resource accumulation while allowing regeneration
@@ -61,31 +289,182 @@ impl Default for EcosystemTuning {
             detritus_decay_rate: 0.012,         // Reduced from 0.015 (detritus persists for decomposers)
             prey_decay_rate: 0.025,           // Increased from 0.02 (prey moves/dies quickly)
 
+            // Diffusion [Plant, Mineral, Sunlight, Water, Detritus, Prey]
+            // Sunlight and water spread fastest; minerals are nearly inert
+            resource_diffusion_rates: [0.1, 0.005, 0.2, 0.15, 0.05, 0.1],
+            // Light isn't blown around; airborne/waterborne resources follow the wind
+            resource_wind_response: [0.5, 0.0, 0.0, 0.5, 0.5, 0.5],
+            water_flow_strength: 0.2, // Water runs downhill into basins and oceans
+
             // Consumption (balanced with regeneration rates)
             // Lower consumption ensures resources can regenerate
             consumption_rate_base: 4.0,         // Reduced from 5.0 to balance with regeneration
             energy_conversion_efficiency: 0.35, // Increased from 0.3 (organisms get more energy)
             decomposer_efficiency_multiplier: 0.6, // Increased from 0.5 (decomposers are more efficient)
+            grazing_radius_per_size: 1.0, // Size 3 organisms reach ~3 cells out
+            fair_cell_sharing: true,
+            // Photosynthesis: sunlight is the energy source, water/mineral are limiting cofactors
+            photosynthesis_sunlight_efficiency: 0.6,
+            photosynthesis_water_per_sunlight: 0.5,
+            photosynthesis_mineral_per_sunlight: 0.2,
+            // Digestion: food fills the stomach and converts to energy gradually
+            stomach_capacity_per_size: 10.0,
+            digestion_rate: 0.2,
+            chase_retarget_threshold: 0.2, // Matches the minimum density worth chasing
+
+            // Movement: each organism's heading drifts independently
+            wander_jitter: 1.5,
+            // Thermotaxis: thermally stressed wanderers and migrants drift toward comfort
+            thermotaxis_strength: 0.5,
+            thermotaxis_sample_distance: 6.0,
+
+            // Sensory occlusion (off by default)
+            sensory_occlusion: false,
+            occlusion_ridge_height: 0.05,
+            occlusion_forest_density: 0.25, // Four trees deep hides anything
+
+            // Territory scent marking (off by default)
+            scent_mark_strength: 0.0,
+            scent_mark_interval: 30, // Twice a second
+            scent_half_life_seconds: 60.0,
+            territory_intrusion_threshold: 0.3,
+            territory_avoidance_strength: 0.5,
+            territorial_stress: 0.5,
+
+            // Herd detection
+            herd_link_distance: 8.0,
+            herd_min_size: 3,
+
+            // Behavior level of detail (off by default)
+            behavior_lod_interval: 1,
+            behavior_lod_distance: 300.0,
+
+            // Resource perception (acuity off by default)
+            resource_detection_thresholds: [0.1; RESOURCE_TYPE_COUNT],
+            perception_acuity_strength: 0.0,
+            resource_scan_range_cap: 0.0, // Uncapped: resources are scanned over the full sensory range
+
+            // Decomposers: slow, frugal, and drawn to fresh carcasses
+            decomposer_speed_multiplier: 0.6,
+            decomposer_metabolism_multiplier: 0.6,
+            carcass_detritus_per_size: 0.3,
+            carcass_detritus_per_energy: 0.0, // Tissue only, as before
+            carcass_freshness_window: 30.0,
+            carcass_decay_seconds: 0.0,
+            excretion_fraction: 0.05, // A trickle of waste feeds decomposers between deaths
 
             // Metabolism (balanced to prevent energy drain)
             base_metabolism_multiplier: 0.9,    // Reduced from 1.0 (organisms use less energy)
             movement_cost_multiplier: 0.85,      // Reduced from 1.0 (movement costs less)
+            resting_metabolism_multiplier: 0.5,  // Resting halves base cost so rest aids recovery
+            sensing_cost_per_range: 0.0,
+            energy_storage_maintenance: 0.0002, // A 100-energy reserve costs about a base metabolism
+
+            // Crowding stress
+            crowding_radius: 4.0,
+            crowding_neighbor_threshold: 6.0,
+            crowding_energy_penalty: 0.5,
+            crowding_reproduction_suppression: 0.5, // Reproduction stops at three times the threshold
 
             // Reproduction (tuned for stability - prevents instant spawning)
             reproduction_chance_multiplier: 0.03, // 3% chance per frame when conditions met (reduced from 10%)
             min_reproduction_cooldown: 600.0,    // Minimum 600 ticks (~10 seconds at 60 FPS)
             max_reproduction_cooldown: 3600.0,  // Maximum 3600 ticks (~60 seconds at 60 FPS)
+            mating_contact_distance: 3.0,
+            sexes_enabled: false, // Hermaphroditic by default
+            clutch_energy_strategy: ClutchEnergyStrategy::Even,
+            clutch_exponential_ratio: 0.5, // Each younger sibling gets half the previous share
+            clutch_fixed_cost_fraction: 0.3,
+            offspring_dispersal_radius: 5.0,   // The original ±5 unit box
+            dispersal_exploration_weight: 0.5, // Drive 0 disperses half as far, drive 1 half again as far
+
+            // Predation (off by default - prey organisms are otherwise only chased)
+            predation_enabled: false,
+            intraspecific_predation: true,
+            base_capture_chance: 0.35,
+            predation_contact_distance: 3.0,
+            predation_strike_cooldown: 2.0,
+            failed_strike_energy_cost: 3.0, // Aggression has a price even when it pays off less often
+            counter_damage_per_size: 8.0,
+            health_per_size: 50.0,
+            strike_damage_per_size: 40.0, // An even match takes two strikes; bigger predators kill outright
+            health_regeneration_rate: 0.01,
+            wound_speed_penalty: 0.5,
+
+            // Death (near-dead organisms shouldn't linger in the spatial hash)
+            death_energy_fraction: 0.01,
 
             // Spawn
             initial_spawn_count: 100,
+            initial_organism_energy_fraction: 1.0, // Founders start full
+            offspring_min_energy_fraction: 0.15,
+            newborn_grace_ticks: 60, // One second at 60 ticks/s
+            organism_seed: 0x5EED_0F_11FE,
+            restart_on_extinction: false,
+
+            // Parental care (off by default)
+            parental_care_ticks: 0,
+            parental_care_min_trait: 0.2,
+            parental_care_energy_rate: 1.0,
+            parental_care_reserve: 0.4,
+            parental_care_radius: 6.0,
 
             // Speciation
             speciation_threshold: 0.15,
+            speciation_distance_metric: GeneticDistanceMetric::Rms,
+
+            // Mutation operators
+            mutation_uniform_weight: 1.0,
+            mutation_gaussian_weight: 0.0,
+            mutation_duplication_weight: 0.0,
+            mutation_macro_weight: 0.0,
+            mutation_uniform_range: 0.1,  // Original ±0.1 jitter
+            mutation_gaussian_sigma: 0.05,
+
+            // Stress-induced mutagenesis (off by default)
+            stress_mutagenesis_strength: 0.0,
+            stress_memory_seconds: 30.0,
+            stress_energy_threshold: 0.3,
+            thermal_comfort_range: 0.2, // Temperatures 0.3-0.7 are comfortable
+
+            // Alarm propagation
+            alarm_radius: 12.0,      // Same-species neighbors within this range hear the alarm
+            alarm_threat_boost: 3.0, // Seconds of threat memory seeded at full social sensitivity
+
+            // Mobbing
+            mobbing_ally_threshold: 3,
+            mobbing_trait_threshold: 0.7, // Only the boldest, most aggressive stand their ground
+
+            // Impassable terrain (producers barely move, so nothing blocks them)
+            producer_impassable_terrain: Vec::new(),
+            consumer_impassable_terrain: vec![TerrainType::Mountain, TerrainType::Volcanic],
+            decomposer_impassable_terrain: vec![TerrainType::Mountain, TerrainType::Volcanic],
         }
     }
 }
 
 impl EcosystemTuning {
+    /// Terrain an organism type can't move into
+    pub fn impassable_terrain(&self, organism_type: OrganismType) -> &[TerrainType] {
+        match organism_type {
+            OrganismType::Producer => &self.producer_impassable_terrain,
+            OrganismType::Consumer => &self.consumer_impassable_terrain,
+            OrganismType::Decomposer => &self.decomposer_impassable_terrain,
+        }
+    }
+
+    /// Mutation operator mix used for reproduction
+    pub fn mutation_operators(&self) -> crate::organisms::genetics::MutationOperators {
+        crate::organisms::genetics::MutationOperators {
+            uniform_weight: self.mutation_uniform_weight,
+            gaussian_weight: self.mutation_gaussian_weight,
+            duplication_weight: self.mutation_duplication_weight,
+            macro_weight: self.mutation_macro_weight,
+            uniform_range: self.mutation_uniform_range,
+            gaussian_sigma: self.mutation_gaussian_sigma,
+        }
+    }
+
     /// Create balanced preset for stable ecosystem
     pub fn balanced() -> Self {
         Self::default()
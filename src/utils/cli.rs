@@ -0,0 +1,33 @@
+/// Command-line option lookup shared by resources configurable at startup
+/// Options are `--name value` or `--name=value`; the last occurrence wins.
+pub fn option_value(name: &str) -> Option<String> {
+    let flag = format!("--{name}");
+    let prefix = format!("--{name}=");
+    let mut args = std::env::args().skip(1);
+    let mut value = None;
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            value = args.next();
+        } else if let Some(rest) = arg.strip_prefix(&prefix) {
+            value = Some(rest.to_string());
+        }
+    }
+    value
+}
+
+/// Two-valued option `--name a b` (last occurrence wins)
+pub fn option_pair(name: &str) -> Option<(String, String)> {
+    let flag = format!("--{name}");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let position = args.iter().rposition(|arg| *arg == flag)?;
+    match (args.get(position + 1), args.get(position + 2)) {
+        (Some(a), Some(b)) => Some((a.clone(), b.clone())),
+        _ => None,
+    }
+}
+
+/// Whether a bare `--name` flag was passed
+pub fn has_flag(name: &str) -> bool {
+    let flag = format!("--{name}");
+    std::env::args().skip(1).any(|arg| arg == flag)
+}
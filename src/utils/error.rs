@@ -0,0 +1,51 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Failure loading or saving simulation files (stats snapshots, genomes, configs)
+/// Callers log these and carry on with what they had; nothing here should end a run.
+#[derive(Debug)]
+pub enum SimError {
+    /// Reading or writing a file failed
+    Io { path: PathBuf, source: std::io::Error },
+    /// A file's contents couldn't be parsed, or a value couldn't be serialized
+    Serialization { path: PathBuf, message: String },
+    /// The contents parsed but aren't usable (out-of-range values, wrong sizes, ...)
+    Validation(String),
+}
+
+impl SimError {
+    pub fn io(path: &Path, source: std::io::Error) -> Self {
+        SimError::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    pub fn serialization(path: &Path, err: impl fmt::Display) -> Self {
+        SimError::Serialization {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimError::Io { path, source } => write!(f, "failed to access {}: {source}", path.display()),
+            SimError::Serialization { path, message } => {
+                write!(f, "failed to parse {}: {message}", path.display())
+            }
+            SimError::Validation(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for SimError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SimError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,12 @@
 /// Utility functions and helpers for the simulation
 pub mod spatial_hash;
+pub mod sim_time;
+pub mod cli;
+pub mod error;
+pub mod shutdown;
 pub use spatial_hash::*;
+pub use sim_time::*;
+pub use error::SimError;
 
 /// Convert between different coordinate systems
 pub mod coordinates {
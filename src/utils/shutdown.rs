@@ -0,0 +1,41 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by the Ctrl+C handler; every running app winds down once it's seen
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Exit status after an interrupt (128 + SIGINT, as shells report it)
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Turn Ctrl+C into a clean shutdown, so buffered CSV rows are flushed on the way out
+/// A second Ctrl+C exits immediately, for when the first one isn't getting through.
+pub fn install_interrupt_handler() {
+    let installed = ctrlc::set_handler(|| {
+        if INTERRUPTED.swap(true, Ordering::SeqCst) {
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        eprintln!("Interrupted, flushing logs (Ctrl+C again to exit immediately)");
+    });
+    if let Err(err) = installed {
+        warn!("Failed to install the Ctrl+C handler, an interrupt may lose buffered logs: {err}");
+    }
+}
+
+/// Whether Ctrl+C has been pressed
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Mark the process as interrupted, as the Ctrl+C handler does
+pub fn request_interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Send `AppExit` once interrupted; `flush_logs_on_exit` and the loggers' `Drop` then close
+/// every CSV before the process ends
+pub fn exit_on_interrupt(mut exit: EventWriter<AppExit>) {
+    if interrupted() {
+        exit.send(AppExit);
+    }
+}
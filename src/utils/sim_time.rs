@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Global simulation clock shared by every system that timestamps output
+///
+/// Advanced exactly once per simulation tick (in `First`, before any simulation system
+/// runs), so loggers, stats and climate all see the same tick number within a frame.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct SimTime {
+    /// Ticks simulated so far (the first tick is 1)
+    pub tick: u64,
+}
+
+impl SimTime {
+    /// True every `interval` ticks (always true for intervals of 0 or 1)
+    pub fn every(&self, interval: u64) -> bool {
+        interval <= 1 || self.tick % interval == 0
+    }
+}
+
+/// Advance the simulation clock by one tick
+pub fn advance_sim_time(mut sim_time: ResMut<SimTime>) {
+    sim_time.tick += 1;
+}
+
+/// Simulation speed against wall-clock time, averaged over a short rolling window
+/// Shown in the HUD; headless runs print it every `report_interval` seconds instead.
+#[derive(Resource, Debug)]
+pub struct SimRate {
+    /// Seconds of history the rates are averaged over
+    pub window: f32,
+    /// Tick rate that counts as real time (ratio 1.0)
+    pub real_time_tps: f32,
+    /// Seconds between console reports when there's no window (0 = never)
+    pub report_interval: f32,
+    /// Prefix for console reports, telling apart instances that share a process
+    pub label: Option<String>,
+    /// (wall-clock time, tick) at the end of each recent frame, oldest first
+    samples: VecDeque<(Instant, u64)>,
+    last_report: Option<Instant>,
+}
+
+impl Default for SimRate {
+    fn default() -> Self {
+        Self {
+            window: 2.0,
+            real_time_tps: 60.0,
+            report_interval: 10.0,
+            label: None,
+            samples: VecDeque::new(),
+            last_report: None,
+        }
+    }
+}
+
+impl SimRate {
+    /// `--rate-window <seconds>` and `--real-time-tps <ticks>` override the defaults
+    pub fn from_args() -> Self {
+        let mut rate = Self::default();
+        let positive = |name: &str| {
+            let value = crate::utils::cli::option_value(name)?;
+            match value.trim().parse::<f32>() {
+                Ok(parsed) if parsed > 0.0 => Some(parsed),
+                _ => {
+                    warn!("Invalid --{} '{}', using the default", name, value);
+                    None
+                }
+            }
+        };
+        if let Some(window) = positive("rate-window") {
+            rate.window = window;
+        }
+        if let Some(tps) = positive("real-time-tps") {
+            rate.real_time_tps = tps;
+        }
+        rate
+    }
+
+    /// Seconds covered by the buffered samples
+    fn span(&self) -> f32 {
+        match (self.samples.front(), self.samples.back()) {
+            (Some((first, _)), Some((last, _))) => last.duration_since(*first).as_secs_f32(),
+            _ => 0.0,
+        }
+    }
+
+    /// Simulation ticks per wall-clock second (0 until two frames have been measured)
+    pub fn ticks_per_second(&self) -> f32 {
+        let span = self.span();
+        match (self.samples.front(), self.samples.back()) {
+            (Some((_, first)), Some((_, last))) if span > 0.0 => (last - first) as f32 / span,
+            _ => 0.0,
+        }
+    }
+
+    /// Rendered frames per wall-clock second
+    pub fn frames_per_second(&self) -> f32 {
+        let span = self.span();
+        if span > 0.0 {
+            (self.samples.len() - 1) as f32 / span
+        } else {
+            0.0
+        }
+    }
+
+    /// Simulated time over wall-clock time (1.0 = real time at `real_time_tps`)
+    pub fn real_time_ratio(&self) -> f32 {
+        self.ticks_per_second() / self.real_time_tps.max(f32::EPSILON)
+    }
+
+    fn record(&mut self, now: Instant, tick: u64) {
+        self.samples.push_back((now, tick));
+        // Keep one sample older than the window so the span always covers it
+        while self.samples.len() > 2
+            && now.duration_since(self.samples[1].0).as_secs_f32() >= self.window
+        {
+            self.samples.pop_front();
+        }
+    }
+}
+
+/// Sample the clock once per frame; without a window, report the rate periodically
+pub fn measure_sim_rate(
+    mut rate: ResMut<SimRate>,
+    sim_time: Res<SimTime>,
+    window_query: Query<(), With<PrimaryWindow>>,
+) {
+    let now = Instant::now();
+    rate.record(now, sim_time.tick);
+
+    if !window_query.is_empty() || rate.report_interval <= 0.0 {
+        return;
+    }
+    let due = rate
+        .last_report
+        .map_or(true, |last| now.duration_since(last).as_secs_f32() >= rate.report_interval);
+    if due {
+        rate.last_report = Some(now);
+        let label = rate.label.as_deref().map(|label| format!("{label}: ")).unwrap_or_default();
+        info!(
+            "[RATE] {}Tick {} | {:.1} ticks/s | {:.1} frames/s | {:.2}x real time",
+            label,
+            sim_time.tick,
+            rate.ticks_per_second(),
+            rate.frames_per_second(),
+            rate.real_time_ratio()
+        );
+    }
+}
@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 use glam::Vec2;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default bucket edge length for the organism hash
+/// Sensory ranges span roughly 6-65 units, so a typical query covers 5x5 to 9x9 buckets.
+pub const DEFAULT_SPATIAL_HASH_CELL_SIZE: f32 = 16.0;
 
 /// Spatial hash grid for efficient neighbor queries
 /// Divides space into buckets for O(1) average-case neighbor lookups
@@ -15,10 +20,13 @@ pub struct SpatialHash {
 
 impl SpatialHash {
     /// Create a new spatial hash with the given cell size
-    /// Smaller cell_size = more precise but more buckets
+    /// A query of radius r visits (2 * ceil(r / cell_size) + 1)^2 buckets. Too small a cell
+    /// wastes time hashing mostly-empty buckets; too large returns so many candidates outside
+    /// the radius that queries degrade toward brute force. Sizes near a third to half of the
+    /// typical query radius tend to work best (see `benchmark_cell_sizes`).
     pub fn new(cell_size: f32) -> Self {
         Self {
-            cell_size,
+            cell_size: cell_size.max(0.1),
             buckets: HashMap::new(),
             entity_buckets: HashMap::new(),
         }
@@ -93,6 +101,41 @@ impl SpatialHash {
         results
     }
 
+    /// Find the closest entity within `radius` of `center` that passes `resolve`
+    /// `resolve` looks up the entity's components and returns its position if it qualifies.
+    /// Returns (entity, position, distance); ties go to the lower entity so results don't
+    /// depend on bucket iteration order.
+    pub fn nearest_where<F>(&self, center: Vec2, radius: f32, mut resolve: F) -> Option<(Entity, Vec2, f32)>
+    where
+        F: FnMut(Entity) -> Option<Vec2>,
+    {
+        let radius_sq = radius * radius;
+        let mut best: Option<(Entity, Vec2, f32)> = None;
+
+        for entity in self.query_radius(center, radius) {
+            let Some(position) = resolve(entity) else {
+                continue;
+            };
+            let distance_sq = (position - center).length_squared();
+            if distance_sq > radius_sq {
+                continue;
+            }
+
+            let closer = match best {
+                None => true,
+                Some((best_entity, _, best_distance_sq)) => {
+                    distance_sq < best_distance_sq
+                        || (distance_sq == best_distance_sq && entity < best_entity)
+                }
+            };
+            if closer {
+                best = Some((entity, position, distance_sq));
+            }
+        }
+
+        best.map(|(entity, position, distance_sq)| (entity, position, distance_sq.sqrt()))
+    }
+
     /// Get entities in a specific bucket
     pub fn get_bucket(&self, bucket: (i32, i32)) -> Option<&Vec<Entity>> {
         self.buckets.get(&bucket)
@@ -113,10 +156,156 @@ pub struct SpatialHashGrid {
 
 impl Default for SpatialHashGrid {
     fn default() -> Self {
+        Self::with_cell_size(DEFAULT_SPATIAL_HASH_CELL_SIZE)
+    }
+}
+
+impl SpatialHashGrid {
+    pub fn with_cell_size(cell_size: f32) -> Self {
         Self {
-            // Use cell size of 16 units - balances precision vs performance
-            // Organisms with sensory range up to 50 will check ~9 buckets
-            organisms: SpatialHash::new(16.0),
+            organisms: SpatialHash::new(cell_size),
+        }
+    }
+
+    /// Default grid with the cell size from `--spatial-hash-cell-size <units>`
+    /// Fixed for the run - entries are bucketed on insert, so it can't change afterwards.
+    pub fn from_args() -> Self {
+        let Some(value) = crate::utils::cli::option_value("spatial-hash-cell-size") else {
+            return Self::default();
+        };
+        match value.trim().parse::<f32>() {
+            Ok(cell_size) if cell_size > 0.0 => Self::with_cell_size(cell_size),
+            _ => {
+                warn!(
+                    "Invalid --spatial-hash-cell-size '{}', using {}",
+                    value, DEFAULT_SPATIAL_HASH_CELL_SIZE
+                );
+                Self::default()
+            }
         }
     }
 }
+
+/// Timing of one cell size in `benchmark_cell_sizes`
+pub struct CellSizeTiming {
+    pub cell_size: f32,
+    /// Inserting the whole population
+    pub build: Duration,
+    /// One radius query (plus exact distance filter) per organism
+    pub queries: Duration,
+    /// Mean entities returned per query, before the distance filter
+    pub mean_candidates: f32,
+    /// Mean entities actually within the radius
+    pub mean_neighbors: f32,
+}
+
+/// Sweep bucket sizes against a fixed, seeded population
+/// `population` organisms are scattered uniformly over a square of side `extent`, each
+/// querying with a radius drawn from `radius_range` (like sensory ranges do). The same
+/// positions and radii are used for every cell size, so the timings are comparable.
+pub fn benchmark_cell_sizes(
+    population: usize,
+    extent: f32,
+    radius_range: (f32, f32),
+    cell_sizes: &[f32],
+    seed: u64,
+) -> Vec<CellSizeTiming> {
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let organisms: Vec<(Entity, Vec2, f32)> = (0..population)
+        .map(|index| {
+            let position = Vec2::new(rng.f32() * extent, rng.f32() * extent);
+            let radius = radius_range.0 + rng.f32() * (radius_range.1 - radius_range.0);
+            (Entity::from_raw(index as u32), position, radius)
+        })
+        .collect();
+    let positions: HashMap<Entity, Vec2> = organisms
+        .iter()
+        .map(|(entity, position, _)| (*entity, *position))
+        .collect();
+
+    cell_sizes
+        .iter()
+        .map(|&cell_size| {
+            let mut hash = SpatialHash::new(cell_size);
+            let build_start = Instant::now();
+            for (entity, position, _) in &organisms {
+                hash.insert(*entity, *position);
+            }
+            let build = build_start.elapsed();
+
+            let mut candidates = 0usize;
+            let mut neighbors = 0usize;
+            let query_start = Instant::now();
+            for (_, position, radius) in &organisms {
+                let found = hash.query_radius(*position, *radius);
+                candidates += found.len();
+                neighbors += found
+                    .iter()
+                    .filter(|other| {
+                        positions
+                            .get(*other)
+                            .is_some_and(|other| other.distance_squared(*position) <= radius * radius)
+                    })
+                    .count();
+            }
+            let queries = query_start.elapsed();
+
+            let count = population.max(1) as f32;
+            CellSizeTiming {
+                cell_size,
+                build,
+                queries,
+                mean_candidates: candidates as f32 / count,
+                mean_neighbors: neighbors as f32 / count,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_with(points: &[(u32, Vec2)]) -> (SpatialHash, HashMap<Entity, Vec2>) {
+        let mut hash = SpatialHash::new(DEFAULT_SPATIAL_HASH_CELL_SIZE);
+        let mut positions = HashMap::new();
+        for (index, position) in points {
+            let entity = Entity::from_raw(*index);
+            hash.insert(entity, *position);
+            positions.insert(entity, *position);
+        }
+        (hash, positions)
+    }
+
+    #[test]
+    fn nearest_where_breaks_ties_toward_the_lower_entity() {
+        // Equidistant on opposite sides, in different buckets; inserted higher entity first
+        let (hash, positions) = hash_with(&[(9, Vec2::new(5.0, 0.0)), (4, Vec2::new(-5.0, 0.0))]);
+        let nearest = hash.nearest_where(Vec2::ZERO, 10.0, |entity| positions.get(&entity).copied());
+        assert_eq!(nearest, Some((Entity::from_raw(4), Vec2::new(-5.0, 0.0), 5.0)));
+    }
+
+    #[test]
+    fn nearest_where_skips_rejected_and_out_of_range_entities() {
+        let (hash, positions) = hash_with(&[
+            (1, Vec2::new(1.0, 0.0)),
+            (2, Vec2::new(3.0, 0.0)),
+            (3, Vec2::new(11.0, 0.0)),
+        ]);
+        let nearest = hash.nearest_where(Vec2::ZERO, 10.0, |entity| {
+            (entity != Entity::from_raw(1)).then(|| positions[&entity])
+        });
+        assert_eq!(nearest.map(|(entity, _, _)| entity), Some(Entity::from_raw(2)));
+    }
+
+    #[test]
+    fn nearest_where_is_none_without_candidates() {
+        let (empty, _) = hash_with(&[]);
+        assert_eq!(empty.nearest_where(Vec2::ZERO, 10.0, |_| Some(Vec2::ZERO)), None);
+
+        // Entity 3 shares a bucket with the query but lies outside the radius
+        let (hash, positions) = hash_with(&[(3, Vec2::new(11.0, 0.0))]);
+        assert_eq!(hash.nearest_where(Vec2::ZERO, 10.0, |entity| positions.get(&entity).copied()), None);
+        assert_eq!(hash.nearest_where(Vec2::new(11.0, 0.0), 10.0, |_| None), None);
+    }
+}
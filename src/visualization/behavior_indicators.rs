@@ -0,0 +1,102 @@
+use crate::organisms::{Alive, Behavior, BehaviorState};
+use crate::visualization::organisms::OrganismSprite;
+use bevy::prelude::*;
+
+/// Edge length of the behavior indicator dot
+const BEHAVIOR_INDICATOR_SIZE: f32 = 3.0;
+
+/// Every behavior state, in legend order
+pub const BEHAVIOR_STATES: [BehaviorState; 8] = [
+    BehaviorState::Wandering,
+    BehaviorState::Chasing,
+    BehaviorState::Eating,
+    BehaviorState::Fleeing,
+    BehaviorState::Mating,
+    BehaviorState::Resting,
+    BehaviorState::Migrating,
+    BehaviorState::Mobbing,
+];
+
+/// Indicator dot showing an organism's behavior state (child of organism sprite)
+#[derive(Component)]
+pub struct BehaviorIndicator {
+    pub organism_entity: Entity,
+    /// State the indicator currently shows
+    pub state: BehaviorState,
+}
+
+/// Indicator color for a behavior state
+pub fn behavior_state_color(state: BehaviorState) -> Color {
+    match state {
+        BehaviorState::Wandering => Color::rgba(0.8, 0.9, 1.0, 0.6), // Pale blue
+        BehaviorState::Chasing => Color::rgb(1.0, 0.55, 0.0),       // Orange
+        BehaviorState::Eating => Color::rgb(1.0, 0.9, 0.2),         // Yellow
+        BehaviorState::Fleeing => Color::rgb(1.0, 0.1, 0.1),        // Red
+        BehaviorState::Mating => Color::rgb(1.0, 0.45, 0.75),       // Pink
+        BehaviorState::Resting => Color::rgb(0.55, 0.55, 0.55),     // Gray
+        BehaviorState::Migrating => Color::rgb(0.2, 0.9, 0.9),      // Cyan
+        BehaviorState::Mobbing => Color::rgb(0.6, 0.3, 1.0),        // Violet
+    }
+}
+
+/// Display name for a behavior state
+pub fn behavior_state_label(state: BehaviorState) -> &'static str {
+    match state {
+        BehaviorState::Wandering => "Wandering",
+        BehaviorState::Chasing => "Chasing",
+        BehaviorState::Eating => "Eating",
+        BehaviorState::Fleeing => "Fleeing",
+        BehaviorState::Mating => "Mating",
+        BehaviorState::Resting => "Resting",
+        BehaviorState::Migrating => "Migrating",
+        BehaviorState::Mobbing => "Mobbing",
+    }
+}
+
+/// Spawn indicators for new organism sprites and recolor them when the behavior state changes
+pub fn update_behavior_indicators(
+    mut commands: Commands,
+    sprite_query: Query<(Entity, &OrganismSprite)>,
+    behavior_query: Query<&Behavior, With<Alive>>,
+    mut indicator_query: Query<(&mut BehaviorIndicator, &mut Sprite), Without<OrganismSprite>>,
+) {
+    let mut indicated = std::collections::HashSet::new();
+
+    // Only touch the sprite when the state actually differs from what's shown
+    for (mut indicator, mut sprite) in indicator_query.iter_mut() {
+        indicated.insert(indicator.organism_entity);
+        if let Ok(behavior) = behavior_query.get(indicator.organism_entity) {
+            if behavior.state != indicator.state {
+                indicator.state = behavior.state;
+                sprite.color = behavior_state_color(behavior.state);
+            }
+        }
+    }
+
+    for (sprite_entity, organism_sprite) in sprite_query.iter() {
+        if indicated.contains(&organism_sprite.organism_entity) {
+            continue;
+        }
+        let Ok(behavior) = behavior_query.get(organism_sprite.organism_entity) else {
+            continue;
+        };
+
+        commands.entity(sprite_entity).with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: behavior_state_color(behavior.state),
+                        custom_size: Some(Vec2::splat(BEHAVIOR_INDICATOR_SIZE)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(Vec3::new(0.0, 0.0, 0.2)), // Above disease overlay
+                    ..default()
+                },
+                BehaviorIndicator {
+                    organism_entity: organism_sprite.organism_entity,
+                    state: behavior.state,
+                },
+            ));
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use crate::organisms::{Alive, BehaviorLod, Position, TrackedOrganisms};
 use bevy::prelude::*;
 
 /// Camera configuration
@@ -8,6 +9,10 @@ pub struct CameraConfig {
     pub min_zoom: f32,
     pub max_zoom: f32,
     pub default_zoom: f32,
+    /// How quickly the camera closes on a followed organism (per second; higher = snappier)
+    pub follow_smoothing: f32,
+    /// Screen pixels a followed organism can drift from the center before the camera moves
+    pub follow_dead_zone: f32,
 }
 
 impl Default for CameraConfig {
@@ -18,10 +23,44 @@ impl Default for CameraConfig {
             min_zoom: 0.1,
             max_zoom: 5.0,
             default_zoom: 1.0,
+            follow_smoothing: 5.0,
+            follow_dead_zone: 40.0,
         }
     }
 }
 
+impl CameraConfig {
+    pub fn from_args() -> Self {
+        let mut config = Self::default();
+        let non_negative = |name: &str| {
+            let value = crate::utils::cli::option_value(name)?;
+            match value.trim().parse::<f32>() {
+                Ok(parsed) if parsed >= 0.0 => Some(parsed),
+                _ => {
+                    warn!("Invalid --{} '{}', using the default", name, value);
+                    None
+                }
+            }
+        };
+        if let Some(smoothing) = non_negative("follow-smoothing") {
+            config.follow_smoothing = smoothing;
+        }
+        if let Some(dead_zone) = non_negative("follow-dead-zone") {
+            config.follow_dead_zone = dead_zone;
+        }
+        config
+    }
+}
+
+/// Camera follow mode: keeps the most recently tracked organism in view
+/// Toggled with F; panning, resetting the camera or the organism dying returns to free camera.
+#[derive(Resource, Default)]
+pub struct CameraFollow {
+    pub enabled: bool,
+    /// Organism being followed
+    target: Option<Entity>,
+}
+
 /// Handle camera controls (panning and zooming)
 /// Using Bevy 0.12 Input<KeyCode> API
 pub fn handle_camera_controls(
@@ -83,3 +122,81 @@ pub fn handle_camera_controls(
     }
 }
 
+
+/// Toggle follow mode and move the camera after the followed organism
+pub fn follow_tracked_organism(
+    mut camera_query: Query<(&mut Transform, &OrthographicProjection), With<Camera2d>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    config: Res<CameraConfig>,
+    mut follow: ResMut<CameraFollow>,
+    tracked: Res<TrackedOrganisms>,
+    position_query: Query<&Position, With<Alive>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F) {
+        follow.enabled = !follow.enabled;
+        follow.target = None;
+        info!("[CAMERA] Follow mode {}", if follow.enabled { "on" } else { "off" });
+    }
+    if !follow.enabled {
+        return;
+    }
+
+    // Manual camera moves hand control back
+    let manual = [KeyCode::W, KeyCode::A, KeyCode::S, KeyCode::D]
+        .into_iter()
+        .any(|key| keyboard_input.pressed(key))
+        || keyboard_input.just_pressed(KeyCode::R);
+    if manual {
+        follow.enabled = false;
+        info!("[CAMERA] Follow mode off (manual camera)");
+        return;
+    }
+
+    // Follow the most recently tracked organism (clicking another switches to it)
+    let newest = tracked.entities().last();
+    if let Some(target) = follow.target {
+        if position_query.get(target).is_err() {
+            follow.enabled = false;
+            follow.target = None;
+            info!("[CAMERA] Followed organism is gone, back to free camera");
+            return;
+        }
+    }
+    if newest.is_some() && newest != follow.target {
+        follow.target = newest;
+    }
+    let Some(position) = follow.target.and_then(|target| position_query.get(target).ok()) else {
+        return;
+    };
+
+    let Ok((mut transform, projection)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    // Only the part of the offset outside the dead zone is chased
+    let offset = position.as_vec2() - transform.translation.truncate();
+    let dead_zone = config.follow_dead_zone * projection.scale;
+    let distance = offset.length();
+    if distance <= dead_zone {
+        return;
+    }
+    let excess = offset * ((distance - dead_zone) / distance);
+    let blend = if config.follow_smoothing > 0.0 {
+        1.0 - (-config.follow_smoothing * time.delta_seconds()).exp()
+    } else {
+        1.0
+    };
+    transform.translation.x += excess.x * blend;
+    transform.translation.y += excess.y * blend;
+}
+
+/// Point behavior level of detail at the camera, so organisms on screen decide every tick
+pub fn update_behavior_focus(
+    camera_query: Query<&Transform, With<Camera2d>>,
+    mut lod: ResMut<BehaviorLod>,
+) {
+    if let Ok(transform) = camera_query.get_single() {
+        lod.focus = Some(transform.translation.truncate());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::organisms::LoggingConfig;
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::{PresentMode, PrimaryWindow};
+use std::time::Instant;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// Ticks between progress lines while fast-forwarding
+const FAST_FORWARD_PROGRESS_INTERVAL: u64 = 5000;
+
+/// Simulated time per tick while fast-forwarding (one frame at the normal 60 Hz)
+const FAST_FORWARD_TICK_SECONDS: f64 = 1.0 / 60.0;
+
+/// Runtime handle on the console log filter installed in `main`
+#[derive(Resource, Clone)]
+pub struct LogFilterHandle {
+    pub handle: reload::Handle<EnvFilter, Registry>,
+    /// Directives the filter was built from (restored after a fast-forward)
+    pub directives: String,
+}
+
+impl LogFilterHandle {
+    fn set(&self, directives: &str) {
+        let filter = EnvFilter::try_new(directives).unwrap_or_else(|_| EnvFilter::new("info"));
+        if let Err(err) = self.handle.reload(filter) {
+            warn!("Failed to change log filter: {err}");
+        }
+    }
+}
+
+/// `--fast-forward <tick>`: simulate unrendered and uncapped up to a target tick,
+/// then hand back to the normal interactive view
+#[derive(Resource, Default)]
+pub struct FastForward {
+    /// Tick to run to (None once reached, or when not fast-forwarding)
+    pub target_tick: Option<u64>,
+    /// Pause the snapshot and tracking CSV loggers until the target tick
+    pub silence_loggers: bool,
+    /// Settings overridden for the fast-forward, restored on arrival
+    saved: Option<SavedSettings>,
+}
+
+struct SavedSettings {
+    present_mode: Option<PresentMode>,
+    title: Option<String>,
+    snapshots_enabled: bool,
+    tracking_enabled: bool,
+    time_strategy: TimeUpdateStrategy,
+    started_tick: u64,
+    started_at: Instant,
+}
+
+impl FastForward {
+    /// `--fast-forward <tick>` sets the target; `--fast-forward-keep-logs` leaves the CSV loggers running
+    pub fn from_args() -> Self {
+        use crate::utils::cli::{has_flag, option_value};
+
+        let target_tick = option_value("fast-forward").and_then(|value| match value.trim().parse() {
+            Ok(tick) => Some(tick),
+            Err(_) => {
+                warn!("Invalid --fast-forward '{}', running normally", value);
+                None
+            }
+        });
+        Self {
+            target_tick,
+            silence_loggers: !has_flag("fast-forward-keep-logs"),
+            saved: None,
+        }
+    }
+}
+
+/// Run condition: the simulation is still fast-forwarding (skip rendering work)
+pub fn fast_forwarding(fast_forward: Res<FastForward>) -> bool {
+    fast_forward.target_tick.is_some()
+}
+
+/// Enter fast-forward on the first tick and restore rendering, frame pacing, loggers
+/// and console output once the target tick is reached
+pub fn update_fast_forward(
+    mut fast_forward: ResMut<FastForward>,
+    sim_time: Res<SimTime>,
+    mut logging: ResMut<LoggingConfig>,
+    log_filter: Option<Res<LogFilterHandle>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    let Some(target_tick) = fast_forward.target_tick else {
+        return;
+    };
+
+    if fast_forward.saved.is_none() {
+        if sim_time.tick >= target_tick {
+            fast_forward.target_tick = None;
+            return;
+        }
+
+        let mut window = windows.get_single_mut().ok();
+        let saved = SavedSettings {
+            present_mode: window.as_ref().map(|window| window.present_mode),
+            title: window.as_ref().map(|window| window.title.clone()),
+            snapshots_enabled: logging.snapshots_enabled,
+            tracking_enabled: logging.tracking_enabled,
+            // Uncapped frames are far shorter than normal ones, so wall-clock dt would shrink
+            // every dt-scaled update; a fixed step keeps each tick worth one normal frame
+            time_strategy: std::mem::replace(
+                &mut *time_strategy,
+                TimeUpdateStrategy::ManualDuration(std::time::Duration::from_secs_f64(
+                    FAST_FORWARD_TICK_SECONDS,
+                )),
+            ),
+            started_tick: sim_time.tick,
+            started_at: Instant::now(),
+        };
+
+        // No vsync and no active cameras: ticks run as fast as the simulation allows
+        if let Some(window) = window.as_mut() {
+            window.present_mode = PresentMode::AutoNoVsync;
+            window.title = format!("{} (fast-forwarding to tick {target_tick})", window.title);
+        }
+        for mut camera in cameras.iter_mut() {
+            camera.is_active = false;
+        }
+        if fast_forward.silence_loggers {
+            logging.snapshots_enabled = false;
+            logging.tracking_enabled = false;
+        }
+
+        info!(
+            "[FAST-FORWARD] Running to tick {target_tick} without rendering{}",
+            if fast_forward.silence_loggers { ", CSV loggers paused" } else { "" }
+        );
+        // Keep warnings and our own progress lines, drop the per-event chatter
+        if let Some(log_filter) = &log_filter {
+            log_filter.set(&format!("warn,{}=info", module_path!()));
+        }
+        fast_forward.saved = Some(saved);
+        return;
+    }
+
+    if sim_time.tick < target_tick {
+        if sim_time.every(FAST_FORWARD_PROGRESS_INTERVAL) {
+            info!("[FAST-FORWARD] Tick {} / {target_tick}", sim_time.tick);
+        }
+        return;
+    }
+
+    let Some(saved) = fast_forward.saved.take() else {
+        return;
+    };
+    fast_forward.target_tick = None;
+
+    if let Ok(mut window) = windows.get_single_mut() {
+        if let Some(present_mode) = saved.present_mode {
+            window.present_mode = present_mode;
+        }
+        if let Some(title) = saved.title {
+            window.title = title;
+        }
+    }
+    for mut camera in cameras.iter_mut() {
+        camera.is_active = true;
+    }
+    *time_strategy = saved.time_strategy;
+    if fast_forward.silence_loggers {
+        logging.snapshots_enabled = saved.snapshots_enabled;
+        logging.tracking_enabled = saved.tracking_enabled;
+    }
+    if let Some(log_filter) = &log_filter {
+        log_filter.set(&log_filter.directives);
+    }
+
+    let elapsed = saved.started_at.elapsed().as_secs_f32();
+    let ticks = sim_time.tick - saved.started_tick;
+    info!(
+        "[FAST-FORWARD] Reached tick {} in {:.1}s ({:.0} ticks/s), resuming normal speed",
+        sim_time.tick,
+        elapsed,
+        ticks as f32 / elapsed.max(f32::EPSILON)
+    );
+}
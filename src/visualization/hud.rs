@@ -0,0 +1,140 @@
+use crate::visualization::behavior_indicators::{
+    behavior_state_color, behavior_state_label, BEHAVIOR_STATES,
+};
+use crate::organisms::EcosystemStats;
+use crate::utils::SimRate;
+use bevy::prelude::*;
+
+/// Marker component for the HUD root node
+#[derive(Component)]
+pub struct HudRoot;
+
+/// Marker for the HUD's population summary line
+#[derive(Component)]
+pub struct HudStatsText;
+
+/// Marker for the HUD's simulation speed line
+#[derive(Component)]
+pub struct HudRateText;
+
+/// Spawn the HUD (simulation speed, population summary and the behavior state color legend)
+pub fn setup_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    left: Val::Px(10.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(6.0)),
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                ..default()
+            },
+            HudRoot,
+        ))
+        .with_children(|hud| {
+            hud.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::rgb(0.85, 0.85, 0.85),
+                        ..default()
+                    },
+                ),
+                HudRateText,
+            ));
+
+            hud.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::rgb(0.85, 0.85, 0.85),
+                        ..default()
+                    },
+                ),
+                HudStatsText,
+            ));
+
+            hud.spawn(TextBundle::from_section(
+                "Behavior",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+
+            for state in BEHAVIOR_STATES {
+                hud.spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|row| {
+                    // Color swatch
+                    row.spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Px(10.0),
+                            height: Val::Px(10.0),
+                            ..default()
+                        },
+                        background_color: behavior_state_color(state).into(),
+                        ..default()
+                    });
+                    row.spawn(TextBundle::from_section(
+                        behavior_state_label(state),
+                        TextStyle {
+                            font_size: 12.0,
+                            color: Color::rgb(0.85, 0.85, 0.85),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+}
+
+/// Show the latest population and species counts next to their moving averages, and the
+/// genetic diversity index
+pub fn update_hud_stats(
+    stats: Res<EcosystemStats>,
+    mut text_query: Query<&mut Text, With<HudStatsText>>,
+) {
+    if !stats.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = format!(
+        "Population {} (avg {:.0})\nSpecies {} (avg {:.1})\nDiversity {:.3}",
+        stats.total_population,
+        stats.smoothed.population.mean(),
+        stats.species_count,
+        stats.smoothed.species_count.mean(),
+        stats.genetic_diversity
+    );
+}
+
+/// Show frame rate, tick rate and how that compares with real time
+pub fn update_hud_rate(rate: Res<SimRate>, mut text_query: Query<&mut Text, With<HudRateText>>) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = format!(
+        "FPS {:.0} | TPS {:.0} ({:.2}x real time)",
+        rate.frames_per_second(),
+        rate.ticks_per_second(),
+        rate.real_time_ratio()
+    );
+}
@@ -0,0 +1,149 @@
+use crate::visualization::organisms::OrganismSprite;
+use crate::world::{ChunkSummary, CHUNK_SIZE};
+use bevy::prelude::*;
+
+/// Sprite scale and level-of-detail configuration
+#[derive(Resource)]
+pub struct LodConfig {
+    /// Sprite size per unit of organism size
+    pub sprite_scale: f32,
+    /// Smallest sprite drawn (keeps tiny organisms visible)
+    pub min_sprite_size: f32,
+    /// Largest sprite drawn
+    pub max_sprite_size: f32,
+    /// Camera scale above which individual sprites are replaced by chunk density blobs
+    pub lod_enter_scale: f32,
+    /// Camera scale below which individual sprites are restored (hysteresis avoids flicker)
+    pub lod_exit_scale: f32,
+    /// Organism count at which a density blob reaches full opacity
+    pub blob_saturation_count: u32,
+}
+
+impl Default for LodConfig {
+    fn default() -> Self {
+        Self {
+            sprite_scale: 3.0,
+            min_sprite_size: 2.0,
+            max_sprite_size: 15.0,
+            lod_enter_scale: 3.0,
+            lod_exit_scale: 2.5,
+            blob_saturation_count: 40,
+        }
+    }
+}
+
+impl LodConfig {
+    /// Sprite edge length for an organism of the given size
+    pub fn sprite_size(&self, organism_size: f32) -> f32 {
+        (organism_size * self.sprite_scale)
+            .max(self.min_sprite_size)
+            .min(self.max_sprite_size)
+    }
+}
+
+/// Current render level of detail
+#[derive(Resource, Default)]
+pub struct RenderLod {
+    /// True while chunk density blobs are drawn instead of organism sprites
+    pub aggregated: bool,
+}
+
+/// Marker component for a per-chunk density blob sprite
+#[derive(Component)]
+pub struct ChunkDensityBlob {
+    pub chunk: (i32, i32),
+}
+
+/// Switch between individual sprites and density blobs based on camera zoom
+pub fn update_render_lod(
+    mut lod: ResMut<RenderLod>,
+    config: Res<LodConfig>,
+    camera_query: Query<&OrthographicProjection, With<Camera2d>>,
+) {
+    let Ok(projection) = camera_query.get_single() else {
+        return;
+    };
+
+    let aggregated = if lod.aggregated {
+        projection.scale > config.lod_exit_scale
+    } else {
+        projection.scale > config.lod_enter_scale
+    };
+
+    if aggregated != lod.aggregated {
+        lod.aggregated = aggregated;
+        info!(
+            "[LOD] Switched to {} rendering (camera scale {:.2})",
+            if aggregated { "chunk density" } else { "per-organism" },
+            projection.scale
+        );
+    }
+}
+
+/// Spawn, update and remove chunk density blobs; drop organism sprites while aggregated
+pub fn update_chunk_density_blobs(
+    mut commands: Commands,
+    lod: Res<RenderLod>,
+    config: Res<LodConfig>,
+    summary: Res<ChunkSummary>,
+    mut blob_query: Query<(Entity, &ChunkDensityBlob, &mut Sprite)>,
+    organism_sprite_query: Query<Entity, With<OrganismSprite>>,
+) {
+    if !lod.aggregated {
+        // Individual sprites are back - remove every blob
+        for (entity, _, _) in blob_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    // Despawn organism sprites (and their disease indicator children) while zoomed out
+    for entity in organism_sprite_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let saturation = config.blob_saturation_count.max(1) as f32;
+    let mut existing = std::collections::HashSet::new();
+
+    for (entity, blob, mut sprite) in blob_query.iter_mut() {
+        let count = summary.organism_count(blob.chunk.0, blob.chunk.1);
+        if count == 0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        sprite.color = density_color(count as f32 / saturation);
+        existing.insert(blob.chunk);
+    }
+
+    for ((chunk_x, chunk_y), count) in summary.occupied_chunks() {
+        if existing.contains(&(chunk_x, chunk_y)) {
+            continue;
+        }
+
+        let chunk_extent = CHUNK_SIZE as f32;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: density_color(count as f32 / saturation),
+                    custom_size: Some(Vec2::new(chunk_extent, chunk_extent)),
+                    ..default()
+                },
+                transform: Transform::from_translation(Vec3::new(
+                    (chunk_x as f32 + 0.5) * chunk_extent,
+                    (chunk_y as f32 + 0.5) * chunk_extent,
+                    0.8, // Above disasters, below organism sprites
+                )),
+                ..default()
+            },
+            ChunkDensityBlob {
+                chunk: (chunk_x, chunk_y),
+            },
+        ));
+    }
+}
+
+/// Blob color for a normalized density (0 = sparse, 1+ = saturated)
+fn density_color(density: f32) -> Color {
+    let density = density.clamp(0.0, 1.0);
+    Color::rgba(0.9, 0.8 - density * 0.5, 0.2, 0.15 + density * 0.6)
+}
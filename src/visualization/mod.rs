@@ -1,10 +1,30 @@
 mod camera;
+mod fast_forward;
 mod organisms;
 mod disasters;
+mod lod;
+mod behavior_indicators;
+mod hud;
+mod tuning_panel;
+mod trail;
+mod sensing;
+mod perturbation;
+mod timelapse;
+mod territory_overlay;
 
 pub use camera::*;
+pub use fast_forward::*;
 pub use organisms::*;
 pub use disasters::*;
+pub use lod::*;
+pub use behavior_indicators::*;
+pub use hud::*;
+pub use tuning_panel::*;
+pub use trail::*;
+pub use sensing::*;
+pub use perturbation::*;
+pub use timelapse::*;
+pub use territory_overlay::*;
 
 use bevy::prelude::*;
 
@@ -12,23 +32,58 @@ pub struct VisualizationPlugin;
 
 impl Plugin for VisualizationPlugin {
     fn build(&self, app: &mut App) {
+        // main may already have set the camera follow smoothing and dead zone
         app.init_resource::<CameraConfig>()
-            .add_systems(Startup, setup_visualization)
+            .init_resource::<CameraFollow>()
+            .init_resource::<LodConfig>()
+            .init_resource::<RenderLod>()
+            .init_resource::<TuningPanel>()
+            .init_resource::<TrackedTrail>()
+            .init_resource::<SensingOverlay>()
+            .init_resource::<TerritoryOverlay>()
+            .init_resource::<HerdColoring>()
+            .init_resource::<Perturbation>()
+            // main may already have set a fast-forward target from the command line
+            .init_resource::<FastForward>()
+            // main may already have set the timelapse interval and directory
+            .init_resource::<Timelapse>()
+            .add_systems(Startup, (setup_visualization, setup_hud, setup_tuning_panel))
+            .add_systems(PreUpdate, update_fast_forward)
             .add_systems(
                 Update,
                 (
+                    // Level of detail (zoomed out = per-chunk density blobs)
+                    (update_render_lod, update_chunk_density_blobs).chain(),
                     // Organism visualization
-                    spawn_organism_sprites,
+                    spawn_organism_sprites.after(update_render_lod),
                     update_organism_sprites,
-                    update_organism_colors,
+                    (toggle_herd_coloring, update_organism_colors).chain(),
                     update_disease_indicators, // Step 9: Disease visualization
+                    update_behavior_indicators,
                     cleanup_dead_organism_sprites,
+                    // Tracked organism path
+                    (record_tracked_trail, draw_tracked_trail).chain(),
+                    // Sensing debug overlay
+                    (toggle_sensing_overlay, draw_sensing_overlay).chain(),
+                    // Territory scent marks
+                    (toggle_territory_overlay, draw_territory_overlay).chain(),
+                    // Click-to-track
+                    select_tracked_organism,
+                    // Resource/terrain perturbations at the cursor
+                    handle_perturbation_input,
+                    handle_bottleneck_input,
                     // Disaster visualization
                     spawn_and_update_disaster_sprites, // Step 9: Disaster visualization
                     cleanup_expired_disaster_sprites, // Step 9: Cleanup expired disasters
                     // Camera controls
-                    handle_camera_controls,
-                ),
+                    (handle_camera_controls, follow_tracked_organism, update_behavior_focus).chain(),
+                    // Live tuning panel
+                    (handle_tuning_panel_input, update_tuning_panel).chain(),
+                    (update_hud_stats, update_hud_rate),
+                    // Time-lapse frames
+                    capture_timelapse_frame,
+                )
+                    .run_if(not(fast_forwarding)),
             );
     }
 }
@@ -46,8 +101,15 @@ fn setup_visualization(mut commands: Commands) {
     });
 
     info!("Visualization system initialized");
-    info!("Camera controls: Arrow Keys/WASD = Pan, +/- = Zoom, 0 = Reset Zoom, R = Reset Camera");
+    info!("Camera controls: Arrow Keys/WASD = Pan, +/- = Zoom, 0 = Reset Zoom, R = Reset Camera, F = Follow tracked organism");
     info!("Organism colors: Green = Producer, Red = Consumer, Purple = Decomposer");
+    info!("Tuning panel: F1 = Toggle, Up/Down = Select, Left/Right = Adjust (Shift = 10x)");
+    info!("Tracked organisms: Left click = Track/untrack the organism under the cursor");
+    info!("Tracked organism trails: white lines fading with age");
+    info!("Sensing overlay: F2 = Toggle (tracked + hovered organism range, target, predator)");
+    info!("Perturbations: F6 = Add resource at cursor (Shift = Remove), F7 = Cycle resource (Shift = Cycle terrain), F8 = Paint terrain");
+    info!("Bottleneck: F9 = Cull the population to a few random survivors");
+    info!("Behavior indicators: colored dot per organism, see the HUD legend");
     info!("Disease visualization: Infected organisms show sickly colors and pulsing effects");
     info!("Disaster visualization: Disasters appear as colored circles with pulsing effects");
 }
@@ -1,5 +1,6 @@
 use crate::organisms::*;
 use crate::organisms::Infected;
+use crate::visualization::lod::{LodConfig, RenderLod};
 use bevy::prelude::*;
 
 /// Marker component for organism sprite entities
@@ -14,16 +15,54 @@ pub struct DiseaseIndicator {
     pub organism_entity: bevy::ecs::entity::Entity,
 }
 
+/// Color herded organisms by herd instead of type and species (toggled with H)
+/// Only useful with `--herds` on; unherded organisms are drawn grey.
+#[derive(Resource, Default)]
+pub struct HerdColoring {
+    pub enabled: bool,
+}
+
+pub fn toggle_herd_coloring(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut coloring: ResMut<HerdColoring>,
+    herds: Res<Herds>,
+) {
+    if keyboard_input.just_pressed(KeyCode::H) {
+        coloring.enabled = !coloring.enabled;
+        info!(
+            "[HERDS] Herd coloring {} ({} herds)",
+            if coloring.enabled { "on" } else { "off" },
+            herds.current.len()
+        );
+    }
+}
+
+/// Distinct, energy-independent color for a herd (grey when not in one)
+fn herd_color(herd: Option<&HerdId>) -> Color {
+    match herd {
+        // Golden-angle hues keep consecutive ids apart
+        Some(herd) => Color::hsl((herd.0 as f32 * 137.508) % 360.0, 0.85, 0.55),
+        None => Color::rgb(0.35, 0.35, 0.35),
+    }
+}
+
 /// Spawn sprites for organisms that don't have sprites yet
 pub fn spawn_organism_sprites(
     mut commands: Commands,
     organism_query: Query<
-        (Entity, &Position, &OrganismType, &Energy, &Size, &SpeciesId),
+        (Entity, &Position, &OrganismType, &Energy, &Size, &SpeciesId, Option<&CachedTraits>),
         With<Alive>,
     >,
     sprite_query: Query<&OrganismSprite>,
     infected_query: Query<&Infected, With<Alive>>,
+    lod_config: Res<LodConfig>,
+    lod: Res<RenderLod>,
 ) {
+    // Chunk density blobs are drawn instead while zoomed out
+    if lod.aggregated {
+        return;
+    }
+
     // Get all organism entities that already have sprites
     let existing_organisms: std::collections::HashSet<_> = sprite_query
         .iter()
@@ -31,14 +70,15 @@ pub fn spawn_organism_sprites(
         .collect();
 
     // Spawn sprites for organisms without sprites
-    for (organism_entity, position, organism_type, energy, size, species_id) in organism_query.iter()
+    for (organism_entity, position, organism_type, energy, size, species_id, traits) in
+        organism_query.iter()
     {
         if existing_organisms.contains(&organism_entity) {
             continue;
         }
 
         let color = get_organism_color(organism_type, energy, species_id);
-        let sprite_size = (size.value() * 3.0).max(2.0).min(15.0); // Clamp size for visibility
+        let sprite_size = lod_config.sprite_size(size.value()); // Clamp size for visibility
 
         // Check if infected to apply initial visual
         let final_color = if let Ok(infected) = infected_query.get(organism_entity) {
@@ -60,7 +100,9 @@ pub fn spawn_organism_sprites(
                         position.x(),
                         position.y(),
                         1.0, // Render above background
-                    )),
+                    ))
+                    // Traits are fixed at birth, so the shape is only set here
+                    .with_scale(traits.map_or(Vec3::ONE, morphology_scale)),
                     ..default()
                 },
                 OrganismSprite {
@@ -158,14 +200,21 @@ pub fn update_organism_colors(
     mut sprite_query: Query<(&OrganismSprite, &mut Sprite)>,
     organism_query: Query<(&OrganismType, &Energy, &Size, &SpeciesId), With<Alive>>,
     infected_query: Query<&Infected, With<Alive>>,
+    herd_query: Query<&HerdId>,
+    herd_coloring: Res<HerdColoring>,
+    lod_config: Res<LodConfig>,
     time: Res<Time>,
 ) {
     for (sprite, mut sprite_component) in sprite_query.iter_mut() {
         if let Ok((organism_type, energy, size, species_id)) =
             organism_query.get(sprite.organism_entity)
         {
-            let mut new_color = get_organism_color(organism_type, energy, species_id);
-            let mut sprite_size = (size.value() * 3.0).max(2.0).min(15.0);
+            let mut new_color = if herd_coloring.enabled {
+                herd_color(herd_query.get(sprite.organism_entity).ok())
+            } else {
+                get_organism_color(organism_type, energy, species_id)
+            };
+            let mut sprite_size = lod_config.sprite_size(size.value());
             
             // Check if organism is infected
             if let Ok(infected) = infected_query.get(sprite.organism_entity) {
@@ -186,6 +235,16 @@ pub fn update_organism_colors(
     }
 }
 
+/// Sprite shape from the genome: fast organisms are drawn elongated, large ones bulky
+/// Area-preserving, so overall sprite size still comes from `Size` alone. Purely cosmetic.
+fn morphology_scale(traits: &CachedTraits) -> Vec3 {
+    let speed = traits.normalized("speed").unwrap_or(0.5);
+    let bulk = traits.normalized("size").unwrap_or(0.5);
+    let aspect = (1.0 + speed * 0.8) / (1.0 + bulk * 0.4);
+    let stretch = aspect.sqrt();
+    Vec3::new(stretch, 1.0 / stretch, 1.0)
+}
+
 /// Apply visual effect to show disease infection
 fn apply_disease_visual_effect(base_color: Color, infected: &Infected) -> Color {
     // Shift color towards sickly green/yellow for infection
@@ -0,0 +1,104 @@
+use crate::organisms::Bottleneck;
+use crate::world::{ResourceType, TerrainType, WorldGrid};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Runtime perturbations applied around the cursor, for watching the ecosystem respond
+/// F6 adds the selected resource (Shift+F6 removes it), F7 cycles the resource
+/// (Shift+F7 cycles the terrain) and F8 paints the selected terrain.
+#[derive(Resource)]
+pub struct Perturbation {
+    /// Radius of the affected patch in world units
+    pub radius: f32,
+    pub resource: ResourceType,
+    /// Density added (or removed) per cell per press
+    pub amount: f32,
+    pub terrain: TerrainType,
+}
+
+impl Default for Perturbation {
+    fn default() -> Self {
+        Self {
+            radius: 8.0,
+            resource: ResourceType::Plant,
+            amount: 0.5,
+            terrain: TerrainType::Desert,
+        }
+    }
+}
+
+/// World position under the cursor
+fn cursor_world_position(
+    window_query: &Query<&Window, With<PrimaryWindow>>,
+    camera_query: &Query<(&Camera, &GlobalTransform)>,
+) -> Option<Vec2> {
+    let cursor = window_query.get_single().ok()?.cursor_position()?;
+    let (camera, camera_transform) = camera_query.get_single().ok()?;
+    camera.viewport_to_world_2d(camera_transform, cursor)
+}
+
+/// Handle perturbation keys (changes land before the next simulation tick)
+pub fn handle_perturbation_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut perturbation: ResMut<Perturbation>,
+    mut world_grid: ResMut<WorldGrid>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    if keyboard_input.just_pressed(KeyCode::F7) {
+        if shift {
+            let next = (perturbation.terrain as usize + 1) % TerrainType::ALL.len();
+            perturbation.terrain = TerrainType::ALL[next];
+            info!("[PERTURB] Terrain = {:?}", perturbation.terrain);
+        } else {
+            let next = (perturbation.resource.index() + 1) % ResourceType::ALL.len();
+            perturbation.resource = ResourceType::ALL[next];
+            info!("[PERTURB] Resource = {}", perturbation.resource.name());
+        }
+    }
+
+    let add = keyboard_input.just_pressed(KeyCode::F6);
+    let paint = keyboard_input.just_pressed(KeyCode::F8);
+    if !add && !paint {
+        return;
+    }
+    let Some(center) = cursor_world_position(&window_query, &camera_query) else {
+        return;
+    };
+
+    if add {
+        let amount = if shift { -perturbation.amount } else { perturbation.amount };
+        let cells = world_grid.add_resource_in_radius(
+            center,
+            perturbation.radius,
+            perturbation.resource,
+            amount,
+        );
+        info!(
+            "[PERTURB] {} {:.2} {} in {} cells around ({:.1}, {:.1})",
+            if shift { "Removed" } else { "Added" },
+            perturbation.amount,
+            perturbation.resource.name(),
+            cells,
+            center.x,
+            center.y
+        );
+    }
+    if paint {
+        let cells = world_grid.set_terrain_in_radius(center, perturbation.radius, perturbation.terrain);
+        info!(
+            "[PERTURB] Set {} cells around ({:.1}, {:.1}) to {:?}",
+            cells, center.x, center.y, perturbation.terrain
+        );
+    }
+}
+
+/// F9 culls the population down to `Bottleneck::survivors` (a founder-effect experiment)
+pub fn handle_bottleneck_input(keyboard_input: Res<Input<KeyCode>>, mut bottleneck: ResMut<Bottleneck>) {
+    if keyboard_input.just_pressed(KeyCode::F9) {
+        let survivors = bottleneck.survivors;
+        bottleneck.cull_to(survivors);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::organisms::{Alive, Behavior, CachedTraits, Position, SensoryDataCache, TrackedOrganisms};
+use crate::utils::SpatialHashGrid;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Debug overlay showing what selected organisms can sense
+/// Draws the sensory range, the current target and the nearest predator for the tracked
+/// organisms and the one under the cursor. Toggled with F2.
+#[derive(Resource)]
+pub struct SensingOverlay {
+    pub enabled: bool,
+    /// Screen-independent hover radius in world units (scaled by camera zoom)
+    pub hover_radius: f32,
+    pub range_color: Color,
+    pub target_color: Color,
+    pub predator_color: Color,
+}
+
+impl Default for SensingOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hover_radius: 4.0,
+            range_color: Color::rgba(0.4, 0.8, 1.0, 0.6),
+            target_color: Color::rgb(1.0, 0.9, 0.2),
+            predator_color: Color::rgb(1.0, 0.2, 0.2),
+        }
+    }
+}
+
+pub fn toggle_sensing_overlay(keyboard_input: Res<Input<KeyCode>>, mut overlay: ResMut<SensingOverlay>) {
+    if keyboard_input.just_pressed(KeyCode::F2) {
+        overlay.enabled = !overlay.enabled;
+        info!("[SENSING] Overlay {}", if overlay.enabled { "on" } else { "off" });
+    }
+}
+
+/// Organism closest to the cursor, if any is within the hover radius
+fn hovered_organism(
+    window_query: &Query<&Window, With<PrimaryWindow>>,
+    camera_query: &Query<(&Camera, &GlobalTransform, &OrthographicProjection)>,
+    spatial_hash: &SpatialHashGrid,
+    position_query: &Query<&Position, With<Alive>>,
+    hover_radius: f32,
+) -> Option<Entity> {
+    let cursor = window_query.get_single().ok()?.cursor_position()?;
+    let (camera, camera_transform, projection) = camera_query.get_single().ok()?;
+    let world_cursor = camera.viewport_to_world_2d(camera_transform, cursor)?;
+    let radius = hover_radius * projection.scale.max(1.0);
+
+    spatial_hash
+        .organisms
+        .nearest_where(world_cursor, radius, |entity| {
+            position_query.get(entity).ok().map(|position| position.as_vec2())
+        })
+        .map(|(entity, _, _)| entity)
+}
+
+/// Left click toggles tracking of the organism under the cursor
+/// With every tracking slot in use, the longest-tracked organism makes room.
+pub fn select_tracked_organism(
+    mouse_input: Res<Input<MouseButton>>,
+    overlay: Res<SensingOverlay>,
+    mut tracked: ResMut<TrackedOrganisms>,
+    spatial_hash: Res<SpatialHashGrid>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform, &OrthographicProjection)>,
+    position_query: Query<&Position, With<Alive>>,
+) {
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    if let Some(entity) = hovered_organism(
+        &window_query,
+        &camera_query,
+        &spatial_hash,
+        &position_query,
+        overlay.hover_radius,
+    ) {
+        tracked.toggle(entity);
+    }
+}
+
+/// Draw sensory range, target and nearest-predator lines for selected organisms
+pub fn draw_sensing_overlay(
+    overlay: Res<SensingOverlay>,
+    tracked: Res<TrackedOrganisms>,
+    sensory_cache: Res<SensoryDataCache>,
+    spatial_hash: Res<SpatialHashGrid>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform, &OrthographicProjection)>,
+    position_query: Query<&Position, With<Alive>>,
+    organism_query: Query<(&Position, &CachedTraits, &Behavior), With<Alive>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    let hovered = hovered_organism(
+        &window_query,
+        &camera_query,
+        &spatial_hash,
+        &position_query,
+        overlay.hover_radius,
+    );
+    let mut selected: Vec<Entity> = tracked.entities().collect();
+    if let Some(hovered) = hovered.filter(|hovered| !selected.contains(hovered)) {
+        selected.push(hovered);
+    }
+
+    for entity in selected {
+        let Ok((position, traits, behavior)) = organism_query.get(entity) else {
+            continue;
+        };
+        let center = position.as_vec2();
+
+        gizmos.circle_2d(center, traits.sensory_range, overlay.range_color);
+        if let Some(target) = behavior.target_position {
+            gizmos.line_2d(center, target, overlay.target_color);
+        }
+        if let Some((_, predator, _)) = sensory_cache.get(entity).and_then(|s| s.nearest_predator) {
+            gizmos.line_2d(center, predator, overlay.predator_color);
+        }
+    }
+}
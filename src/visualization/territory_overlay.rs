@@ -0,0 +1,41 @@
+use crate::organisms::ScentMarks;
+use bevy::prelude::*;
+
+/// Overlay tinting scent-marked cells by the species that holds them (toggled with T)
+/// Stronger marks are more opaque, so home ranges and contested borders stand out.
+#[derive(Resource, Default)]
+pub struct TerritoryOverlay {
+    pub enabled: bool,
+}
+
+pub fn toggle_territory_overlay(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut overlay: ResMut<TerritoryOverlay>,
+    marks: Res<ScentMarks>,
+) {
+    if keyboard_input.just_pressed(KeyCode::T) {
+        overlay.enabled = !overlay.enabled;
+        info!(
+            "[TERRITORY] Overlay {} ({} marked cells)",
+            if overlay.enabled { "on" } else { "off" },
+            marks.len()
+        );
+    }
+}
+
+pub fn draw_territory_overlay(
+    overlay: Res<TerritoryOverlay>,
+    marks: Res<ScentMarks>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.enabled {
+        return;
+    }
+    for (x, y, mark) in marks.iter() {
+        // Golden-angle hues keep neighboring species ids apart
+        let hue = (mark.species as f32 * 137.5) % 360.0;
+        let color = Color::hsla(hue, 0.8, 0.5, 0.15 + mark.strength * 0.6);
+        let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+        gizmos.rect_2d(center, 0.0, Vec2::ONE, color);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::utils::SimTime;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::ScreenshotManager;
+use bevy::window::PrimaryWindow;
+use std::path::PathBuf;
+
+/// `--timelapse <ticks>`: save the rendered frame as a numbered PNG every N ticks
+/// Frames go to `--timelapse-dir` (default `data/timelapse`) as `frame_000000.png`, ... ready
+/// to be stitched into a video. The GPU readback and PNG encoding happen off the main thread.
+#[derive(Resource, Debug)]
+pub struct Timelapse {
+    /// Ticks between captures (None = disabled)
+    pub interval: Option<u64>,
+    pub output_dir: PathBuf,
+    /// Number of the next frame file
+    next_frame: u32,
+    /// Tick of the last capture, so a tick is never captured twice
+    last_tick: Option<u64>,
+}
+
+impl Default for Timelapse {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            output_dir: PathBuf::from("data/timelapse"),
+            next_frame: 0,
+            last_tick: None,
+        }
+    }
+}
+
+impl Timelapse {
+    pub fn from_args() -> Self {
+        use crate::utils::cli::option_value;
+
+        let mut timelapse = Self::default();
+        if let Some(value) = option_value("timelapse") {
+            match value.trim().parse() {
+                Ok(interval) if interval > 0 => timelapse.interval = Some(interval),
+                _ => warn!("Invalid --timelapse '{}', not capturing", value),
+            }
+        }
+        if let Some(dir) = option_value("timelapse-dir") {
+            timelapse.output_dir = PathBuf::from(dir);
+        }
+        timelapse
+    }
+}
+
+/// Request a screenshot of the primary window on every timelapse tick (no-op headless)
+pub fn capture_timelapse_frame(
+    mut timelapse: ResMut<Timelapse>,
+    sim_time: Res<SimTime>,
+    window_query: Query<Entity, With<PrimaryWindow>>,
+    mut screenshots: ResMut<ScreenshotManager>,
+) {
+    let Some(interval) = timelapse.interval else {
+        return;
+    };
+    if !sim_time.every(interval) || timelapse.last_tick == Some(sim_time.tick) {
+        return;
+    }
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    if timelapse.next_frame == 0 {
+        if let Err(err) = std::fs::create_dir_all(&timelapse.output_dir) {
+            error!(
+                "Failed to create timelapse directory {}: {err}",
+                timelapse.output_dir.display()
+            );
+            timelapse.interval = None;
+            return;
+        }
+        info!(
+            "[TIMELAPSE] Capturing a frame every {} ticks to {}",
+            interval,
+            timelapse.output_dir.display()
+        );
+    }
+
+    let path = timelapse
+        .output_dir
+        .join(format!("frame_{:06}.png", timelapse.next_frame));
+    // Only fails if a screenshot of this window is already pending; try again next interval
+    if screenshots.save_screenshot_to_disk(window, path).is_ok() {
+        timelapse.next_frame += 1;
+        timelapse.last_tick = Some(sim_time.tick);
+    }
+}
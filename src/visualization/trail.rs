@@ -0,0 +1,73 @@
+use crate::organisms::{Alive, Position, TrackedOrganisms};
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Recent paths of the tracked organisms, drawn as fading line strips
+#[derive(Resource)]
+pub struct TrackedTrail {
+    /// Recorded points per tracked organism, oldest first
+    paths: HashMap<Entity, VecDeque<Vec2>>,
+    /// Maximum number of points kept per organism
+    pub capacity: usize,
+    /// Minimum distance moved before a new point is recorded
+    pub min_spacing: f32,
+    pub color: Color,
+}
+
+impl Default for TrackedTrail {
+    fn default() -> Self {
+        Self {
+            paths: HashMap::new(),
+            capacity: 300,
+            min_spacing: 1.0,
+            color: Color::rgb(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl TrackedTrail {
+    fn push(&mut self, organism: Entity, point: Vec2) {
+        let min_spacing_sq = self.min_spacing * self.min_spacing;
+        let capacity = self.capacity;
+        let points = self.paths.entry(organism).or_default();
+        if let Some(last) = points.back() {
+            if last.distance_squared(point) < min_spacing_sq {
+                return;
+            }
+        }
+        points.push_back(point);
+        while points.len() > capacity {
+            points.pop_front();
+        }
+    }
+}
+
+/// Record the tracked organisms' positions (dropping trails of organisms no longer tracked)
+pub fn record_tracked_trail(
+    tracked: Res<TrackedOrganisms>,
+    mut trail: ResMut<TrackedTrail>,
+    position_query: Query<&Position, With<Alive>>,
+) {
+    trail.paths.retain(|organism, _| tracked.contains(*organism));
+
+    for organism in tracked.entities() {
+        if let Ok(position) = position_query.get(organism) {
+            trail.push(organism, position.as_vec2());
+        }
+    }
+}
+
+/// Draw the trails, fading older segments out
+pub fn draw_tracked_trail(trail: Res<TrackedTrail>, mut gizmos: Gizmos) {
+    for points in trail.paths.values() {
+        let count = points.len();
+        if count < 2 {
+            continue;
+        }
+
+        gizmos.linestrip_gradient_2d(points.iter().enumerate().map(|(index, point)| {
+            let age = 1.0 - index as f32 / (count - 1) as f32; // 0.0 = newest, 1.0 = oldest
+            (*point, trail.color.with_a(1.0 - age * 0.9))
+        }));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::organisms::EcosystemTuning;
+use bevy::prelude::*;
+
+/// A live-adjustable `EcosystemTuning` field
+struct TuningKnob {
+    label: &'static str,
+    step: f32,
+    min: f32,
+    max: f32,
+    get: fn(&EcosystemTuning) -> f32,
+    field: fn(&mut EcosystemTuning) -> &mut f32,
+}
+
+/// Knobs exposed in the panel, in display order
+const TUNING_KNOBS: [TuningKnob; 8] = [
+    TuningKnob {
+        label: "Plant regeneration",
+        step: 0.01,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.plant_regeneration_rate,
+        field: |t| &mut t.plant_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Water regeneration",
+        step: 0.01,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.water_regeneration_rate,
+        field: |t| &mut t.water_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Sunlight regeneration",
+        step: 0.01,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.sunlight_regeneration_rate,
+        field: |t| &mut t.sunlight_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Mineral regeneration",
+        step: 0.005,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.mineral_regeneration_rate,
+        field: |t| &mut t.mineral_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Detritus regeneration",
+        step: 0.005,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.detritus_regeneration_rate,
+        field: |t| &mut t.detritus_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Prey regeneration",
+        step: 0.005,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.prey_regeneration_rate,
+        field: |t| &mut t.prey_regeneration_rate,
+    },
+    TuningKnob {
+        label: "Reproduction chance",
+        step: 0.005,
+        min: 0.0,
+        max: 1.0,
+        get: |t| t.reproduction_chance_multiplier,
+        field: |t| &mut t.reproduction_chance_multiplier,
+    },
+    TuningKnob {
+        label: "Speciation threshold",
+        step: 0.01,
+        min: 0.01,
+        max: 1.0,
+        get: |t| t.speciation_threshold,
+        field: |t| &mut t.speciation_threshold,
+    },
+];
+
+/// Keyboard-driven tuning panel state
+/// F1 toggles, Up/Down selects a knob, Left/Right adjusts it (hold Shift for 10x steps)
+#[derive(Resource, Default)]
+pub struct TuningPanel {
+    pub visible: bool,
+    pub selected: usize,
+}
+
+/// Marker component for the tuning panel text
+#[derive(Component)]
+pub struct TuningPanelText;
+
+/// Spawn the (initially hidden) tuning panel
+pub fn setup_tuning_panel(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .with_children(|panel| {
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::rgb(0.85, 0.85, 0.85),
+                        ..default()
+                    },
+                ),
+                TuningPanelText,
+            ));
+        });
+}
+
+/// Handle panel input and adjust `EcosystemTuning` in place (takes effect next tick)
+pub fn handle_tuning_panel_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut panel: ResMut<TuningPanel>,
+    mut tuning: ResMut<EcosystemTuning>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F1) {
+        panel.visible = !panel.visible;
+    }
+    if !panel.visible {
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::M) {
+        tuning.speciation_distance_metric = tuning.speciation_distance_metric.next();
+        info!("[TUNING] Speciation distance metric = {}", tuning.speciation_distance_metric.label());
+    }
+
+    let knob_count = TUNING_KNOBS.len();
+    if keyboard_input.just_pressed(KeyCode::Up) {
+        panel.selected = (panel.selected + knob_count - 1) % knob_count;
+    }
+    if keyboard_input.just_pressed(KeyCode::Down) {
+        panel.selected = (panel.selected + 1) % knob_count;
+    }
+
+    let direction = if keyboard_input.just_pressed(KeyCode::Right) {
+        1.0
+    } else if keyboard_input.just_pressed(KeyCode::Left) {
+        -1.0
+    } else {
+        return;
+    };
+    let multiplier = if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        10.0
+    } else {
+        1.0
+    };
+
+    let knob = &TUNING_KNOBS[panel.selected];
+    let value = (knob.field)(&mut tuning);
+    *value = (*value + direction * knob.step * multiplier).clamp(knob.min, knob.max);
+    info!("[TUNING] {} = {:.4}", knob.label, *value);
+}
+
+/// Redraw the panel when it or the tuning changes
+pub fn update_tuning_panel(
+    panel: Res<TuningPanel>,
+    tuning: Res<EcosystemTuning>,
+    mut text_query: Query<(&mut Text, &Parent), With<TuningPanelText>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    if !panel.is_changed() && !tuning.is_changed() {
+        return;
+    }
+
+    for (mut text, parent) in text_query.iter_mut() {
+        if let Ok(mut visibility) = visibility_query.get_mut(parent.get()) {
+            *visibility = if panel.visible {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+        }
+
+        let mut lines = String::from("Tuning (F1 hide, Up/Down select, Left/Right adjust, M metric)\n");
+        for (index, knob) in TUNING_KNOBS.iter().enumerate() {
+            let value = (knob.get)(&tuning);
+            let marker = if index == panel.selected { ">" } else { " " };
+            lines.push_str(&format!("{marker} {:<22} {:.4}\n", knob.label, value));
+        }
+        let metric = tuning.speciation_distance_metric.label();
+        lines.push_str(&format!("  {:<22} {}\n", "Distance metric", metric));
+        text.sections[0].value = lines;
+    }
+}
@@ -12,11 +12,11 @@ pub struct Cell {
     pub terrain: TerrainType,
     /// Resource densities for each resource type
     /// [Plant, Mineral, Sunlight, Water, Detritus, Prey]
-    pub resource_density: [f32; 6],
+    pub resource_density: [f32; RESOURCE_TYPE_COUNT],
     /// Rolling measure of recent consumption pressure per resource type
-    pub resource_pressure: [f32; 6],
+    pub resource_pressure: [f32; RESOURCE_TYPE_COUNT],
     /// Adaptive modifier per resource type (responds to pressure & climate)
-    pub resource_adaptation: [f32; 6],
+    pub resource_adaptation: [f32; RESOURCE_TYPE_COUNT],
 }
 
 impl Default for Cell {
@@ -26,9 +26,9 @@ impl Default for Cell {
             humidity: 0.5,
             elevation: 0,
             terrain: TerrainType::Plains,
-            resource_density: [0.0; 6],
-            resource_pressure: [0.0; 6],
-            resource_adaptation: [0.0; 6],
+            resource_density: [0.0; RESOURCE_TYPE_COUNT],
+            resource_pressure: [0.0; RESOURCE_TYPE_COUNT],
+            resource_adaptation: [0.0; RESOURCE_TYPE_COUNT],
         }
     }
 }
@@ -90,6 +90,20 @@ impl Default for TerrainType {
     }
 }
 
+impl TerrainType {
+    /// Every terrain type, in discriminant order
+    pub const ALL: [TerrainType; 8] = [
+        TerrainType::Ocean,
+        TerrainType::Plains,
+        TerrainType::Forest,
+        TerrainType::Desert,
+        TerrainType::Tundra,
+        TerrainType::Mountain,
+        TerrainType::Swamp,
+        TerrainType::Volcanic,
+    ];
+}
+
 /// Resource types in the ecosystem
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(usize)]
@@ -102,4 +116,73 @@ pub enum ResourceType {
     Prey = 5,
 }
 
-pub const RESOURCE_TYPE_COUNT: usize = 6;
+/// Number of resource types (derived from the last enum variant)
+pub const RESOURCE_TYPE_COUNT: usize = ResourceType::Prey as usize + 1;
+
+impl ResourceType {
+    /// Every resource type, in index order
+    pub const ALL: [ResourceType; RESOURCE_TYPE_COUNT] = [
+        ResourceType::Plant,
+        ResourceType::Mineral,
+        ResourceType::Sunlight,
+        ResourceType::Water,
+        ResourceType::Detritus,
+        ResourceType::Prey,
+    ];
+
+    /// Iterate over every resource type in index order
+    pub fn all() -> impl Iterator<Item = ResourceType> {
+        Self::ALL.into_iter()
+    }
+
+    /// Index into per-resource arrays (e.g. `Cell::resource_density`)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Resource type for an array index
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+
+    /// Display name (also used for CSV/log columns)
+    pub fn name(self) -> &'static str {
+        match self {
+            ResourceType::Plant => "Plant",
+            ResourceType::Mineral => "Mineral",
+            ResourceType::Sunlight => "Sunlight",
+            ResourceType::Water => "Water",
+            ResourceType::Detritus => "Detritus",
+            ResourceType::Prey => "Prey",
+        }
+    }
+
+    /// Resource type for a display name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().find(|resource| resource.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_covers_every_resource_type() {
+        assert_eq!(ResourceType::all().count(), RESOURCE_TYPE_COUNT);
+        for (index, resource) in ResourceType::all().enumerate() {
+            assert_eq!(resource.index(), index);
+        }
+    }
+
+    #[test]
+    fn index_and_name_round_trip() {
+        for resource in ResourceType::all() {
+            assert_eq!(ResourceType::from_index(resource.index()), Some(resource));
+            assert_eq!(ResourceType::from_name(resource.name()), Some(resource));
+            assert_eq!(ResourceType::from_name(&resource.name().to_lowercase()), Some(resource));
+        }
+        assert_eq!(ResourceType::from_index(RESOURCE_TYPE_COUNT), None);
+        assert_eq!(ResourceType::from_name("Oxygen"), None);
+    }
+}
@@ -11,7 +11,7 @@ pub struct ClimateState {
     pub base_humidity: f32,
     /// Current season (0.0 to 1.0, cycles annually)
     pub season: f32,
-    /// Time in simulation ticks
+    /// Simulation tick of the last update (mirrors `SimTime`)
     pub time: u64,
     /// Phase offset for spatial variation
     pub noise_phase: f32,
@@ -21,10 +21,25 @@ pub struct ClimateState {
     pub events: Vec<ClimateEvent>,
     /// Seed driving deterministic regional offsets
     pub regional_seed: u64,
+    /// Stream for drift and event timing, seeded alongside `regional_seed`
+    rng: fastrand::Rng,
+    /// Global wind (world units per tick); rotates with the seasons and drifts climate events
+    pub wind: Vec2,
+    /// World size (width, height) when the world wraps toroidally; None = unbounded
+    /// Regional variation then repeats exactly once per period and event distances are
+    /// measured across the seam, so climate stays continuous where the world wraps.
+    pub wrap_size: Option<Vec2>,
 }
 
 impl Default for ClimateState {
     fn default() -> Self {
+        Self::seeded(0)
+    }
+}
+
+impl ClimateState {
+    /// Climate whose drift and events replay from `seed` (see `WorldSeed::stream_seed`)
+    pub fn seeded(seed: u64) -> Self {
         Self {
             base_temperature: 0.5,
             base_humidity: 0.5,
@@ -33,15 +48,22 @@ impl Default for ClimateState {
             noise_phase: 0.0,
             event_cooldown: 120.0,
             events: Vec::new(),
-            regional_seed: fastrand::u64(..),
+            regional_seed: seed,
+            rng: fastrand::Rng::with_seed(seed.rotate_left(32)),
+            wind: Vec2::ZERO,
+            wrap_size: None,
         }
     }
-}
 
-impl ClimateState {
-    /// Update climate state (called each tick)
-    pub fn update(&mut self, _dt: f32) {
-        self.time += 1;
+    /// Make climate seam-aware for a toroidal world of the given size
+    pub fn with_wrap(mut self, size: Vec2) -> Self {
+        self.wrap_size = (size.x > 0.0 && size.y > 0.0).then_some(size);
+        self
+    }
+
+    /// Update climate state (called each tick with the current `SimTime` tick)
+    pub fn update(&mut self, tick: u64, _dt: f32) {
+        self.time = tick;
 
         // Seasonal cycle (1000 ticks = 1 year)
         let season_period = 1000.0;
@@ -59,14 +81,20 @@ impl ClimateState {
 
         // Long-term climate drift
         let drift_rate = 0.0001;
-        self.base_temperature += (fastrand::f32() - 0.5) * drift_rate;
+        self.base_temperature += (self.rng.f32() - 0.5) * drift_rate;
         self.base_temperature = self.base_temperature.clamp(0.2, 0.8);
 
         let dt = 1.0f32;
         self.noise_phase += 0.015 * dt;
 
-        // Decay stochastic events
+        // Wind direction turns once per year, with slow wobble; strength peaks mid-season
+        let wind_angle = self.season * std::f32::consts::TAU + (self.noise_phase * 0.3).sin() * 0.5;
+        let wind_speed = 0.15 + (self.season * 2.0 * std::f32::consts::TAU).sin().abs() * 0.1;
+        self.wind = Vec2::new(wind_angle.cos(), wind_angle.sin()) * wind_speed;
+
+        // Drift and decay stochastic events
         for event in &mut self.events {
+            event.center += self.wind * dt;
             event.time_remaining -= dt;
         }
         self.events.retain(|event| event.time_remaining > 0.0);
@@ -74,10 +102,10 @@ impl ClimateState {
         // Randomly spawn new event
         self.event_cooldown -= dt;
         if self.event_cooldown <= 0.0 {
-            if fastrand::f32() < 0.02 {
+            if self.rng.f32() < 0.02 {
                 self.spawn_event();
             }
-            self.event_cooldown = fastrand::f32() * 300.0 + 120.0;
+            self.event_cooldown = self.rng.f32() * 300.0 + 120.0;
         }
     }
 
@@ -126,8 +154,10 @@ impl ClimateState {
 
     fn regional_offsets(&self, world_pos: Vec2) -> (f32, f32) {
         let scale = 0.005;
-        let angle_x = world_pos.x * scale + self.noise_phase;
-        let angle_y = world_pos.y * scale * 1.3 - self.noise_phase * 0.6;
+        let angle_x = wrapped_angle(world_pos.x, scale, self.wrap_size.map(|size| size.x))
+            + self.noise_phase;
+        let angle_y = wrapped_angle(world_pos.y, scale * 1.3, self.wrap_size.map(|size| size.y))
+            - self.noise_phase * 0.6;
         let temp = (angle_x.sin() * angle_y.cos()) * 0.08;
         let humidity = (angle_x.cos() * 0.06) + (angle_y.sin() * 0.05);
         (temp, humidity)
@@ -137,7 +167,7 @@ impl ClimateState {
         let mut temp = 0.0;
         let mut humidity = 0.0;
         for event in &self.events {
-            let distance = world_pos.distance(event.center);
+            let distance = self.wrapped_delta(world_pos - event.center).length();
             if distance <= event.radius {
                 let influence = 1.0 - (distance / event.radius).powf(1.5);
                 temp += event.temperature_delta * influence;
@@ -147,6 +177,15 @@ impl ClimateState {
         (temp, humidity)
     }
 
+    /// Shortest displacement equivalent to `delta` on the wrapped world (unchanged when unbounded)
+    fn wrapped_delta(&self, delta: Vec2) -> Vec2 {
+        let Some(size) = self.wrap_size else {
+            return delta;
+        };
+        let wrap = |value: f32, period: f32| value - (value / period).round() * period;
+        Vec2::new(wrap(delta.x, size.x), wrap(delta.y, size.y))
+    }
+
     fn spawn_event(&mut self) {
         let mut rng = fastrand::Rng::with_seed(self.regional_seed ^ self.time);
         let center = Vec2::new(rng.f32() * 400.0 - 200.0, rng.f32() * 400.0 - 200.0);
@@ -168,6 +207,19 @@ impl ClimateState {
     }
 }
 
+/// Angle of the regional sine pattern along one axis
+/// On a wrapped axis the frequency is rounded to a whole number of cycles per period (at
+/// least one), so the pattern meets itself at the seam.
+fn wrapped_angle(coordinate: f32, scale: f32, period: Option<f32>) -> f32 {
+    match period {
+        Some(period) if period > 0.0 => {
+            let cycles = (period * scale / std::f32::consts::TAU).round().max(1.0);
+            coordinate.rem_euclid(period) / period * cycles * std::f32::consts::TAU
+        }
+        _ => coordinate * scale,
+    }
+}
+
 /// Update climate for a single cell
 pub fn update_cell_climate(cell: &mut Cell, climate: &ClimateState, world_pos: Vec2) {
     let mut temperature = climate.get_cell_temperature(cell.elevation, cell.terrain);
@@ -193,3 +245,51 @@ pub struct ClimateEvent {
     pub humidity_delta: f32,
     pub time_remaining: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORLD_SIZE: f32 = 400.0;
+
+    /// Temperature and humidity of a default Plains cell at `x` (y fixed)
+    fn climate_at(climate: &ClimateState, x: f32) -> (f32, f32) {
+        let mut cell = Cell::default();
+        update_cell_climate(&mut cell, climate, Vec2::new(x, 37.0));
+        (cell.temperature, cell.humidity)
+    }
+
+    fn climate_with_event_on_seam(wrapped: bool) -> ClimateState {
+        let mut climate = ClimateState::seeded(7);
+        if wrapped {
+            climate = climate.with_wrap(Vec2::splat(WORLD_SIZE));
+        }
+        climate.update(250, 1.0);
+        climate.events.push(ClimateEvent {
+            center: Vec2::new(190.0, 37.0),
+            radius: 40.0,
+            temperature_delta: 0.1,
+            humidity_delta: -0.1,
+            time_remaining: 100.0,
+        });
+        climate
+    }
+
+    #[test]
+    fn wrapped_climate_is_continuous_across_the_seam() {
+        let climate = climate_with_event_on_seam(true);
+        let (east_temp, east_humidity) = climate_at(&climate, WORLD_SIZE / 2.0 - 0.01);
+        let (west_temp, west_humidity) = climate_at(&climate, -WORLD_SIZE / 2.0 + 0.01);
+        assert!((east_temp - west_temp).abs() < 1e-3, "{east_temp} vs {west_temp}");
+        assert!((east_humidity - west_humidity).abs() < 1e-3, "{east_humidity} vs {west_humidity}");
+    }
+
+    #[test]
+    fn unwrapped_climate_jumps_at_the_seam() {
+        // Without wrapping the event only reaches the east side, so the two edges disagree
+        let climate = climate_with_event_on_seam(false);
+        let (east_temp, _) = climate_at(&climate, WORLD_SIZE / 2.0 - 0.01);
+        let (west_temp, _) = climate_at(&climate, -WORLD_SIZE / 2.0 + 0.01);
+        assert!((east_temp - west_temp).abs() > 0.01);
+    }
+}
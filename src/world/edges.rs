@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use glam::Vec2;
+
+/// Half the side of the square organisms live in, centered on the origin
+const WORLD_HALF_EXTENT: f32 = 200.0;
+
+/// What happens to organisms that reach the edge of the world square
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WorldEdges {
+    /// Organisms stop at the edge
+    #[default]
+    Clamp,
+    /// `--wrap-world`: organisms leaving one side re-enter on the opposite one, and climate is
+    /// continuous across the seam (see `ClimateState::with_wrap`)
+    Wrap,
+}
+
+impl WorldEdges {
+    pub fn from_args() -> Self {
+        if crate::utils::cli::has_flag("wrap-world") {
+            WorldEdges::Wrap
+        } else {
+            WorldEdges::Clamp
+        }
+    }
+
+    /// Width and height of the world square
+    pub fn size(self) -> Vec2 {
+        Vec2::splat(WORLD_HALF_EXTENT * 2.0)
+    }
+
+    /// Bring a position back inside the world square
+    pub fn confine(self, position: Vec2) -> Vec2 {
+        match self {
+            WorldEdges::Clamp => position.clamp(
+                Vec2::splat(-WORLD_HALF_EXTENT),
+                Vec2::splat(WORLD_HALF_EXTENT),
+            ),
+            WorldEdges::Wrap => {
+                let size = self.size();
+                let wrap = |value: f32, period: f32| {
+                    (value + WORLD_HALF_EXTENT).rem_euclid(period) - WORLD_HALF_EXTENT
+                };
+                Vec2::new(wrap(position.x, size.x), wrap(position.y, size.y))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_reenters_on_the_opposite_side() {
+        let wrapped = WorldEdges::Wrap.confine(Vec2::new(201.0, -203.0));
+        assert!((wrapped - Vec2::new(-199.0, 197.0)).length() < 1e-4);
+        let clamped = WorldEdges::Clamp.confine(Vec2::new(201.0, -203.0));
+        assert_eq!(clamped, Vec2::new(200.0, -200.0));
+    }
+}
@@ -15,14 +15,24 @@ pub struct DisasterEvents {
     pub spawn_cooldown: f32,
     /// Total disasters spawned (for tracking)
     pub total_disasters: u32,
+    /// Stream for disaster timing, placement and strength
+    rng: fastrand::Rng,
 }
 
 impl Default for DisasterEvents {
     fn default() -> Self {
+        Self::seeded(0)
+    }
+}
+
+impl DisasterEvents {
+    /// Disasters that replay from `seed` (see `WorldSeed::stream_seed`)
+    pub fn seeded(seed: u64) -> Self {
         Self {
             active_disasters: Vec::new(),
             spawn_cooldown: 500.0, // Initial cooldown (longer than climate events)
             total_disasters: 0,
+            rng: fastrand::Rng::with_seed(seed),
         }
     }
 }
@@ -121,11 +131,11 @@ pub fn update_disaster_events(
     disaster_events.spawn_cooldown -= dt;
     if disaster_events.spawn_cooldown <= 0.0 {
         // Lower probability than climate events (major disasters are rarer)
-        if fastrand::f32() < 0.001 {
+        if disaster_events.rng.f32() < 0.001 {
             spawn_random_disaster(&mut disaster_events, &world_grid);
         }
         // Reset cooldown (300-1000 seconds)
-        disaster_events.spawn_cooldown = fastrand::f32() * 700.0 + 300.0;
+        disaster_events.spawn_cooldown = disaster_events.rng.f32() * 700.0 + 300.0;
     }
 }
 
@@ -311,19 +321,22 @@ fn spawn_random_disaster(
     world_grid: &WorldGrid,
 ) {
     // Get a random position in the world (within loaded chunks)
-    let chunk_coords = world_grid.get_chunk_coords();
+    let mut chunk_coords = world_grid.get_chunk_coords();
     if chunk_coords.is_empty() {
         return;
     }
+    // The grid's map order differs between runs; sort so the seeded pick is reproducible
+    chunk_coords.sort_unstable();
 
-    let (chunk_x, chunk_y) = chunk_coords[fastrand::usize(..chunk_coords.len())];
+    let rng = &mut disaster_events.rng;
+    let (chunk_x, chunk_y) = chunk_coords[rng.usize(..chunk_coords.len())];
     let center = Vec2::new(
-        (chunk_x as f32 + fastrand::f32()) * crate::world::chunk::CHUNK_SIZE as f32,
-        (chunk_y as f32 + fastrand::f32()) * crate::world::chunk::CHUNK_SIZE as f32,
+        (chunk_x as f32 + rng.f32()) * crate::world::chunk::CHUNK_SIZE as f32,
+        (chunk_y as f32 + rng.f32()) * crate::world::chunk::CHUNK_SIZE as f32,
     );
 
     // Choose disaster type
-    let disaster_type = match fastrand::u8(..4) {
+    let disaster_type = match rng.u8(..4) {
         0 => DisasterType::Volcano,
         1 => DisasterType::Meteor,
         2 => DisasterType::Flood,
@@ -332,10 +345,10 @@ fn spawn_random_disaster(
 
     // Set parameters based on type
     let (radius, intensity, duration) = match disaster_type {
-        DisasterType::Volcano => (80.0 + fastrand::f32() * 40.0, 0.7 + fastrand::f32() * 0.3, 300.0),
-        DisasterType::Meteor => (30.0 + fastrand::f32() * 20.0, 0.8 + fastrand::f32() * 0.2, 1.0), // Instant
-        DisasterType::Flood => (60.0 + fastrand::f32() * 40.0, 0.6 + fastrand::f32() * 0.4, 200.0),
-        DisasterType::Drought => (100.0 + fastrand::f32() * 50.0, 0.5 + fastrand::f32() * 0.5, 400.0),
+        DisasterType::Volcano => (80.0 + rng.f32() * 40.0, 0.7 + rng.f32() * 0.3, 300.0),
+        DisasterType::Meteor => (30.0 + rng.f32() * 20.0, 0.8 + rng.f32() * 0.2, 1.0), // Instant
+        DisasterType::Flood => (60.0 + rng.f32() * 40.0, 0.6 + rng.f32() * 0.4, 200.0),
+        DisasterType::Drought => (100.0 + rng.f32() * 50.0, 0.5 + rng.f32() * 0.5, 400.0),
     };
 
     let disaster_id = disaster_events.total_disasters;
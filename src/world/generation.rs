@@ -0,0 +1,367 @@
+use crate::world::chunk::{Chunk, CHUNK_SIZE};
+use crate::world::grid::WorldGrid;
+use crate::world::terrain;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, AsyncComputeTaskPool, Task};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// How chunks get created once organisms reach them (see `EcosystemTuning::frontier_policy`)
+///
+/// Whatever the policy, a cell whose chunk doesn't exist yet reads as the frontier cell,
+/// `Cell::default()`: bare Plains at mid temperature and humidity with no resources,
+/// passable for every organism type. Nothing is written there - organisms can't eat from it
+/// and carcasses leave no detritus (see `WorldGrid::get_cell_or_default`).
+/// A placeholder chunk starts out as frontier cells but is otherwise an ordinary chunk while
+/// it generates: climate, regeneration and disasters update it, and grazing and carcasses
+/// write to it. The generated chunk replaces it, and anything written to it, when ready.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrontierPolicy {
+    /// Chunks organisms enter are queued, and at most `max_chunk_creations_per_tick` are
+    /// created per tick (bounded frame time, slower to fill in during bursts of exploration)
+    #[default]
+    Queued,
+    /// A placeholder is created as soon as `queue_occupied_chunks` finds an organism
+    /// registered in a missing chunk, and every placeholder starts generating at once
+    /// (the per-tick budget is ignored)
+    CreateOnAccess,
+}
+
+/// In-flight chunk generation tasks, keyed by chunk coordinates
+/// While a task runs, `WorldGrid` holds an empty placeholder chunk so every system keeps
+/// working (it just sees bare Plains with no resources until the real chunk lands).
+///
+/// Chunks organisms wander into are queued rather than created immediately: at most
+/// `EcosystemTuning::max_chunk_creations_per_tick` placeholders start generating each tick,
+/// so a burst of exploration is spread over several frames (unless `FrontierPolicy` says
+/// otherwise). Until its chunk exists an organism reads frontier cells (see `FrontierPolicy`).
+#[derive(Resource, Default)]
+pub struct ChunkGenerationTasks {
+    tasks: HashMap<(i32, i32), Task<Chunk>>,
+    /// Chunks waiting to be created, oldest first
+    queued: VecDeque<(i32, i32)>,
+}
+
+/// Queue creation of chunks that organisms have entered but that don't exist yet
+/// (or create their placeholders right away under `FrontierPolicy::CreateOnAccess`)
+pub fn queue_occupied_chunks(
+    mut generation: ResMut<ChunkGenerationTasks>,
+    mut world_grid: ResMut<WorldGrid>,
+    tuning: Option<Res<crate::organisms::EcosystemTuning>>,
+) {
+    let policy = tuning.as_deref().map(|t| t.frontier_policy).unwrap_or_default();
+    let mut missing: Vec<(i32, i32)> = world_grid
+        .occupied_chunks()
+        .map(|(coords, _)| coords)
+        .filter(|&(chunk_x, chunk_y)| world_grid.get_chunk(chunk_x, chunk_y).is_none())
+        .collect();
+    // Deterministic queue order regardless of hash iteration
+    missing.sort_unstable();
+
+    if policy == FrontierPolicy::CreateOnAccess {
+        for (chunk_x, chunk_y) in missing {
+            world_grid.get_or_create_chunk(chunk_x, chunk_y);
+        }
+        return;
+    }
+
+    for coords in missing {
+        if !generation.queued.contains(&coords) {
+            generation.queued.push_back(coords);
+        }
+    }
+}
+
+/// Start generating placeholder chunks on the async compute pool, at most
+/// `max_chunk_creations_per_tick` per tick
+/// Placeholders created elsewhere go first, then queued chunks are created in order.
+/// Under `FrontierPolicy::CreateOnAccess` every placeholder is dispatched immediately.
+pub fn dispatch_chunk_generation(
+    mut generation: ResMut<ChunkGenerationTasks>,
+    mut world_grid: ResMut<WorldGrid>,
+    tuning: Option<Res<crate::organisms::EcosystemTuning>>,
+    world_seed: Res<terrain::WorldSeed>,
+    generator: Res<terrain::ActiveTerrainGenerator>,
+) {
+    let (seed_fraction, mut budget, policy) = tuning
+        .as_deref()
+        .map(|t| {
+            (
+                t.initial_resource_seed_fraction,
+                t.max_chunk_creations_per_tick,
+                t.frontier_policy,
+            )
+        })
+        .unwrap_or((0.5, 2, FrontierPolicy::Queued));
+    let world_seed = *world_seed;
+    let pool = AsyncComputeTaskPool::get();
+
+    let mut to_generate: Vec<(i32, i32)> = world_grid
+        .pending_chunks()
+        .filter(|coords| !generation.tasks.contains_key(coords))
+        .collect();
+    to_generate.sort_unstable();
+    if policy == FrontierPolicy::CreateOnAccess {
+        budget = budget.max(to_generate.len());
+    }
+    to_generate.truncate(budget);
+    budget -= to_generate.len();
+
+    while budget > 0 {
+        let Some((chunk_x, chunk_y)) = generation.queued.pop_front() else {
+            break;
+        };
+        // Another system may have created it while it waited
+        if world_grid.get_chunk(chunk_x, chunk_y).is_some() {
+            continue;
+        }
+        world_grid.get_or_create_chunk(chunk_x, chunk_y);
+        to_generate.push((chunk_x, chunk_y));
+        budget -= 1;
+    }
+
+    for (chunk_x, chunk_y) in to_generate {
+        let generator = generator.0.clone();
+        generation.tasks.insert(
+            (chunk_x, chunk_y),
+            pool.spawn(async move {
+                let mut chunk = Chunk::new(chunk_x, chunk_y);
+                terrain::initialize_chunk(&mut chunk, generator.as_ref(), world_seed, seed_fraction);
+                chunk
+            }),
+        );
+    }
+
+    if !generation.queued.is_empty() {
+        debug!(
+            "[WORLD] {} chunks generating, {} queued",
+            generation.tasks.len(),
+            generation.queued.len()
+        );
+    }
+}
+
+/// Swap finished chunks into the world grid
+pub fn apply_generated_chunks(
+    mut generation: ResMut<ChunkGenerationTasks>,
+    mut world_grid: ResMut<WorldGrid>,
+) {
+    let finished: Vec<(i32, i32)> = generation
+        .tasks
+        .iter()
+        .filter(|(_, task)| task.is_finished())
+        .map(|(coords, _)| *coords)
+        .collect();
+
+    for coords in finished {
+        let Some(task) = generation.tasks.remove(&coords) else {
+            continue;
+        };
+        let chunk = block_on(task);
+
+        // The placeholder may have been unloaded while generating
+        if world_grid.is_chunk_pending(coords.0, coords.1) {
+            world_grid.insert_chunk(chunk);
+            debug!("[WORLD] Generated chunk ({}, {})", coords.0, coords.1);
+        }
+    }
+}
+
+/// Timing of one frontier policy in `benchmark_chunk_generation`
+pub struct ChunkBurstTiming {
+    pub policy: FrontierPolicy,
+    /// From the burst arriving until every chunk has been swapped in
+    pub total: Duration,
+    /// Longest single frame of queue, dispatch and apply (the worst stall)
+    pub longest_frame: Duration,
+}
+
+/// Timing of `benchmark_chunk_generation`
+pub struct ChunkGenerationTiming {
+    pub chunks: usize,
+    /// `initialize_chunk` for the whole burst on the calling thread (a one-frame stall)
+    pub synchronous: Duration,
+    pub bursts: Vec<ChunkBurstTiming>,
+}
+
+/// Time a burst of `chunk_count` new chunks generated synchronously against the async path
+/// The async path registers one organism in each missing chunk and runs `queue_occupied_chunks`,
+/// `dispatch_chunk_generation` and `apply_generated_chunks` back to back until all chunks are
+/// in, once per frontier policy. Frames aren't paced, so `total` is the time to fill the world
+/// in rather than a number of frames.
+pub fn benchmark_chunk_generation(
+    chunk_count: usize,
+    generator: &terrain::ActiveTerrainGenerator,
+    world_seed: terrain::WorldSeed,
+) -> ChunkGenerationTiming {
+    let defaults = crate::organisms::EcosystemTuning::default();
+    // A row of chunks well away from the origin, so none exist beforehand
+    let coords: Vec<(i32, i32)> = (0..chunk_count as i32).map(|x| (x, 1000)).collect();
+
+    let synchronous_start = Instant::now();
+    for &(chunk_x, chunk_y) in &coords {
+        let mut chunk = Chunk::new(chunk_x, chunk_y);
+        terrain::initialize_chunk(
+            &mut chunk,
+            generator.0.as_ref(),
+            world_seed,
+            defaults.initial_resource_seed_fraction,
+        );
+        std::hint::black_box(&chunk);
+    }
+    let synchronous = synchronous_start.elapsed();
+
+    AsyncComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+    let bursts = [FrontierPolicy::Queued, FrontierPolicy::CreateOnAccess]
+        .into_iter()
+        .map(|policy| {
+            let mut world = World::new();
+            let mut grid = WorldGrid::default();
+            for (index, &(chunk_x, chunk_y)) in coords.iter().enumerate() {
+                let center = (Vec2::new(chunk_x as f32, chunk_y as f32) + 0.5) * CHUNK_SIZE as f32;
+                grid.update_organism_chunk(Entity::from_raw(index as u32), center);
+            }
+            world.insert_resource(grid);
+            world.insert_resource(ChunkGenerationTasks::default());
+            world.insert_resource(crate::organisms::EcosystemTuning {
+                frontier_policy: policy,
+                ..crate::organisms::EcosystemTuning::default()
+            });
+            world.insert_resource(world_seed);
+            world.insert_resource(generator.clone());
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(
+                (queue_occupied_chunks, dispatch_chunk_generation, apply_generated_chunks).chain(),
+            );
+
+            let mut longest_frame = Duration::ZERO;
+            let start = Instant::now();
+            loop {
+                let frame_start = Instant::now();
+                schedule.run(&mut world);
+                longest_frame = longest_frame.max(frame_start.elapsed());
+                let grid = world.resource::<WorldGrid>();
+                let generated = coords.iter().all(|&(chunk_x, chunk_y)| {
+                    grid.get_chunk(chunk_x, chunk_y).is_some()
+                        && !grid.is_chunk_pending(chunk_x, chunk_y)
+                });
+                if generated {
+                    break;
+                }
+            }
+            ChunkBurstTiming {
+                policy,
+                total: start.elapsed(),
+                longest_frame,
+            }
+        })
+        .collect();
+
+    ChunkGenerationTiming {
+        chunks: chunk_count,
+        synchronous,
+        bursts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::EcosystemTuning;
+    use crate::world::cell::{Cell, ResourceType};
+
+    /// World with one organism registered at `position`, after one `queue_occupied_chunks`
+    fn queue_with_policy(policy: FrontierPolicy, position: Vec2) -> World {
+        let mut world = World::new();
+        let mut grid = WorldGrid::default();
+        grid.update_organism_chunk(Entity::from_raw(1), position);
+        world.insert_resource(grid);
+        world.insert_resource(ChunkGenerationTasks::default());
+        world.insert_resource(EcosystemTuning {
+            frontier_policy: policy,
+            ..EcosystemTuning::default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(queue_occupied_chunks);
+        schedule.run(&mut world);
+        world
+    }
+
+    #[test]
+    fn missing_chunks_read_as_frontier_cells() {
+        let mut grid = WorldGrid::default();
+        let position = Vec2::new(500.0, -500.0);
+        let cell = grid.get_cell_or_default(position.x, position.y);
+        let frontier = Cell::default();
+        assert_eq!(cell.terrain, frontier.terrain);
+        assert_eq!(cell.get_resource(ResourceType::Plant), 0.0);
+
+        // Writes are dropped rather than creating the chunk
+        let deposited = crate::organisms::deposit_detritus(&mut grid, position, 1.0);
+        assert_eq!(deposited, 0.0);
+        assert!(grid.get_cell(position.x, position.y).is_none());
+    }
+
+    #[test]
+    fn queued_policy_waits_for_dispatch() {
+        let position = Vec2::new(500.0, -500.0);
+        let world = queue_with_policy(FrontierPolicy::Queued, position);
+        let grid = world.resource::<WorldGrid>();
+        assert!(grid.get_cell(position.x, position.y).is_none());
+        let coords = Chunk::world_to_chunk(position.x, position.y);
+        assert!(world.resource::<ChunkGenerationTasks>().queued.contains(&coords));
+    }
+
+    #[test]
+    fn create_on_access_makes_a_placeholder_at_once() {
+        let position = Vec2::new(500.0, -500.0);
+        let world = queue_with_policy(FrontierPolicy::CreateOnAccess, position);
+        let grid = world.resource::<WorldGrid>();
+        let (chunk_x, chunk_y) = Chunk::world_to_chunk(position.x, position.y);
+        assert!(grid.is_chunk_pending(chunk_x, chunk_y));
+        // The placeholder reads exactly like the frontier until it's generated
+        let cell = grid.get_cell(position.x, position.y).copied().unwrap();
+        assert_eq!(cell.terrain, Cell::default().terrain);
+        assert!(world.resource::<ChunkGenerationTasks>().queued.is_empty());
+    }
+
+    #[test]
+    fn chunk_creations_per_tick_are_capped() {
+        bevy::tasks::AsyncComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut world = World::new();
+        let mut grid = WorldGrid::default();
+        for i in 0..5 {
+            grid.update_organism_chunk(Entity::from_raw(i), Vec2::new(100.0 * i as f32, 500.0));
+        }
+        world.insert_resource(grid);
+        world.insert_resource(ChunkGenerationTasks::default());
+        world.insert_resource(EcosystemTuning {
+            max_chunk_creations_per_tick: 2,
+            ..EcosystemTuning::default()
+        });
+        world.insert_resource(terrain::WorldSeed::default());
+        world.insert_resource(terrain::ActiveTerrainGenerator::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((queue_occupied_chunks, dispatch_chunk_generation).chain());
+        // Five occupied chunks go out 2, then 2, then the last one
+        for started in [2, 4, 5] {
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<ChunkGenerationTasks>().tasks.len(), started);
+        }
+    }
+
+    #[test]
+    fn chunk_generation_benchmark_fills_in_every_burst() {
+        let timing = benchmark_chunk_generation(
+            3,
+            &terrain::ActiveTerrainGenerator::default(),
+            terrain::WorldSeed(5),
+        );
+        assert_eq!(timing.chunks, 3);
+        let policies: Vec<_> = timing.bursts.iter().map(|burst| burst.policy).collect();
+        assert_eq!(policies, [FrontierPolicy::Queued, FrontierPolicy::CreateOnAccess]);
+    }
+}
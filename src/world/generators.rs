@@ -0,0 +1,242 @@
+use crate::world::cell::TerrainType;
+use crate::world::chunk::{Chunk, CHUNK_SIZE};
+use crate::world::terrain::{RadialTerrain, TerrainGenerator, WorldSeed};
+use std::sync::Arc;
+
+/// Look up a terrain generator by name (`radial`, `noise`, `continents`, `flat`)
+/// `flat:<terrain>` picks the flat generator's terrain, e.g. `flat:forest`.
+pub fn terrain_generator_by_name(name: &str) -> Option<Arc<dyn TerrainGenerator>> {
+    let name = name.trim().to_ascii_lowercase();
+    let (kind, argument) = match name.split_once(':') {
+        Some((kind, argument)) => (kind, Some(argument)),
+        None => (name.as_str(), None),
+    };
+
+    match (kind, argument) {
+        ("radial", None) => Some(Arc::new(RadialTerrain)),
+        ("noise", None) => Some(Arc::new(NoiseTerrain::default())),
+        ("continents", None) => Some(Arc::new(ContinentsTerrain::default())),
+        ("flat", None) => Some(Arc::new(FlatTerrain::default())),
+        ("flat", Some(terrain)) => TerrainType::ALL
+            .into_iter()
+            .find(|candidate| format!("{candidate:?}").eq_ignore_ascii_case(terrain))
+            .map(|terrain| Arc::new(FlatTerrain::new(terrain)) as Arc<dyn TerrainGenerator>),
+        _ => None,
+    }
+}
+
+/// Uniform terrain at a single elevation, for behavior tests that shouldn't depend on the map
+#[derive(Debug, Clone, Copy)]
+pub struct FlatTerrain {
+    pub terrain: TerrainType,
+    pub elevation: u16,
+}
+
+impl FlatTerrain {
+    pub fn new(terrain: TerrainType) -> Self {
+        Self {
+            terrain,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for FlatTerrain {
+    fn default() -> Self {
+        Self {
+            terrain: TerrainType::Plains,
+            elevation: 22937, // 0.35 of the range: above the Ocean band (< 0.3) of the other generators
+        }
+    }
+}
+
+impl TerrainGenerator for FlatTerrain {
+    fn name(&self) -> &'static str {
+        "flat"
+    }
+
+    fn generate(&self, chunk: &mut Chunk, _world_seed: WorldSeed) {
+        for cell in chunk.cells_mut().iter_mut() {
+            cell.terrain = self.terrain;
+            cell.elevation = self.elevation;
+        }
+    }
+}
+
+/// Terrain from fractal value noise sampled in world coordinates
+/// Elevation picks the band (ocean, lowland, upland, peaks) and a second moisture field
+/// picks the biome within it. Noise is continuous across chunk borders, so no smoothing
+/// pass is needed.
+#[derive(Debug, Clone, Copy)]
+pub struct NoiseTerrain {
+    /// Size of the largest features in cells
+    pub feature_size: f32,
+    pub octaves: u32,
+}
+
+impl Default for NoiseTerrain {
+    fn default() -> Self {
+        Self {
+            feature_size: 48.0,
+            octaves: 4,
+        }
+    }
+}
+
+impl TerrainGenerator for NoiseTerrain {
+    fn name(&self) -> &'static str {
+        "noise"
+    }
+
+    fn generate(&self, chunk: &mut Chunk, world_seed: WorldSeed) {
+        let elevation_seed = world_seed.0;
+        let moisture_seed = world_seed.0 ^ MOISTURE_SALT;
+        fill_chunk(chunk, |x, y| {
+            let elevation = fractal_noise(elevation_seed, x, y, self.feature_size, self.octaves);
+            let moisture = fractal_noise(moisture_seed, x, y, self.feature_size, self.octaves);
+            let terrain = if elevation < 0.3 {
+                TerrainType::Ocean
+            } else {
+                biome(elevation, moisture)
+            };
+            (elevation, terrain)
+        });
+    }
+}
+
+/// A few large landmasses separated by open ocean
+/// A very low-frequency field decides land or sea; detail noise only shapes the land, so
+/// coasts stay coherent over hundreds of cells.
+#[derive(Debug, Clone, Copy)]
+pub struct ContinentsTerrain {
+    /// Approximate continent size in cells
+    pub continent_size: f32,
+    /// Share of the continent field below which cells are ocean (0-1)
+    pub sea_level: f32,
+}
+
+impl Default for ContinentsTerrain {
+    fn default() -> Self {
+        Self {
+            continent_size: 256.0,
+            sea_level: 0.5,
+        }
+    }
+}
+
+impl TerrainGenerator for ContinentsTerrain {
+    fn name(&self) -> &'static str {
+        "continents"
+    }
+
+    fn generate(&self, chunk: &mut Chunk, world_seed: WorldSeed) {
+        let continent_seed = world_seed.0 ^ CONTINENT_SALT;
+        let detail_seed = world_seed.0;
+        let moisture_seed = world_seed.0 ^ MOISTURE_SALT;
+        let sea_level = self.sea_level.clamp(0.05, 0.95);
+        fill_chunk(chunk, |x, y| {
+            let continent = fractal_noise(continent_seed, x, y, self.continent_size, 3);
+            if continent < sea_level {
+                // Deeper water further from the coast
+                return (continent / sea_level * 0.3, TerrainType::Ocean);
+            }
+
+            // Inland height rises with distance from the coast, roughened by detail noise
+            let inland = (continent - sea_level) / (1.0 - sea_level);
+            let detail = fractal_noise(detail_seed, x, y, self.continent_size / 8.0, 4);
+            let elevation = 0.3 + 0.7 * (inland * 0.6 + detail * 0.4);
+            let moisture = fractal_noise(moisture_seed, x, y, self.continent_size / 4.0, 3);
+            (elevation, biome(elevation, moisture))
+        });
+    }
+}
+
+/// Decorrelates the moisture and continent fields from elevation for the same world seed
+const MOISTURE_SALT: u64 = 0x5DEE_CE66_D1CE_4E5B;
+const CONTINENT_SALT: u64 = 0xA076_1D64_78BD_642F;
+
+/// Set every cell of a chunk from `sample(world_x, world_y) -> (elevation 0-1, terrain)`
+fn fill_chunk(chunk: &mut Chunk, sample: impl Fn(f32, f32) -> (f32, TerrainType)) {
+    let origin_x = chunk.chunk_x as f32 * CHUNK_SIZE as f32;
+    let origin_y = chunk.chunk_y as f32 * CHUNK_SIZE as f32;
+    for y in 0..CHUNK_SIZE {
+        for x in 0..CHUNK_SIZE {
+            let (elevation, terrain) = sample(origin_x + x as f32, origin_y + y as f32);
+            if let Some(cell) = chunk.get_cell_mut(x, y) {
+                cell.elevation = (elevation.clamp(0.0, 1.0) * 65535.0) as u16;
+                cell.terrain = terrain;
+            }
+        }
+    }
+}
+
+/// Land biome for an elevation (0.3-1) and moisture (0-1)
+fn biome(elevation: f32, moisture: f32) -> TerrainType {
+    if elevation < 0.36 {
+        if moisture > 0.6 {
+            TerrainType::Swamp
+        } else {
+            TerrainType::Plains
+        }
+    } else if elevation < 0.6 {
+        if moisture < 0.35 {
+            TerrainType::Desert
+        } else if moisture < 0.55 {
+            TerrainType::Plains
+        } else {
+            TerrainType::Forest
+        }
+    } else if elevation < 0.72 {
+        TerrainType::Tundra
+    } else if elevation > 0.85 && moisture < 0.3 {
+        TerrainType::Volcanic
+    } else {
+        TerrainType::Mountain
+    }
+}
+
+/// Fractal (multi-octave) value noise in [0, 1], `feature_size` cells per base lattice step
+fn fractal_noise(seed: u64, x: f32, y: f32, feature_size: f32, octaves: u32) -> f32 {
+    let mut frequency = 1.0 / feature_size.max(1.0);
+    let mut amplitude = 1.0;
+    let mut total = 0.0;
+    let mut weight = 0.0;
+    for octave in 0..octaves.max(1) {
+        let octave_seed = seed.wrapping_add((octave as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        total += value_noise(octave_seed, x * frequency, y * frequency) * amplitude;
+        weight += amplitude;
+        frequency *= 2.0;
+        amplitude *= 0.5;
+    }
+    total / weight
+}
+
+/// Smoothly interpolated lattice noise in [0, 1]
+fn value_noise(seed: u64, x: f32, y: f32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (smoothstep(x - x0), smoothstep(y - y0));
+    let (ix, iy) = (x0 as i64, y0 as i64);
+
+    let top = lerp(lattice(seed, ix, iy), lattice(seed, ix + 1, iy), fx);
+    let bottom = lerp(lattice(seed, ix, iy + 1), lattice(seed, ix + 1, iy + 1), fx);
+    lerp(top, bottom, fy)
+}
+
+/// Deterministic value in [0, 1) for a lattice point
+fn lattice(seed: u64, x: i64, y: i64) -> f32 {
+    let mut hash = seed ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    // splitmix64 finalizer
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
@@ -1,7 +1,9 @@
-use crate::world::cell::Cell;
+use crate::world::cell::{Cell, ResourceType, TerrainType, RESOURCE_TYPE_COUNT};
 use crate::world::chunk::Chunk;
+use crate::world::resources::{clamp_to_capacity, resource_capacity};
 use bevy::prelude::*;
-use std::collections::HashMap;
+use glam::Vec2;
+use std::collections::{HashMap, HashSet};
 
 /// The world grid manages chunks in a sparse storage system
 /// Only active chunks are kept in memory for efficiency
@@ -11,19 +13,44 @@ pub struct WorldGrid {
     chunks: HashMap<(i32, i32), Chunk>,
     /// Set of dirty chunks that need updates this tick
     dirty_chunks: Vec<(i32, i32)>,
+    /// Organisms registered in each chunk (maintained by `update_spatial_hash`)
+    chunk_organisms: HashMap<(i32, i32), HashSet<Entity>>,
+    /// Chunk each registered organism is currently in
+    organism_chunks: HashMap<Entity, (i32, i32)>,
+    /// Placeholder chunks still waiting for terrain generation (see `generation`)
+    pending_generation: HashSet<(i32, i32)>,
 }
 
 impl WorldGrid {
     /// Get or create a chunk at the specified chunk coordinates
+    /// New chunks start as empty placeholders and are generated off-thread
     pub fn get_or_create_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> &mut Chunk {
         let key = (chunk_x, chunk_y);
         if !self.chunks.contains_key(&key) {
             let chunk = Chunk::new(chunk_x, chunk_y);
             self.chunks.insert(key, chunk);
+            self.pending_generation.insert(key);
         }
         self.chunks.get_mut(&key).unwrap()
     }
 
+    /// Insert a fully generated chunk, replacing any placeholder at its coordinates
+    pub fn insert_chunk(&mut self, chunk: Chunk) {
+        let key = (chunk.chunk_x, chunk.chunk_y);
+        self.pending_generation.remove(&key);
+        self.chunks.insert(key, chunk);
+    }
+
+    /// Whether a chunk is still a placeholder awaiting generation
+    pub fn is_chunk_pending(&self, chunk_x: i32, chunk_y: i32) -> bool {
+        self.pending_generation.contains(&(chunk_x, chunk_y))
+    }
+
+    /// Coordinates of placeholder chunks awaiting generation
+    pub fn pending_chunks(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.pending_generation.iter().copied()
+    }
+
     /// Get a chunk without creating it if it doesn't exist
     pub fn get_chunk(&self, chunk_x: i32, chunk_y: i32) -> Option<&Chunk> {
         self.chunks.get(&(chunk_x, chunk_y))
@@ -43,6 +70,14 @@ impl WorldGrid {
             .and_then(|chunk| chunk.get_cell(local_x, local_y))
     }
 
+    /// Copy of the cell at world coordinates, or the frontier cell (`Cell::default()`) where no
+    /// chunk exists yet - safe for organisms that wander ahead of chunk generation
+    /// Lookups that return `None` from `get_cell` must behave as if they had read this cell
+    /// (see `FrontierPolicy`).
+    pub fn get_cell_or_default(&self, world_x: f32, world_y: f32) -> Cell {
+        self.get_cell(world_x, world_y).copied().unwrap_or_default()
+    }
+
     /// Get a mutable cell at world coordinates (creates chunk if needed)
     pub fn get_cell_mut(&mut self, world_x: f32, world_y: f32) -> Option<&mut Cell> {
         let (chunk_x, chunk_y) = Chunk::world_to_chunk(world_x, world_y);
@@ -52,6 +87,66 @@ impl WorldGrid {
         chunk.get_cell_mut(local_x, local_y)
     }
 
+    /// Add `amount` of a resource to every loaded cell within `radius` of `center` (negative
+    /// amounts remove it), keeping each cell within [0, terrain capacity]
+    /// Returns the number of cells changed (see `for_each_cell_in_radius`).
+    pub fn add_resource_in_radius(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        resource_type: ResourceType,
+        amount: f32,
+    ) -> usize {
+        self.for_each_cell_in_radius(center, radius, |cell| {
+            let capacity = resource_capacity(cell.terrain, resource_type);
+            let updated = (cell.get_resource(resource_type) + amount).clamp(0.0, capacity);
+            cell.set_resource(resource_type, updated);
+        })
+    }
+
+    /// Turn every loaded cell within `radius` of `center` into `terrain`, clamping its
+    /// resources to the new terrain's capacities
+    /// Returns the number of cells changed (see `for_each_cell_in_radius`).
+    pub fn set_terrain_in_radius(&mut self, center: Vec2, radius: f32, terrain: TerrainType) -> usize {
+        self.for_each_cell_in_radius(center, radius, |cell| {
+            cell.terrain = terrain;
+            clamp_to_capacity(cell);
+        })
+    }
+
+    /// Apply `edit` to every cell whose center is within `radius` of `center` (the cell under
+    /// `center` is always included), marking the touched cells and chunks dirty
+    /// Missing and placeholder chunks are skipped - generation would overwrite the edit.
+    fn for_each_cell_in_radius(&mut self, center: Vec2, radius: f32, mut edit: impl FnMut(&mut Cell)) -> usize {
+        let radius = radius.max(0.0);
+        let radius_sq = radius * radius;
+        let center_cell = (center.x.floor() as i32, center.y.floor() as i32);
+        let mut touched = 0;
+
+        for y in (center.y - radius).floor() as i32..=(center.y + radius).floor() as i32 {
+            for x in (center.x - radius).floor() as i32..=(center.x + radius).floor() as i32 {
+                let cell_center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if (x, y) != center_cell && cell_center.distance_squared(center) > radius_sq {
+                    continue;
+                }
+
+                let (chunk_x, chunk_y) = Chunk::world_to_chunk(cell_center.x, cell_center.y);
+                if self.is_chunk_pending(chunk_x, chunk_y) {
+                    continue;
+                }
+                let (local_x, local_y) = Chunk::world_to_local(cell_center.x, cell_center.y);
+                if let Some(cell) = self
+                    .get_chunk_mut(chunk_x, chunk_y)
+                    .and_then(|chunk| chunk.get_cell_mut(local_x, local_y))
+                {
+                    edit(cell);
+                    touched += 1;
+                }
+            }
+        }
+        touched
+    }
+
     /// Get all dirty chunks (chunks that have been modified)
     pub fn get_dirty_chunks(&self) -> Vec<(i32, i32)> {
         self.chunks
@@ -79,8 +174,105 @@ impl WorldGrid {
         self.chunks.keys().copied().collect()
     }
 
+    /// Sum of every loaded cell's resource densities, per resource type
+    /// Uses f64 accumulators so totals stay precise across many cells
+    pub fn total_resources(&self) -> [f64; RESOURCE_TYPE_COUNT] {
+        let mut totals = [0.0f64; RESOURCE_TYPE_COUNT];
+        for chunk in self.chunks.values() {
+            for cell in chunk.cells().iter() {
+                for (total, density) in totals.iter_mut().zip(cell.resource_density.iter()) {
+                    *total += *density as f64;
+                }
+            }
+        }
+        totals
+    }
+
+    /// Resource totals broken down by terrain type (only terrains present in loaded chunks)
+    pub fn total_resources_by_terrain(&self) -> HashMap<TerrainType, [f64; RESOURCE_TYPE_COUNT]> {
+        let mut totals: HashMap<TerrainType, [f64; RESOURCE_TYPE_COUNT]> = HashMap::new();
+        for chunk in self.chunks.values() {
+            for cell in chunk.cells().iter() {
+                let terrain_totals = totals
+                    .entry(cell.terrain)
+                    .or_insert([0.0; RESOURCE_TYPE_COUNT]);
+                for (total, density) in terrain_totals.iter_mut().zip(cell.resource_density.iter()) {
+                    *total += *density as f64;
+                }
+            }
+        }
+        totals
+    }
+
     /// Remove a chunk (useful for cleanup of distant chunks)
-    pub fn remove_chunk(&mut self, chunk_x: i32, chunk_y: i32) {
-        self.chunks.remove(&(chunk_x, chunk_y));
+    /// Chunks with registered organisms are kept; returns whether the chunk was removed
+    pub fn remove_chunk(&mut self, chunk_x: i32, chunk_y: i32) -> bool {
+        if self.chunk_organism_count(chunk_x, chunk_y) > 0 {
+            return false;
+        }
+        self.pending_generation.remove(&(chunk_x, chunk_y));
+        self.chunks.remove(&(chunk_x, chunk_y)).is_some()
+    }
+
+    /// Organisms currently registered in a chunk
+    pub fn organisms_in_chunk(&self, chunk_x: i32, chunk_y: i32) -> impl Iterator<Item = Entity> + '_ {
+        self.chunk_organisms
+            .get(&(chunk_x, chunk_y))
+            .into_iter()
+            .flat_map(|organisms| organisms.iter().copied())
+    }
+
+    /// Number of organisms registered in a chunk
+    pub fn chunk_organism_count(&self, chunk_x: i32, chunk_y: i32) -> usize {
+        self.chunk_organisms
+            .get(&(chunk_x, chunk_y))
+            .map_or(0, |organisms| organisms.len())
+    }
+
+    /// Chunks that currently have registered organisms, with their counts
+    pub fn occupied_chunks(&self) -> impl Iterator<Item = ((i32, i32), usize)> + '_ {
+        self.chunk_organisms
+            .iter()
+            .map(|(coords, organisms)| (*coords, organisms.len()))
+    }
+
+    /// Register an organism at a world position, moving it between chunk sets if it crossed a boundary
+    pub fn update_organism_chunk(&mut self, entity: Entity, position: Vec2) {
+        let chunk = Chunk::world_to_chunk(position.x, position.y);
+        match self.organism_chunks.insert(entity, chunk) {
+            Some(previous) if previous == chunk => return,
+            Some(previous) => self.unregister_from_chunk(entity, previous),
+            None => {}
+        }
+        self.chunk_organisms.entry(chunk).or_default().insert(entity);
+    }
+
+    /// Remove an organism from the chunk registry
+    pub fn remove_organism(&mut self, entity: Entity) {
+        if let Some(chunk) = self.organism_chunks.remove(&entity) {
+            self.unregister_from_chunk(entity, chunk);
+        }
+    }
+
+    /// Drop registry entries for organisms that fail `keep`
+    pub fn retain_organisms(&mut self, mut keep: impl FnMut(Entity) -> bool) {
+        let stale: Vec<Entity> = self
+            .organism_chunks
+            .keys()
+            .copied()
+            .filter(|entity| !keep(*entity))
+            .collect();
+        for entity in stale {
+            self.remove_organism(entity);
+        }
+    }
+
+    fn unregister_from_chunk(&mut self, entity: Entity, chunk: (i32, i32)) {
+        if let Some(organisms) = self.chunk_organisms.get_mut(&chunk) {
+            organisms.remove(&entity);
+            if organisms.is_empty() {
+                self.chunk_organisms.remove(&chunk);
+            }
+        }
     }
 }
@@ -0,0 +1,158 @@
+use crate::world::cell::{ResourceType, TerrainType};
+use crate::world::chunk::{Chunk, CHUNK_SIZE};
+use crate::world::grid::WorldGrid;
+use crate::world::resources::resource_capacity;
+use bevy::prelude::*;
+
+/// Route a fraction of each cell's water to its lower neighbors
+/// Outflow is split by elevation drop, so water runs down the steepest slopes and pools in
+/// local minima (basins) and Ocean cells, which never drain. Mass is conserved: a share
+/// that doesn't fit in a full neighbor stays where it was.
+/// Returns the new water density per cell (row-major); like `flow_resources`, flow stops at
+/// chunk borders.
+fn route_water(chunk: &Chunk, outflow_fraction: f32) -> Vec<f32> {
+    let cell_at = |x: usize, y: usize| chunk.get_cell(x, y).expect("cell in chunk bounds");
+    let water: Vec<f32> = (0..CHUNK_SIZE * CHUNK_SIZE)
+        .map(|index| cell_at(index % CHUNK_SIZE, index / CHUNK_SIZE).get_resource(ResourceType::Water))
+        .collect();
+    let mut next = water.clone();
+
+    for y in 0..CHUNK_SIZE {
+        for x in 0..CHUNK_SIZE {
+            let index = y * CHUNK_SIZE + x;
+            let cell = cell_at(x, y);
+            if water[index] <= 0.0 || cell.terrain == TerrainType::Ocean {
+                continue;
+            }
+
+            let mut lower = Vec::with_capacity(8);
+            let mut total_drop = 0.0;
+            for dy in -1..=1isize {
+                for dx in -1..=1isize {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let nx = x as isize + dx;
+                    let ny = y as isize + dy;
+                    if nx < 0 || ny < 0 || nx >= CHUNK_SIZE as isize || ny >= CHUNK_SIZE as isize {
+                        continue;
+                    }
+                    let neighbor = cell_at(nx as usize, ny as usize);
+                    if neighbor.elevation < cell.elevation {
+                        let drop = (cell.elevation - neighbor.elevation) as f32;
+                        let capacity = resource_capacity(neighbor.terrain, ResourceType::Water);
+                        lower.push((ny as usize * CHUNK_SIZE + nx as usize, drop, capacity));
+                        total_drop += drop;
+                    }
+                }
+            }
+            if lower.is_empty() {
+                continue; // Basin - water pools here
+            }
+
+            let outflow = water[index] * outflow_fraction;
+            for (neighbor_index, drop, capacity) in lower {
+                let room = (capacity - next[neighbor_index]).max(0.0);
+                let moved = (outflow * drop / total_drop).min(room).min(next[index]);
+                next[neighbor_index] += moved;
+                next[index] -= moved;
+            }
+        }
+    }
+
+    next
+}
+
+/// Hydrology: water flows downhill, forming rivers toward basins and oceans
+pub fn flow_water_downhill(
+    mut world_grid: ResMut<WorldGrid>,
+    time: Res<Time>,
+    tuning: Option<Res<crate::organisms::EcosystemTuning>>,
+) {
+    use rayon::prelude::*;
+
+    let strength = tuning.as_deref().map(|t| t.water_flow_strength).unwrap_or(0.2);
+    let outflow_fraction = (strength * time.delta_seconds()).clamp(0.0, 1.0);
+    if outflow_fraction <= 0.0 {
+        return;
+    }
+
+    let chunk_coords = world_grid.get_chunk_coords();
+    let routed: Vec<_> = chunk_coords
+        .par_iter()
+        .filter_map(|&(chunk_x, chunk_y)| {
+            world_grid
+                .get_chunk(chunk_x, chunk_y)
+                .map(|chunk| (chunk_x, chunk_y, route_water(chunk, outflow_fraction)))
+        })
+        .collect();
+
+    for (chunk_x, chunk_y, water) in routed {
+        if let Some(chunk) = world_grid.get_chunk_mut(chunk_x, chunk_y) {
+            for (index, value) in water.into_iter().enumerate() {
+                if let Some(cell) = chunk.get_cell_mut(index % CHUNK_SIZE, index / CHUNK_SIZE) {
+                    cell.set_resource(ResourceType::Water, value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plains chunk sloping down toward +x, with the same water everywhere
+    fn sloped_chunk(water: f32) -> Chunk {
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let cell = chunk.get_cell_mut(x, y).unwrap();
+                cell.terrain = TerrainType::Plains;
+                cell.elevation = ((CHUNK_SIZE - x) * 1000) as u16;
+                cell.set_resource(ResourceType::Water, water);
+            }
+        }
+        chunk
+    }
+
+    fn column_water(chunk: &Chunk, x: usize) -> f32 {
+        (0..CHUNK_SIZE)
+            .map(|y| chunk.get_cell(x, y).unwrap().get_resource(ResourceType::Water))
+            .sum()
+    }
+
+    #[test]
+    fn water_accumulates_downhill() {
+        let mut chunk = sloped_chunk(0.1);
+        let total_before: f32 = (0..CHUNK_SIZE).map(|x| column_water(&chunk, x)).sum();
+
+        for _ in 0..50 {
+            let water = route_water(&chunk, 0.2);
+            for (index, value) in water.into_iter().enumerate() {
+                let cell = chunk.get_cell_mut(index % CHUNK_SIZE, index / CHUNK_SIZE).unwrap();
+                cell.set_resource(ResourceType::Water, value);
+            }
+        }
+
+        let initial_column = 0.1 * CHUNK_SIZE as f32;
+        let top = column_water(&chunk, 0);
+        let bottom = column_water(&chunk, CHUNK_SIZE - 1);
+        assert!(top < initial_column, "top column kept {top}");
+        assert!(bottom > initial_column, "bottom column only has {bottom}");
+
+        // Water only moves; none is created or lost
+        let total_after: f32 = (0..CHUNK_SIZE).map(|x| column_water(&chunk, x)).sum();
+        assert!((total_after - total_before).abs() < 1e-3);
+    }
+
+    #[test]
+    fn flat_ground_holds_its_water() {
+        let mut chunk = sloped_chunk(0.1);
+        for cell in chunk.cells_mut().iter_mut() {
+            cell.elevation = 1000;
+        }
+        let water = route_water(&chunk, 0.5);
+        assert!(water.iter().all(|value| (value - 0.1).abs() < 1e-6));
+    }
+}
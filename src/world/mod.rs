@@ -1,10 +1,15 @@
 mod cell;
 mod chunk;
 mod climate;
+mod edges;
 mod grid;
 mod resources;
 mod terrain;
 mod events;
+mod summary;
+mod generation;
+mod generators;
+mod hydrology;
 
 use bevy::prelude::*;
 use bevy::time::Time;
@@ -12,17 +17,26 @@ use glam::Vec2;
 use std::collections::HashSet;
 
 pub use cell::Cell;
-pub use cell::{ResourceType, TerrainType};
-pub use chunk::Chunk;
+pub use cell::{ResourceType, TerrainType, RESOURCE_TYPE_COUNT};
+pub use chunk::{Chunk, CHUNK_SIZE};
 pub use climate::ClimateState;
+pub use edges::WorldEdges;
 pub use grid::WorldGrid;
 pub use resources::*;
 pub use terrain::*;
 pub use events::*;
+pub use summary::ChunkSummary;
+pub use generation::{benchmark_chunk_generation, ChunkGenerationTasks, FrontierPolicy};
+pub use generators::{ContinentsTerrain, FlatTerrain, NoiseTerrain};
 
 // Re-export specific types for visualization
 pub use events::{DisasterEvents, Disaster, DisasterType};
 
+/// The world's per-tick Update systems (climate, resources, disasters, chunk generation)
+/// Unordered against the organism systems unless a plugin orders them (see `energy_audit`).
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorldUpdateSet;
+
 /// Track which chunks/cells need updates (optimization 2)
 #[derive(Resource, Default)]
 pub struct DirtyChunks {
@@ -63,14 +77,33 @@ impl DirtyChunks {
     }
 }
 
+/// `WorldSeed::stream_seed` salts for the world's random streams
+const CLIMATE_STREAM: u64 = 0xC11A_7E;
+const DISASTER_STREAM: u64 = 0xD15A_57E5;
+
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<WorldGrid>()
-            .init_resource::<ClimateState>()
+        // main may already have set the seed, edges and terrain generator from the command line
+        app.init_resource::<WorldSeed>().init_resource::<WorldEdges>();
+        // Climate and disasters draw from the world seed, so a seed replays its weather too
+        let world_seed = *app.world.resource::<WorldSeed>();
+        let mut climate = ClimateState::seeded(world_seed.stream_seed(CLIMATE_STREAM));
+        let edges = *app.world.resource::<WorldEdges>();
+        if edges == WorldEdges::Wrap {
+            climate = climate.with_wrap(edges.size());
+        }
+
+        app.init_resource::<ActiveTerrainGenerator>()
+            .init_resource::<WorldGrid>()
+            .insert_resource(climate)
             .init_resource::<DirtyChunks>()
-            .init_resource::<events::DisasterEvents>() // Step 9: Major disasters
+            .insert_resource(events::DisasterEvents::seeded(
+                world_seed.stream_seed(DISASTER_STREAM),
+            )) // Step 9: Major disasters
+            .init_resource::<ChunkSummary>()
+            .init_resource::<ChunkGenerationTasks>()
             .add_systems(Startup, initialize_world)
             .add_systems(
                 Update,
@@ -79,26 +112,61 @@ impl Plugin for WorldPlugin {
                     mark_active_chunks,
                     update_chunks,
                     regenerate_and_decay_resources,
-                    flow_resources,
+                    // Diffusion, then downhill water flow
+                    (flow_resources, hydrology::flow_water_downhill).chain(),
                     events::update_disaster_events, // Step 9: Update disasters
-                ),
+                    summary::update_chunk_summary,
+                    // Off-thread terrain generation for newly created chunks
+                    (
+                        generation::apply_generated_chunks,
+                        generation::queue_occupied_chunks,
+                        generation::dispatch_chunk_generation,
+                    )
+                        .chain(),
+                )
+                    // Nothing to simulate after an extinction
+                    .run_if(crate::organisms::ecosystem_active)
+                    .in_set(WorldUpdateSet),
             )
             .add_systems(
                 Update,
-                events::apply_disaster_damage_system, // Step 9: Apply disaster damage to organisms
+                events::apply_disaster_damage_system // Step 9: Apply disaster damage to organisms
+                    .run_if(crate::organisms::ecosystem_active)
+                    .in_set(WorldUpdateSet),
             );
     }
 }
 
-fn initialize_world(mut world_grid: ResMut<WorldGrid>) {
+/// World-coordinate span (`min..max`, the same on both axes) of the chunks generated at startup
+/// `initialize_world` creates chunks `-radius..=radius`; founders spawn anywhere inside them.
+pub fn initial_world_bounds(radius_chunks: u32) -> (f32, f32) {
+    let radius = radius_chunks as f32;
+    (-radius * CHUNK_SIZE as f32, (radius + 1.0) * CHUNK_SIZE as f32)
+}
+
+fn initialize_world(
+    mut world_grid: ResMut<WorldGrid>,
+    tuning: Option<Res<crate::organisms::EcosystemTuning>>,
+    world_seed: Res<WorldSeed>,
+    generator: Res<ActiveTerrainGenerator>,
+) {
     info!("Initializing world grid...");
+    info!("World seed: {} | Terrain: {}", world_seed.0, generator.0.name());
+
+    let seed_fraction = tuning
+        .as_deref()
+        .map(|t| t.initial_resource_seed_fraction)
+        .unwrap_or(0.5);
+    let radius = tuning.as_deref().map_or(1, |t| t.initial_world_radius) as i32;
 
-    // Initialize a smaller area around origin (reduced from 5x5 to 3x3 for better performance)
+    // Initialize a small area around origin (3x3 chunks by default, for better performance)
     // In production, chunks are created on-demand
-    for chunk_x in -1..=1 {
-        for chunk_y in -1..=1 {
-            let chunk = world_grid.get_or_create_chunk(chunk_x, chunk_y);
-            terrain::initialize_chunk(chunk);
+    // Generated synchronously - startup can afford it and organisms spawn here immediately
+    for chunk_x in -radius..=radius {
+        for chunk_y in -radius..=radius {
+            let mut chunk = Chunk::new(chunk_x, chunk_y);
+            terrain::initialize_chunk(&mut chunk, generator.0.as_ref(), *world_seed, seed_fraction);
+            world_grid.insert_chunk(chunk);
         }
     }
 
@@ -106,11 +174,30 @@ fn initialize_world(mut world_grid: ResMut<WorldGrid>) {
         "World grid initialized with {} chunks",
         world_grid.chunk_count()
     );
+    let totals = world_grid.total_resources();
+    info!("Seeded resources: {}", format_resource_amounts(&totals));
 }
 
 /// Update global climate state
-fn update_climate(mut climate: ResMut<ClimateState>, time: Res<Time>) {
-    climate.update(time.delta_seconds());
+fn update_climate(
+    mut climate: ResMut<ClimateState>,
+    time: Res<Time>,
+    sim_time: Res<crate::utils::SimTime>,
+) {
+    climate.update(sim_time.tick, time.delta_seconds());
+
+    if sim_time.every(1000) {
+        info!(
+            "[CLIMATE] Tick {} | Season {:.2} | Temp {:.2} | Humidity {:.2} | Wind ({:.3}, {:.3}) | Events {}",
+            climate.time,
+            climate.season,
+            climate.base_temperature,
+            climate.base_humidity,
+            climate.wind.x,
+            climate.wind.y,
+            climate.events.len()
+        );
+    }
 }
 
 /// Mark chunks/cells as active based on organism positions
@@ -223,6 +310,22 @@ fn regenerate_and_decay_resources(
     let dt = time.delta_seconds();
     let chunk_coords: Vec<_> = world_grid.get_chunk_coords();
     let tuning_ref = tuning.as_deref();
+    let shading_strength = tuning_ref.map_or(0.0, |t| t.terrain_shading_strength);
+    let grid = &*world_grid;
+
+    // Shade cast on a cell by its eight neighbors (read from the grid, so it crosses chunk borders)
+    let sunlight_shading = |chunk_x: i32, chunk_y: i32, x: usize, y: usize, cell: &Cell| {
+        if shading_strength <= 0.0 {
+            return 1.0;
+        }
+        let world_x = (chunk_x * crate::world::chunk::CHUNK_SIZE as i32 + x as i32) as f32;
+        let world_y = (chunk_y * crate::world::chunk::CHUNK_SIZE as i32 + y as i32) as f32;
+        let neighbors = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
+            .into_iter()
+            .filter_map(|(dx, dy)| grid.get_cell(world_x + dx as f32, world_y + dy as f32))
+            .map(|neighbor| (neighbor.elevation, neighbor.terrain));
+        resources::sunlight_shading_multiplier(cell.elevation, neighbors, shading_strength)
+    };
 
     // Collect cells that need updating (read-only phase)
     let cells_to_update: Vec<_> = chunk_coords
@@ -242,7 +345,8 @@ fn regenerate_and_decay_resources(
                                     
                                     // Only update if cell has resources OR is active (near organisms)
                                     if has_resources || dirty_chunks.active_cells.contains(&((chunk_x, chunk_y), (x, y))) {
-                                        updates.push((chunk_x, chunk_y, x, y, *cell));
+                                        let shading = sunlight_shading(chunk_x, chunk_y, x, y, cell);
+                                        updates.push((chunk_x, chunk_y, x, y, *cell, shading));
                                     }
                                 }
                             }
@@ -257,9 +361,9 @@ fn regenerate_and_decay_resources(
     // Process updates in parallel
     let updated_cells: Vec<_> = cells_to_update
         .par_iter()
-        .map(|(chunk_x, chunk_y, x, y, cell)| {
+        .map(|(chunk_x, chunk_y, x, y, cell, shading)| {
             let mut new_cell = *cell;
-            resources::regenerate_resources(&mut new_cell, dt, tuning_ref);
+            resources::regenerate_resources(&mut new_cell, dt, tuning_ref, *shading);
             resources::decay_resources(&mut new_cell, dt, tuning_ref);
             resources::quantize_resources(&mut new_cell, 0.001);
             (*chunk_x, *chunk_y, *x, *y, new_cell)
@@ -279,11 +383,26 @@ fn regenerate_and_decay_resources(
 /// Step 10: PARALLELIZED - Processes chunks in parallel using rayon
 /// OPTIMIZED: Uses direct array indexing instead of find() for O(1) access
 /// OPTIMIZED: Uses flat Vec to avoid any stack allocations
-fn flow_resources(mut world_grid: ResMut<WorldGrid>, time: Res<Time>) {
+fn flow_resources(
+    mut world_grid: ResMut<WorldGrid>,
+    time: Res<Time>,
+    climate: Res<ClimateState>,
+    tuning: Option<Res<crate::organisms::EcosystemTuning>>,
+) {
     use rayon::prelude::*;
     
     let dt = time.delta_seconds();
-    let diffusion_rate = 0.1; // How quickly resources flow
+    // How quickly each resource flows
+    let diffusion_rates = tuning
+        .as_deref()
+        .map(|t| t.resource_diffusion_rates)
+        .unwrap_or([0.1; RESOURCE_TYPE_COUNT]);
+    // Upwind neighbors contribute more, so resources creep downwind
+    let wind_response = tuning
+        .as_deref()
+        .map(|t| t.resource_wind_response)
+        .unwrap_or([0.5; RESOURCE_TYPE_COUNT]);
+    let wind = climate.wind;
     let chunk_coords: Vec<_> = world_grid.get_chunk_coords();
 
     // Step 10: Process chunks in parallel
@@ -334,7 +453,7 @@ fn flow_resources(mut world_grid: ResMut<WorldGrid>, time: Res<Time>) {
                     let index = y * CHUNK_SIZE + x;
                     let base_idx = index * RESOURCE_COUNT;
                     let mut neighbor_sum = [0.0f32; RESOURCE_COUNT];
-                    let mut neighbor_count = 0;
+                    let mut neighbor_weight = [0.0f32; RESOURCE_COUNT];
 
                     for dy in -1..=1 {
                         for dx in -1..=1 {
@@ -351,22 +470,28 @@ fn flow_resources(mut world_grid: ResMut<WorldGrid>, time: Res<Time>) {
                                 && ny < CHUNK_SIZE as isize
                             {
                                 let n_index = (ny as usize * CHUNK_SIZE + nx as usize) * RESOURCE_COUNT;
+                                // Flow direction from neighbor into this cell, aligned with wind
+                                let inflow = Vec2::new(-dx as f32, -dy as f32).normalize();
+                                let alignment = inflow.dot(wind);
                                 for i in 0..RESOURCE_COUNT {
-                                    neighbor_sum[i] += temp_resources[n_index + i];
+                                    let weight = (1.0 + wind_response[i] * alignment).max(0.0);
+                                    neighbor_sum[i] += temp_resources[n_index + i] * weight;
+                                    neighbor_weight[i] += weight;
                                 }
-                                neighbor_count += 1;
                             }
                         }
                     }
 
-                    if neighbor_count > 0 {
-                        for i in 0..RESOURCE_COUNT {
-                            let old_value = temp_resources[base_idx + i];
-                            let neighbor_avg = neighbor_sum[i] / neighbor_count as f32;
-                            let diff = neighbor_avg - old_value;
-                            new_resources[base_idx + i] =
-                                (old_value + diff * diffusion_rate * dt).clamp(0.0, 1.0);
+                    for i in 0..RESOURCE_COUNT {
+                        // Non-diffusing resources are left exactly as they are
+                        if neighbor_weight[i] <= 0.0 || diffusion_rates[i] <= 0.0 {
+                            continue;
                         }
+                        let old_value = temp_resources[base_idx + i];
+                        let neighbor_avg = neighbor_sum[i] / neighbor_weight[i];
+                        let diff = neighbor_avg - old_value;
+                        new_resources[base_idx + i] =
+                            (old_value + diff * diffusion_rates[i] * dt).clamp(0.0, 1.0);
                     }
                 }
             }
@@ -388,9 +513,102 @@ fn flow_resources(mut world_grid: ResMut<WorldGrid>, time: Res<Time>) {
                         for i in 0..RESOURCE_COUNT {
                             cell.resource_density[i] = new_resources[base_idx + i];
                         }
+                        // Whatever diffused in beyond what the terrain holds is lost
+                        resources::clamp_to_capacity(cell);
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn water_diffused_into_desert_clamps_to_its_cap() {
+        let desert = (CHUNK_SIZE / 2, CHUNK_SIZE / 2);
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let cell = chunk.get_cell_mut(x, y).unwrap();
+                cell.terrain = if (x, y) == desert {
+                    TerrainType::Desert
+                } else {
+                    TerrainType::Swamp
+                };
+                let capacity = resource_capacity(cell.terrain, ResourceType::Water);
+                cell.set_resource(ResourceType::Water, capacity);
+            }
+        }
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs(1));
+        let mut world = World::new();
+        world.insert_resource(grid);
+        world.insert_resource(time);
+        world.insert_resource(ClimateState::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(flow_resources);
+        schedule.run(&mut world);
+
+        let grid = world.resource::<WorldGrid>();
+        let cap = resource_capacity(TerrainType::Desert, ResourceType::Water);
+        let cell_water = |x: usize, y: usize| {
+            grid.get_chunk(0, 0).unwrap().get_cell(x, y).unwrap().get_resource(ResourceType::Water)
+        };
+        // Wet swamp all around would push the desert cell well above its cap
+        assert_eq!(cell_water(desert.0, desert.1), cap);
+        assert!(cap < 0.5);
+        // The swamp keeps (nearly) all of its water
+        assert!(cell_water(desert.0 + 2, desert.1) > 0.99);
+    }
+
+    #[test]
+    fn zero_diffusion_leaves_a_resource_untouched() {
+        let mut tuning = crate::organisms::EcosystemTuning::default();
+        tuning.resource_diffusion_rates[ResourceType::Mineral as usize] = 0.0;
+
+        // Checkerboard of minerals and plants, well inside Plains capacity
+        let stock = |x: usize, y: usize| if (x + y) % 2 == 0 { 0.1 } else { 0.0 };
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let cell = chunk.get_cell_mut(x, y).unwrap();
+                cell.terrain = TerrainType::Plains;
+                cell.set_resource(ResourceType::Mineral, stock(x, y));
+                cell.set_resource(ResourceType::Plant, stock(x, y));
+            }
+        }
+        let mut grid = WorldGrid::default();
+        grid.insert_chunk(chunk);
+
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs(1));
+        let mut world = World::new();
+        world.insert_resource(grid);
+        world.insert_resource(time);
+        world.insert_resource(ClimateState::default());
+        world.insert_resource(tuning);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(flow_resources);
+        schedule.run(&mut world);
+
+        let chunk = world.resource::<WorldGrid>().get_chunk(0, 0).unwrap();
+        let mut plants_moved = false;
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let cell = chunk.get_cell(x, y).unwrap();
+                assert_eq!(cell.get_resource(ResourceType::Mineral), stock(x, y));
+                plants_moved |= cell.get_resource(ResourceType::Plant) != stock(x, y);
+            }
+        }
+        // The diffusing resource beside it did spread
+        assert!(plants_moved);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::world::cell::{Cell, ResourceType, RESOURCE_TYPE_COUNT};
+use crate::world::cell::{Cell, ResourceType, TerrainType, RESOURCE_TYPE_COUNT};
 
 /// Resource regeneration rates per terrain type (base rates, multiplied by tuning)
 /// [Plant, Mineral, Sunlight, Water, Detritus, Prey]
@@ -34,6 +34,42 @@ pub const BASE_DECAY_RATES: [f32; RESOURCE_TYPE_COUNT] = [
 /// Maximum resource capacity per cell
 pub const MAX_RESOURCE_DENSITY: f32 = 1.0;
 
+/// How much of each resource a cell of a given terrain can hold (fraction of `MAX_RESOURCE_DENSITY`)
+/// Resources diffusing or regenerating past a cap are clamped to it, so terrain sets the
+/// local equilibrium (a Desert can't stay waterlogged, an Ocean can't carry plants).
+/// [Plant, Mineral, Sunlight, Water, Detritus, Prey]
+pub const RESOURCE_CAPACITY: [[f32; RESOURCE_TYPE_COUNT]; 8] = [
+    // Ocean
+    [0.0, 0.6, 1.0, 1.0, 0.6, 1.0],
+    // Plains
+    [0.8, 0.6, 1.0, 0.7, 0.7, 0.8],
+    // Forest
+    [1.0, 0.5, 0.8, 0.8, 1.0, 0.8],
+    // Desert
+    [0.2, 0.8, 1.0, 0.2, 0.3, 0.4],
+    // Tundra
+    [0.3, 0.6, 0.8, 0.5, 0.4, 0.4],
+    // Mountain
+    [0.2, 1.0, 1.0, 0.4, 0.3, 0.3],
+    // Swamp
+    [0.9, 0.4, 0.7, 1.0, 1.0, 0.8],
+    // Volcanic
+    [0.0, 1.0, 1.0, 0.2, 0.3, 0.1],
+];
+
+/// Capacity of a resource on a terrain (see `RESOURCE_CAPACITY`)
+pub fn resource_capacity(terrain: TerrainType, resource_type: ResourceType) -> f32 {
+    MAX_RESOURCE_DENSITY * RESOURCE_CAPACITY[terrain as usize][resource_type.index()]
+}
+
+/// Clamp every resource in a cell to its terrain's capacity
+pub fn clamp_to_capacity(cell: &mut Cell) {
+    let caps = &RESOURCE_CAPACITY[cell.terrain as usize];
+    for (resource, cap) in cell.resource_density.iter_mut().zip(caps) {
+        *resource = resource.min(MAX_RESOURCE_DENSITY * cap);
+    }
+}
+
 /// Resource regeneration rate multiplier based on temperature
 pub fn temperature_regeneration_multiplier(temperature: f32) -> f32 {
     // Optimal temperature around 0.5, drops off at extremes
@@ -54,40 +90,111 @@ pub fn humidity_regeneration_multiplier(humidity: f32, resource_type: ResourceTy
     }
 }
 
+/// Elevation rise (as a fraction of the full 0-65535 range) at which a neighbor fully shades a cell
+const FULL_SHADE_ELEVATION_RISE: f32 = 0.25;
+
+/// Shade cast by a Forest neighbor's canopy, whatever its elevation
+const CANOPY_SHADE: f32 = 0.5;
+
+/// Sunlight regeneration multiplier from neighbors casting shade (1.0 = unshaded)
+/// Each neighbor shades in proportion to how far it rises above the cell, or by its canopy if
+/// it's Forest (whichever is more); the average shade over all eight neighbors is scaled by
+/// `strength`. Missing neighbors (unloaded chunks) cast none.
+pub fn sunlight_shading_multiplier(
+    elevation: u16,
+    neighbors: impl Iterator<Item = (u16, TerrainType)>,
+    strength: f32,
+) -> f32 {
+    if strength <= 0.0 {
+        return 1.0;
+    }
+    let shade: f32 = neighbors
+        .map(|(neighbor_elevation, terrain)| {
+            let rise = (neighbor_elevation as f32 - elevation as f32) / u16::MAX as f32;
+            let relief = (rise / FULL_SHADE_ELEVATION_RISE).clamp(0.0, 1.0);
+            let canopy = if terrain == TerrainType::Forest { CANOPY_SHADE } else { 0.0 };
+            relief.max(canopy)
+        })
+        .sum();
+    (1.0 - strength * shade / 8.0).clamp(0.0, 1.0)
+}
+
+/// Seed a freshly generated cell's resources from its terrain's regeneration profile
+/// Each resource starts at `seed_fraction` of capacity, scaled by how fast the terrain regenerates it
+pub fn seed_resources(cell: &mut Cell, seed_fraction: f32) {
+    let terrain_idx = cell.terrain as usize;
+    let seed_fraction = seed_fraction.clamp(0.0, 1.0);
+
+    for (resource_idx, &base_regeneration_rate) in BASE_REGENERATION_RATES[terrain_idx].iter().enumerate() {
+        cell.resource_density[resource_idx] =
+            MAX_RESOURCE_DENSITY * seed_fraction * base_regeneration_rate.min(1.0);
+    }
+    clamp_to_capacity(cell);
+}
+
+/// Tuned regeneration rate relative to the rate `BASE_REGENERATION_RATES` was balanced for
+fn regeneration_tuning_multiplier(tuning: &crate::organisms::EcosystemTuning, resource_type: ResourceType) -> f32 {
+    match resource_type {
+        ResourceType::Plant => tuning.plant_regeneration_rate / 0.08,
+        ResourceType::Mineral => tuning.mineral_regeneration_rate / 0.05,
+        ResourceType::Sunlight => tuning.sunlight_regeneration_rate / 0.15,
+        ResourceType::Water => tuning.water_regeneration_rate / 0.12,
+        ResourceType::Detritus => tuning.detritus_regeneration_rate / 0.03,
+        ResourceType::Prey => tuning.prey_regeneration_rate / 0.02,
+    }
+}
+
+/// Tuned decay rate relative to the rate `BASE_DECAY_RATES` was balanced for
+fn decay_tuning_multiplier(tuning: &crate::organisms::EcosystemTuning, resource_type: ResourceType) -> f32 {
+    match resource_type {
+        ResourceType::Plant => tuning.plant_decay_rate / 0.01,
+        ResourceType::Mineral => tuning.mineral_decay_rate / 0.001,
+        ResourceType::Sunlight => tuning.sunlight_decay_rate / 0.02,
+        ResourceType::Water => tuning.water_decay_rate / 0.005,
+        ResourceType::Detritus => tuning.detritus_decay_rate / 0.015,
+        ResourceType::Prey => tuning.prey_decay_rate / 0.02,
+    }
+}
+
+/// Format per-resource amounts as "Plant: 1.0 | Mineral: 2.0 | ..." for logging
+pub fn format_resource_amounts(amounts: &[f64; RESOURCE_TYPE_COUNT]) -> String {
+    ResourceType::all()
+        .map(|resource| format!("{}: {:.1}", resource.name(), amounts[resource.index()]))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
 /// Update resource regeneration for a single cell
 /// Step 8: Now uses tuning parameters for ecosystem balance
-pub fn regenerate_resources(cell: &mut Cell, dt: f32, tuning: Option<&crate::organisms::EcosystemTuning>) {
+/// `sunlight_shading` scales Sunlight regeneration (see `sunlight_shading_multiplier`)
+pub fn regenerate_resources(
+    cell: &mut Cell,
+    dt: f32,
+    tuning: Option<&crate::organisms::EcosystemTuning>,
+    sunlight_shading: f32,
+) {
     let terrain_idx = cell.terrain as usize;
     let temp_mult = temperature_regeneration_multiplier(cell.temperature);
 
     // Get tuning multipliers (default to 1.0 if no tuning provided)
-    let plant_mult = tuning.map(|t| t.plant_regeneration_rate / 0.08).unwrap_or(1.0);
-    let mineral_mult = tuning.map(|t| t.mineral_regeneration_rate / 0.05).unwrap_or(1.0);
-    let sunlight_mult = tuning.map(|t| t.sunlight_regeneration_rate / 0.15).unwrap_or(1.0);
-    let water_mult = tuning.map(|t| t.water_regeneration_rate / 0.12).unwrap_or(1.0);
-    let detritus_mult = tuning.map(|t| t.detritus_regeneration_rate / 0.03).unwrap_or(1.0);
-    let prey_mult = tuning.map(|t| t.prey_regeneration_rate / 0.02).unwrap_or(1.0);
-
-    let multipliers = [plant_mult, mineral_mult, sunlight_mult, water_mult, detritus_mult, prey_mult];
-
-    for (resource_idx, &base_regeneration_rate) in BASE_REGENERATION_RATES[terrain_idx].iter().enumerate() {
-        let resource_type = match resource_idx {
-            0 => ResourceType::Plant,
-            1 => ResourceType::Mineral,
-            2 => ResourceType::Sunlight,
-            3 => ResourceType::Water,
-            4 => ResourceType::Detritus,
-            5 => ResourceType::Prey,
-            _ => continue,
-        };
-
+    for resource_type in ResourceType::all() {
+        let resource_idx = resource_type.index();
+        let base_regeneration_rate = BASE_REGENERATION_RATES[terrain_idx][resource_idx];
         let humidity_mult = humidity_regeneration_multiplier(cell.humidity, resource_type);
         let adaptation = 1.0 + cell.resource_adaptation[resource_idx].clamp(-0.5, 1.5);
-        let tuning_mult = multipliers[resource_idx];
-        let effective_rate = base_regeneration_rate * temp_mult * humidity_mult * adaptation * tuning_mult;
+        let tuning_mult = tuning
+            .map(|t| regeneration_tuning_multiplier(t, resource_type))
+            .unwrap_or(1.0);
+        let shading_mult = if resource_type == ResourceType::Sunlight {
+            sunlight_shading
+        } else {
+            1.0
+        };
+        let effective_rate =
+            base_regeneration_rate * temp_mult * humidity_mult * adaptation * tuning_mult * shading_mult;
 
         let current = cell.resource_density[resource_idx];
-        let new_value = (current + effective_rate * dt).min(MAX_RESOURCE_DENSITY);
+        let new_value = (current + effective_rate * dt).min(resource_capacity(cell.terrain, resource_type));
         cell.resource_density[resource_idx] = new_value;
 
         // Gradually relax pressure memory
@@ -105,18 +212,14 @@ pub fn regenerate_resources(cell: &mut Cell, dt: f32, tuning: Option<&crate::org
 /// Step 8: Now uses tuning parameters for ecosystem balance
 pub fn decay_resources(cell: &mut Cell, dt: f32, tuning: Option<&crate::organisms::EcosystemTuning>) {
     // Get tuning multipliers (default to 1.0 if no tuning provided)
-    let plant_decay_mult = tuning.map(|t| t.plant_decay_rate / 0.01).unwrap_or(1.0);
-    let mineral_decay_mult = tuning.map(|t| t.mineral_decay_rate / 0.001).unwrap_or(1.0);
-    let sunlight_decay_mult = tuning.map(|t| t.sunlight_decay_rate / 0.02).unwrap_or(1.0);
-    let water_decay_mult = tuning.map(|t| t.water_decay_rate / 0.005).unwrap_or(1.0);
-    let detritus_decay_mult = tuning.map(|t| t.detritus_decay_rate / 0.015).unwrap_or(1.0);
-    let prey_decay_mult = tuning.map(|t| t.prey_decay_rate / 0.02).unwrap_or(1.0);
-
-    let multipliers = [plant_decay_mult, mineral_decay_mult, sunlight_decay_mult, water_decay_mult, detritus_decay_mult, prey_decay_mult];
-
-    for (idx, &base_decay_rate) in BASE_DECAY_RATES.iter().enumerate() {
+    for resource_type in ResourceType::all() {
+        let idx = resource_type.index();
+        let base_decay_rate = BASE_DECAY_RATES[idx];
         if base_decay_rate > 0.0 {
-            let effective_decay = base_decay_rate * multipliers[idx];
+            let decay_mult = tuning
+                .map(|t| decay_tuning_multiplier(t, resource_type))
+                .unwrap_or(1.0);
+            let effective_decay = base_decay_rate * decay_mult;
             let current = cell.resource_density[idx];
             cell.resource_density[idx] = (current * (1.0 - effective_decay * dt)).max(0.0);
         }
@@ -134,14 +237,15 @@ pub fn quantize_resources(cell: &mut Cell, threshold: f32) {
 
 /// Adjust resource adaptation based on sustained pressure and climate
 fn update_resource_adaptation(cell: &mut Cell, dt: f32) {
-    for idx in 0..RESOURCE_TYPE_COUNT {
+    for resource_type in ResourceType::all() {
+        let idx = resource_type.index();
         let pressure = cell.resource_pressure[idx];
         let target = (pressure * 0.08) - 0.05; // mild boost under pressure
         let current = cell.resource_adaptation[idx];
 
         // Climate stress pushes adaptation negative for plants & water
-        let climate_stress = match idx {
-            x if x == ResourceType::Plant as usize || x == ResourceType::Water as usize => {
+        let climate_stress = match resource_type {
+            ResourceType::Plant | ResourceType::Water => {
                 ((0.5 - cell.humidity).abs() + (0.5 - cell.temperature).abs()) * 0.15
             }
             _ => 0.0,
@@ -151,3 +255,36 @@ fn update_resource_adaptation(cell: &mut Cell, dt: f32) {
         cell.resource_adaptation[idx] = (current + delta).clamp(-0.5, 1.5);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_beside_a_mountain_regenerate_less_sunlight() {
+        let tuning = crate::organisms::EcosystemTuning::default();
+        let valley = 10_000;
+        let open = [(valley, TerrainType::Plains); 8];
+        let mut beside_peak = open;
+        beside_peak[3] = (60_000, TerrainType::Mountain);
+
+        let unshaded = sunlight_shading_multiplier(valley, open.into_iter(), tuning.terrain_shading_strength);
+        let shaded =
+            sunlight_shading_multiplier(valley, beside_peak.into_iter(), tuning.terrain_shading_strength);
+        assert_eq!(unshaded, 1.0);
+        assert!(shaded < unshaded);
+        // No shading at all when the strength is zero
+        assert_eq!(sunlight_shading_multiplier(valley, beside_peak.into_iter(), 0.0), 1.0);
+
+        let regrow = |shading| {
+            let mut cell = Cell {
+                terrain: TerrainType::Plains,
+                elevation: valley,
+                ..Cell::default()
+            };
+            regenerate_resources(&mut cell, 0.1, Some(&tuning), shading);
+            cell.get_resource(ResourceType::Sunlight)
+        };
+        assert!(regrow(shaded) < regrow(unshaded));
+    }
+}
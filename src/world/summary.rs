@@ -0,0 +1,34 @@
+use crate::world::grid::WorldGrid;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Per-chunk aggregates rebuilt every frame (used for LOD rendering and chunk-level stats)
+#[derive(Resource, Default)]
+pub struct ChunkSummary {
+    /// Number of living organisms in each chunk
+    organism_counts: HashMap<(i32, i32), u32>,
+}
+
+impl ChunkSummary {
+    /// Number of living organisms in a chunk (0 if none)
+    pub fn organism_count(&self, chunk_x: i32, chunk_y: i32) -> u32 {
+        self.organism_counts
+            .get(&(chunk_x, chunk_y))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Iterate over chunks that currently contain organisms
+    pub fn occupied_chunks(&self) -> impl Iterator<Item = ((i32, i32), u32)> + '_ {
+        self.organism_counts.iter().map(|(coords, count)| (*coords, *count))
+    }
+}
+
+/// Rebuild per-chunk organism counts from the world grid's chunk registry
+pub fn update_chunk_summary(mut summary: ResMut<ChunkSummary>, world_grid: Res<WorldGrid>) {
+    summary.organism_counts.clear();
+
+    for (chunk, count) in world_grid.occupied_chunks() {
+        summary.organism_counts.insert(chunk, count as u32);
+    }
+}
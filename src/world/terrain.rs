@@ -1,75 +1,401 @@
 use crate::world::cell::TerrainType;
 use crate::world::chunk::{Chunk, CHUNK_SIZE};
+use crate::world::resources::seed_resources;
+use bevy::prelude::*;
 use rand::{Rng, SeedableRng};
+use std::sync::Arc;
 
-/// Generate terrain for a chunk based on chunk coordinates
+/// Seed for the whole world's terrain, mixed into every chunk's generation seed
+/// The same seed always reproduces the same world. Seed 0 gives the original
+/// coordinate-only terrain.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorldSeed(pub u64);
+
+impl WorldSeed {
+    /// Read the seed from `--seed <n>` / `--seed=<n>` on the command line, falling back to
+    /// the `WORLD_SEED` environment variable, then to the default
+    pub fn from_args() -> Self {
+        let value = crate::utils::cli::option_value("seed")
+            .or_else(|| std::env::var("WORLD_SEED").ok());
+
+        match value {
+            Some(value) => match value.trim().parse() {
+                Ok(seed) => WorldSeed(seed),
+                Err(_) => {
+                    warn!("Invalid world seed '{}', using the default", value);
+                    WorldSeed::default()
+                }
+            },
+            None => WorldSeed::default(),
+        }
+    }
+
+    /// Seed for one of the world's random streams (climate, disasters), kept apart by `salt`
+    pub fn stream_seed(self, salt: u64) -> u64 {
+        (self.0 ^ salt).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Generation seed for one chunk
+    fn chunk_seed(self, chunk_x: i32, chunk_y: i32) -> u64 {
+        let coordinate_seed = (chunk_x as u64).wrapping_mul(31) ^ (chunk_y as u64);
+        // Spread the world seed across all bits so nearby seeds give unrelated worlds
+        self.0.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ coordinate_seed
+    }
+}
+
+/// Majority-filter passes applied after raw generation: the minimum number of identical
+/// neighbors (out of 8) needed to convert a cell in each pass
+/// The last pass only removes cells surrounded on all sides, which simultaneous majority
+/// updates can leave behind (and which can't create new ones)
+const SMOOTHING_PASSES: [u8; 3] = [5, 5, 8];
+
+/// Unsmoothed (elevation, terrain) per cell for a chunk, row-major
+/// Deterministic in the world seed and chunk coordinates, so neighbors can regenerate it for border smoothing
+fn generate_raw_terrain(world_seed: WorldSeed, chunk_x: i32, chunk_y: i32) -> Vec<(u16, TerrainType)> {
+    // Use the world seed and chunk coordinates as seed for deterministic generation
+    let mut local_rng = rand::rngs::StdRng::seed_from_u64(world_seed.chunk_seed(chunk_x, chunk_y));
+    let mut cells = Vec::with_capacity(CHUNK_SIZE * CHUNK_SIZE);
+
+    for y in 0..CHUNK_SIZE {
+        for x in 0..CHUNK_SIZE {
+            // Simple terrain generation based on distance from center and elevation
+            let center_x = CHUNK_SIZE as f32 / 2.0;
+            let center_y = CHUNK_SIZE as f32 / 2.0;
+            let dist_from_center =
+                ((x as f32 - center_x).powi(2) + (y as f32 - center_y).powi(2)).sqrt()
+                    / (CHUNK_SIZE as f32 / 2.0);
+
+            // Generate elevation (0-65535)
+            let base_elevation = (dist_from_center * 10000.0) as u16;
+            let elevation_noise = local_rng.gen_range(0..5000);
+            let elevation = (base_elevation + elevation_noise).min(65535);
+
+            // Determine terrain type based on elevation and position
+            let elevation_normalized = elevation as f32 / 65535.0;
+
+            let terrain = if elevation_normalized < 0.2 {
+                // Low elevation - water/swamp
+                if local_rng.gen_bool(0.7) {
+                    TerrainType::Ocean
+                } else {
+                    TerrainType::Swamp
+                }
+            } else if elevation_normalized < 0.3 {
+                // Low land - plains/forest
+                if local_rng.gen_bool(0.6) {
+                    TerrainType::Plains
+                } else {
+                    TerrainType::Forest
+                }
+            } else if elevation_normalized < 0.5 {
+                // Mid elevation - varied
+                match local_rng.gen_range(0..4) {
+                    0 => TerrainType::Plains,
+                    1 => TerrainType::Forest,
+                    2 => TerrainType::Desert,
+                    _ => TerrainType::Tundra,
+                }
+            } else if elevation_normalized < 0.8 {
+                // High elevation - tundra/mountain
+                if local_rng.gen_bool(0.7) {
+                    TerrainType::Tundra
+                } else {
+                    TerrainType::Mountain
+                }
+            } else {
+                // Very high - mountain/volcanic
+                if local_rng.gen_bool(0.9) {
+                    TerrainType::Mountain
+                } else {
+                    TerrainType::Volcanic
+                }
+            };
+
+            cells.push((elevation, terrain));
+        }
+    }
+
+    cells
+}
+
+/// Raw terrain for the chunk plus a halo (one cell per smoothing pass) taken from its neighbors
+/// Returns (grid, width) with the grid row-major
+fn padded_raw_terrain(world_seed: WorldSeed, chunk_x: i32, chunk_y: i32) -> (Vec<TerrainType>, usize) {
+    let halo = SMOOTHING_PASSES.len();
+    let width = CHUNK_SIZE + 2 * halo;
+    let mut grid = vec![TerrainType::default(); width * width];
+
+    for offset_y in -1..=1 {
+        for offset_x in -1..=1 {
+            let raw = generate_raw_terrain(world_seed, chunk_x + offset_x, chunk_y + offset_y);
+            for y in 0..CHUNK_SIZE {
+                let padded_y = (offset_y * CHUNK_SIZE as i32 + y as i32) + halo as i32;
+                if padded_y < 0 || padded_y >= width as i32 {
+                    continue;
+                }
+                for x in 0..CHUNK_SIZE {
+                    let padded_x = (offset_x * CHUNK_SIZE as i32 + x as i32) + halo as i32;
+                    if padded_x < 0 || padded_x >= width as i32 {
+                        continue;
+                    }
+                    grid[padded_y as usize * width + padded_x as usize] = raw[y * CHUNK_SIZE + x].1;
+                }
+            }
+        }
+    }
+
+    (grid, width)
+}
+
+/// One majority-filter pass over the cells at least `margin` away from the grid edge
+/// A cell adopts the most common neighboring terrain when at least `majority` of its
+/// 8 neighbors agree (ties go to the lower terrain id, keeping the pass deterministic)
+fn smooth_terrain_pass(
+    grid: &[TerrainType],
+    width: usize,
+    margin: usize,
+    majority: u8,
+) -> Vec<TerrainType> {
+    let mut smoothed = grid.to_vec();
+
+    for y in margin..width - margin {
+        for x in margin..width - margin {
+            let mut counts = [0u8; 8];
+            for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                let nx = (x as i32 + dx) as usize;
+                let ny = (y as i32 + dy) as usize;
+                counts[grid[ny * width + nx] as usize] += 1;
+            }
+
+            let (most_common, count) = counts
+                .iter()
+                .enumerate()
+                .fold((0, 0), |best, (id, &count)| if count > best.1 { (id, count) } else { best });
+            if count >= majority {
+                smoothed[y * width + x] = terrain_from_id(most_common);
+            }
+        }
+    }
+
+    smoothed
+}
+
+fn terrain_from_id(id: usize) -> TerrainType {
+    match id {
+        0 => TerrainType::Ocean,
+        1 => TerrainType::Plains,
+        2 => TerrainType::Forest,
+        3 => TerrainType::Desert,
+        4 => TerrainType::Tundra,
+        5 => TerrainType::Mountain,
+        6 => TerrainType::Swamp,
+        _ => TerrainType::Volcanic,
+    }
+}
+
+/// Generate terrain for a chunk based on the world seed and chunk coordinates
 /// This creates simple procedural terrain - can be enhanced with noise later
-pub fn generate_chunk_terrain(chunk: &mut Chunk) {
-    // Use chunk coordinates as seed for deterministic generation
-    let seed = (chunk.chunk_x as u64).wrapping_mul(31) ^ (chunk.chunk_y as u64);
-    let mut local_rng = rand::rngs::StdRng::seed_from_u64(seed);
+///
+/// Raw per-cell terrain is noisy, so a majority filter is run over the chunk and a halo of
+/// its neighbors' raw terrain. Each cell's result depends only on raw terrain within
+/// one cell per smoothing pass, so adjacent chunks agree at their shared border regardless
+/// of which one is generated first.
+pub fn generate_chunk_terrain(chunk: &mut Chunk, world_seed: WorldSeed) {
+    let raw = generate_raw_terrain(world_seed, chunk.chunk_x, chunk.chunk_y);
+    let (mut grid, width) = padded_raw_terrain(world_seed, chunk.chunk_x, chunk.chunk_y);
+    for (pass, majority) in SMOOTHING_PASSES.iter().enumerate() {
+        grid = smooth_terrain_pass(&grid, width, pass + 1, *majority);
+    }
 
+    let halo = SMOOTHING_PASSES.len();
     for y in 0..CHUNK_SIZE {
         for x in 0..CHUNK_SIZE {
             if let Some(cell) = chunk.get_cell_mut(x, y) {
-                // Simple terrain generation based on distance from center and elevation
-                let center_x = CHUNK_SIZE as f32 / 2.0;
-                let center_y = CHUNK_SIZE as f32 / 2.0;
-                let dist_from_center =
-                    ((x as f32 - center_x).powi(2) + (y as f32 - center_y).powi(2)).sqrt()
-                        / (CHUNK_SIZE as f32 / 2.0);
-
-                // Generate elevation (0-65535)
-                let base_elevation = (dist_from_center * 10000.0) as u16;
-                let elevation_noise = local_rng.gen_range(0..5000);
-                cell.elevation = (base_elevation + elevation_noise).min(65535);
-
-                // Determine terrain type based on elevation and position
-                let elevation_normalized = cell.elevation as f32 / 65535.0;
-
-                cell.terrain = if elevation_normalized < 0.2 {
-                    // Low elevation - water/swamp
-                    if local_rng.gen_bool(0.7) {
-                        TerrainType::Ocean
-                    } else {
-                        TerrainType::Swamp
-                    }
-                } else if elevation_normalized < 0.3 {
-                    // Low land - plains/forest
-                    if local_rng.gen_bool(0.6) {
-                        TerrainType::Plains
-                    } else {
-                        TerrainType::Forest
-                    }
-                } else if elevation_normalized < 0.5 {
-                    // Mid elevation - varied
-                    match local_rng.gen_range(0..4) {
-                        0 => TerrainType::Plains,
-                        1 => TerrainType::Forest,
-                        2 => TerrainType::Desert,
-                        _ => TerrainType::Tundra,
-                    }
-                } else if elevation_normalized < 0.8 {
-                    // High elevation - tundra/mountain
-                    if local_rng.gen_bool(0.7) {
-                        TerrainType::Tundra
-                    } else {
-                        TerrainType::Mountain
-                    }
+                cell.elevation = raw[y * CHUNK_SIZE + x].0;
+                cell.terrain = grid[(y + halo) * width + (x + halo)];
+            }
+        }
+    }
+}
+
+/// Source of a chunk's terrain and elevation
+/// Implementations must be deterministic in the world seed and chunk coordinates, so a
+/// chunk regenerates identically and adjacent chunks agree at their shared border.
+pub trait TerrainGenerator: Send + Sync {
+    /// Name used to select the generator (`--terrain <name>`)
+    fn name(&self) -> &'static str;
+    fn generate(&self, chunk: &mut Chunk, world_seed: WorldSeed);
+}
+
+/// The original per-chunk radial terrain (see `generate_chunk_terrain`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RadialTerrain;
+
+impl TerrainGenerator for RadialTerrain {
+    fn name(&self) -> &'static str {
+        "radial"
+    }
+
+    fn generate(&self, chunk: &mut Chunk, world_seed: WorldSeed) {
+        generate_chunk_terrain(chunk, world_seed);
+    }
+}
+
+/// Terrain generator every new chunk is built with
+/// Shared with the async generation tasks, so it's held behind an `Arc`.
+#[derive(Resource, Clone)]
+pub struct ActiveTerrainGenerator(pub Arc<dyn TerrainGenerator>);
+
+impl Default for ActiveTerrainGenerator {
+    fn default() -> Self {
+        Self(Arc::new(RadialTerrain))
+    }
+}
+
+impl ActiveTerrainGenerator {
+    /// Read the generator from `--terrain <radial|noise|continents|flat[:terrain]>`,
+    /// falling back to the radial generator
+    pub fn from_args() -> Self {
+        let Some(name) = crate::utils::cli::option_value("terrain") else {
+            return Self::default();
+        };
+        match crate::world::generators::terrain_generator_by_name(&name) {
+            Some(generator) => {
+                info!("[WORLD] Terrain generator: {}", generator.name());
+                Self(generator)
+            }
+            None => {
+                warn!("Unknown terrain generator '{}', using radial", name);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// Initialize a chunk with generated terrain and seeded resources
+/// `resource_seed_fraction` is the fraction of capacity each cell starts with (see `seed_resources`)
+pub fn initialize_chunk(
+    chunk: &mut Chunk,
+    generator: &dyn TerrainGenerator,
+    world_seed: WorldSeed,
+    resource_seed_fraction: f32,
+) {
+    generator.generate(chunk, world_seed);
+
+    for cell in chunk.cells_mut().iter_mut() {
+        seed_resources(cell, resource_seed_fraction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoothing_removes_an_isolated_cell() {
+        let width = 5;
+        let mut grid = vec![TerrainType::Tundra; width * width];
+        grid[2 * width + 2] = TerrainType::Desert;
+
+        let smoothed = smooth_terrain_pass(&grid, width, 1, 5);
+        assert!(smoothed.iter().all(|terrain| *terrain == TerrainType::Tundra));
+    }
+
+    #[test]
+    fn smoothing_keeps_cells_without_a_majority() {
+        // Checkerboard: no neighbor terrain reaches 5 of 8 for any cell
+        let width = 5;
+        let grid: Vec<_> = (0..width * width)
+            .map(|i| {
+                if (i % width + i / width) % 2 == 0 {
+                    TerrainType::Plains
                 } else {
-                    // Very high - mountain/volcanic
-                    if local_rng.gen_bool(0.9) {
-                        TerrainType::Mountain
-                    } else {
-                        TerrainType::Volcanic
+                    TerrainType::Forest
+                }
+            })
+            .collect();
+        // Each interior cell has 4 of one and 4 of the other
+        assert_eq!(smooth_terrain_pass(&grid, width, 1, 5), grid);
+    }
+
+    #[test]
+    fn generated_chunks_have_no_fully_surrounded_cells() {
+        for seed in [0, 1, 42] {
+            for (chunk_x, chunk_y) in [(0, 0), (3, -2)] {
+                let mut chunk = Chunk::new(chunk_x, chunk_y);
+                generate_chunk_terrain(&mut chunk, WorldSeed(seed));
+                let cells = chunk.cells();
+                for y in 1..CHUNK_SIZE - 1 {
+                    for x in 1..CHUNK_SIZE - 1 {
+                        let terrain = cells[y * CHUNK_SIZE + x].terrain;
+                        let neighbor = cells[(y - 1) * CHUNK_SIZE + x].terrain;
+                        let surrounded = neighbor != terrain
+                            && (-1i32..=1).all(|dy| {
+                                (-1i32..=1).all(|dx| {
+                                    (dx == 0 && dy == 0) || {
+                                        let index = (y as i32 + dy) as usize * CHUNK_SIZE
+                                            + (x as i32 + dx) as usize;
+                                        cells[index].terrain == neighbor
+                                    }
+                                })
+                            });
+                        assert!(!surrounded, "seed {seed}: isolated cell at ({x}, {y})");
                     }
-                };
+                }
             }
         }
     }
-}
 
-/// Initialize a chunk with generated terrain
-pub fn initialize_chunk(chunk: &mut Chunk) {
-    generate_chunk_terrain(chunk);
+    /// (elevation, terrain) of every cell in a freshly generated chunk
+    fn terrain_of(world_seed: WorldSeed, chunk_x: i32, chunk_y: i32) -> Vec<(u16, TerrainType)> {
+        let mut chunk = Chunk::new(chunk_x, chunk_y);
+        generate_chunk_terrain(&mut chunk, world_seed);
+        chunk.cells().iter().map(|cell| (cell.elevation, cell.terrain)).collect()
+    }
+
+    #[test]
+    fn same_seed_reproduces_terrain() {
+        for (chunk_x, chunk_y) in [(0, 0), (-4, 7)] {
+            assert_eq!(
+                terrain_of(WorldSeed(42), chunk_x, chunk_y),
+                terrain_of(WorldSeed(42), chunk_x, chunk_y)
+            );
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let a = terrain_of(WorldSeed(1), 0, 0);
+        let b = terrain_of(WorldSeed(2), 0, 0);
+        let differing = a.iter().zip(&b).filter(|(a, b)| a != b).count();
+        // Elevation noise alone makes nearly every cell differ
+        assert!(differing > a.len() / 2, "only {differing} cells differ");
+    }
+
+    #[test]
+    fn stream_seeds_are_distinct_per_salt() {
+        let seed = WorldSeed(42);
+        assert_ne!(seed.stream_seed(1), seed.stream_seed(2));
+        assert_ne!(seed.stream_seed(1), WorldSeed(43).stream_seed(1));
+    }
+
+    #[test]
+    fn new_forest_chunks_start_with_plants() {
+        let forest = crate::world::FlatTerrain::new(TerrainType::Forest);
+        let seed_fraction = crate::organisms::EcosystemTuning::default().initial_resource_seed_fraction;
+        let mut chunk = Chunk::new(0, 0);
+        initialize_chunk(&mut chunk, &forest, WorldSeed(0), seed_fraction);
+        assert!(chunk
+            .cells()
+            .iter()
+            .all(|cell| cell.get_resource(crate::world::ResourceType::Plant) > 0.0));
+
+        // A zero seed fraction keeps the old barren start
+        let mut barren = Chunk::new(0, 0);
+        initialize_chunk(&mut barren, &forest, WorldSeed(0), 0.0);
+        assert!(barren
+            .cells()
+            .iter()
+            .all(|cell| cell.get_resource(crate::world::ResourceType::Plant) == 0.0));
+    }
 }